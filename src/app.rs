@@ -1,20 +1,51 @@
-use std::sync::Arc;
-use tokio::spawn;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::{
+    cmp::Ordering,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::{spawn, sync::Semaphore};
 
 use crate::{
     client::Client,
-    config::Config,
+    config::{Config, DownloadConflictAction, SessionState},
     error::{AppError, Result},
     event::{
-        AppEventType, CompleteDownloadObjectResult, CompleteInitializeResult,
-        CompleteLoadObjectResult, CompleteLoadObjectsResult, CompletePreviewObjectResult, Sender,
+        AppEventType, CompleteAbortMultipartUploadResult, CompleteCopyAsDataUriResult,
+        CompleteCountObjectsResult, CompleteDownloadObjectResult, CompleteDownloadObjectsResult,
+        CompleteInitializeResult, CompleteLoadAccountIdResult, CompleteLoadBucketPolicyResult,
+        CompleteLoadBucketPropertiesResult, CompleteLoadBucketPublicResult,
+        CompleteLoadBucketRegionResult, CompleteLoadBucketVersioningResult,
+        CompleteLoadMultipartUploadsResult, CompleteLoadObjectAclResult,
+        CompleteLoadObjectHeadResult, CompleteLoadObjectResult, CompleteLoadObjectsResult,
+        CompleteLoadPrefixVersionsResult, CompletePreviewObjectRangeResult,
+        CompletePreviewObjectResult, CompleteTailObjectResult, DownloadedObject, Sender,
+        TailUpdate,
+    },
+    export::{export_object_items, ExportFormat},
+    file::{
+        available_file_path, copy_to_clipboard, data_uri, exists_file, move_file, open_file,
+        partial_file_path, remove_file, save_binary, save_error_log, temp_file_path,
+        verify_file_checksum,
     },
-    file::{copy_to_clipboard, save_binary, save_error_log},
-    if_match,
-    object::{AppObjects, BucketItem, FileDetail, ObjectItem, ObjectKey, RawObject},
+    if_match, key_code, key_code_char,
+    object::{AppObjects, BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject},
     pages::page::{Page, PageStack},
+    ui::common::{
+        format_size_byte, set_datetime_format, set_highlight_style, set_management_console,
+        set_no_color, set_relative_datetime, set_size_units, size_format_options,
+    },
+    util::{format_eta, ByteRange, TimeRange, TransferRate},
 };
 
+// enough for the preview pane, which shows only the first lines
+const HEAD_PREVIEW_MAX_BYTES: usize = 8 * 1024;
+
+// the credentials are loaded again automatically at most once in this interval
+const CREDENTIALS_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 pub enum Notification {
     None,
@@ -24,10 +55,22 @@ pub enum Notification {
     Error(String),
 }
 
+#[derive(Debug)]
+pub enum AppDialog {
+    None,
+    OverwriteConfirm(CompleteDownloadObjectResult),
+    QuitConfirm,
+    CredentialsExpired,
+}
+
 #[derive(Debug)]
 pub struct AppViewState {
     pub notification: Notification,
+    // the error notification is only the summary, so the whole message is kept to copy it
+    pub error_full_msg: Option<String>,
     pub is_loading: bool,
+    pub loading_frame: usize,
+    pub dialog: AppDialog,
 
     width: usize,
     height: usize,
@@ -37,7 +80,10 @@ impl AppViewState {
     fn new(width: usize, height: usize) -> AppViewState {
         AppViewState {
             notification: Notification::None,
+            error_full_msg: None,
             is_loading: true,
+            loading_frame: 0,
+            dialog: AppDialog::None,
             width,
             height,
         }
@@ -47,25 +93,62 @@ impl AppViewState {
         self.width = width;
         self.height = height;
     }
+
+    pub fn tick(&mut self) {
+        if self.is_loading {
+            self.loading_frame = self.loading_frame.wrapping_add(1);
+        } else {
+            self.loading_frame = 0;
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct App {
     pub app_view_state: AppViewState,
     pub page_stack: PageStack,
+    // only the page stacks are kept per tab, app_view_state (loading, notification) is shared
+    inactive_tabs: Vec<PageStack>,
+    current_tab: usize,
     app_objects: AppObjects,
     client: Option<Arc<Client>>,
+    credentials_reloaded_at: Option<Instant>,
+    account_id: Option<String>,
+    // the bucket specified on start, only it is loaded again on reload
+    bucket: Option<String>,
+    restore_path: Vec<String>,
+    // the file to open after the folders of restore_path
+    restore_file: Option<String>,
+    // the version of restore_file to select in the detail
+    restore_version: Option<String>,
+    // shared by the requests made for each bucket, not to send too many at once with many buckets
+    bucket_load_permits: Arc<Semaphore>,
     config: Config,
     tx: Sender,
 }
 
 impl App {
     pub fn new(config: Config, tx: Sender, width: usize, height: usize) -> App {
+        set_relative_datetime(config.time.relative);
+        set_datetime_format(config.time.format.clone());
+        set_size_units(config.size.units);
+        set_no_color(config.no_color);
+        set_management_console(!config.no_management_console);
+        set_highlight_style(config.theme.highlight.style());
         App {
             app_view_state: AppViewState::new(width, height),
             app_objects: AppObjects::default(),
             page_stack: PageStack::new(tx.clone()),
+            inactive_tabs: Vec::new(),
+            current_tab: 0,
             client: None,
+            credentials_reloaded_at: None,
+            account_id: None,
+            bucket: None,
+            restore_path: Vec::new(),
+            restore_file: None,
+            restore_version: None,
+            bucket_load_permits: Arc::new(Semaphore::new(config.list.concurrency.max(1))),
             config,
             tx,
         }
@@ -73,7 +156,21 @@ impl App {
 
     pub fn initialize(&mut self, client: Client, bucket: Option<String>) {
         self.client = Some(Arc::new(client));
+        self.bucket = bucket;
+
+        // the list is shown as soon as the names are loaded, the rest is filled in later
+        self.spawn_load_buckets(AppEventType::CompleteInitialize);
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let account_id = client.whoami().await;
+            let result = CompleteLoadAccountIdResult::new(account_id);
+            tx.send(AppEventType::CompleteLoadAccountId(result));
+        });
+    }
 
+    fn spawn_load_buckets(&self, event: fn(Result<CompleteInitializeResult>) -> AppEventType) {
+        let bucket = self.bucket.clone();
         let (client, tx) = self.unwrap_client_tx();
         spawn(async move {
             let buckets = match bucket {
@@ -81,33 +178,324 @@ impl App {
                 None => client.load_all_buckets().await,
             };
             let result = CompleteInitializeResult::new(buckets);
-            tx.send(AppEventType::CompleteInitialize(result));
+            tx.send(event(result));
         });
     }
 
+    pub fn reload_buckets(&mut self) {
+        self.spawn_load_buckets(AppEventType::CompleteReloadBuckets);
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_reload_buckets(&mut self, result: Result<CompleteInitializeResult>) {
+        match result {
+            Ok(CompleteInitializeResult { buckets }) => {
+                self.app_objects.set_bucket_items(buckets.clone());
+                for page_stack in self.all_tabs_mut() {
+                    if let Page::BucketList(page) = page_stack.head_mut() {
+                        page.replace_items(buckets.clone());
+                    }
+                }
+
+                self.load_bucket_regions();
+                if self.config.list.bucket_versioning {
+                    self.load_bucket_versionings();
+                }
+                if self.config.list.public_access {
+                    self.load_bucket_publics();
+                }
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn complete_load_account_id(&mut self, result: Result<CompleteLoadAccountIdResult>) {
+        // the account id is only for display, so ignore the error (e.g. access denied)
+        if let Ok(CompleteLoadAccountIdResult { account_id }) = result {
+            self.account_id = Some(account_id);
+        }
+    }
+
     pub fn complete_initialize(&mut self, result: Result<CompleteInitializeResult>) {
         match result {
             Ok(CompleteInitializeResult { buckets }) => {
                 self.app_objects.set_bucket_items(buckets);
 
-                let bucket_list_page = Page::of_bucket_list(self.bucket_items(), self.tx.clone());
+                let bucket_list_page = Page::of_bucket_list(
+                    self.bucket_items(),
+                    self.config.list.clone(),
+                    self.tx.clone(),
+                );
                 self.page_stack.pop(); // remove initializing page
                 self.page_stack.push(bucket_list_page);
+
+                self.load_bucket_regions();
+                if self.config.list.bucket_versioning {
+                    self.load_bucket_versionings();
+                }
+                if self.config.list.public_access {
+                    self.load_bucket_publics();
+                }
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
             }
         }
 
-        if self.bucket_items().len() == 1 {
+        if self.restore_session() {
+            // since continues to load object, is_loading is not reset.
+        } else if self.is_single_bucket_entered() {
             // bucket name is specified, or if there is only one bucket, open it.
             // since continues to load object, is_loading is not reset.
-            self.bucket_list_move_down();
+            self.enter_single_bucket();
         } else {
             self.app_view_state.is_loading = false;
         }
     }
 
+    fn restore_session(&mut self) -> bool {
+        if !self.config.session.restore {
+            return false;
+        }
+        // the session state is only a convenience, so ignore the error
+        let Ok(SessionState { bucket, path }) = self.config.load_session_state() else {
+            return false;
+        };
+        let Some(bucket) = bucket else {
+            return false;
+        };
+        let Page::BucketList(page) = self.page_stack.head_mut() else {
+            return false;
+        };
+        if !page.select_item_by_name(&bucket) {
+            // the bucket no longer exists
+            return false;
+        }
+        self.restore_path = path;
+        self.bucket_list_move_down();
+        true
+    }
+
+    // the default prefix is opened in the same way as restoring the session
+    fn enter_single_bucket(&mut self) {
+        self.restore_path = self
+            .config
+            .single_bucket
+            .default_prefix
+            .as_deref()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+        self.bucket_list_move_down();
+        if matches!(self.page_stack.current_page(), Page::ObjectList(_)) {
+            // the top folder has been already loaded
+            self.continue_restore_session();
+        }
+    }
+
+    fn continue_restore_session(&mut self) {
+        // the folders already loaded are opened immediately, so continue until one has to be loaded
+        while !self.restore_path.is_empty() {
+            let name = self.restore_path.remove(0);
+            let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+                self.clear_restore_path();
+                return;
+            };
+            if !page.select_dir_by_name(&name) {
+                // the prefix no longer exists, stay at the deepest existing one
+                self.clear_restore_path();
+                let msg = format!("Prefix not found: {}/", name);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+                return;
+            }
+            let len = self.page_stack.len();
+            self.object_list_move_down();
+            if self.page_stack.len() == len {
+                // the page is pushed after loading
+                return;
+            }
+        }
+
+        let Some(name) = self.restore_file.take() else {
+            return;
+        };
+        let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        if page.select_file_by_name(&name) {
+            self.object_list_move_down();
+        } else {
+            self.restore_version = None;
+            let msg = format!("Object not found: {}", name);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        }
+    }
+
+    fn clear_restore_path(&mut self) {
+        self.restore_path.clear();
+        self.restore_file = None;
+        self.restore_version = None;
+    }
+
+    pub fn save_session(&self) {
+        if !self.config.session.restore {
+            return;
+        }
+        if !matches!(self.page_stack.head(), Page::BucketList(_)) {
+            // not initialized yet
+            return;
+        }
+        let object_list_pages: Vec<_> = self
+            .page_stack
+            .iter()
+            .filter_map(|page| if_match! { page: Page::ObjectList(p) => p })
+            .collect();
+        let state = match object_list_pages.split_last() {
+            Some((_, parents)) => {
+                // the item selected in the last object list page is not opened
+                let path = parents
+                    .iter()
+                    .map(|page| page.current_selected_item())
+                    .filter_map(
+                        |item| if_match! { item: ObjectItem::Dir { name, .. } => name.clone() },
+                    )
+                    .collect();
+                SessionState {
+                    bucket: Some(self.current_bucket()),
+                    path,
+                }
+            }
+            None => SessionState::default(),
+        };
+        if let Err(e) = self.config.save_session_state(state) {
+            self.handle_error(&AppError::msg(format!("{:#}", e)));
+        }
+    }
+
+    // the requests wait for a permit, so at most `list.concurrency` of them are sent at a time
+    fn spawn_for_each_bucket<F, Fut>(&self, f: F)
+    where
+        F: Fn(Arc<Client>, Sender, String) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        for item in self.bucket_items() {
+            let (client, tx) = self.unwrap_client_tx();
+            let permits = self.bucket_load_permits.clone();
+            let fut = f(client, tx, item.name);
+            spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                fut.await;
+            });
+        }
+    }
+
+    fn load_bucket_regions(&self) {
+        // regions are loaded lazily so as not to slow down the initial load
+        self.spawn_for_each_bucket(|client, tx, name| async move {
+            let region = client.load_bucket_region(&name).await;
+            let result = CompleteLoadBucketRegionResult::new(name, region);
+            tx.send(AppEventType::CompleteLoadBucketRegion(result));
+        });
+    }
+
+    pub fn complete_load_bucket_region(&mut self, result: Result<CompleteLoadBucketRegionResult>) {
+        // the region is only for display, so ignore the error and leave it blank
+        if let Ok(CompleteLoadBucketRegionResult { name, region }) = result {
+            self.app_objects.set_bucket_region(&name, region.clone());
+            for page_stack in self.all_tabs_mut() {
+                if let Page::BucketList(page) = page_stack.head_mut() {
+                    page.set_bucket_region(&name, region.clone());
+                }
+            }
+        }
+    }
+
+    fn load_bucket_versionings(&self) {
+        self.spawn_for_each_bucket(|client, tx, name| async move {
+            let versioning = client.load_bucket_versioning(&name).await;
+            let result = CompleteLoadBucketVersioningResult::new(name, versioning);
+            tx.send(AppEventType::CompleteLoadBucketVersioning(result));
+        });
+    }
+
+    pub fn complete_load_bucket_versioning(
+        &mut self,
+        result: Result<CompleteLoadBucketVersioningResult>,
+    ) {
+        // same as the region, leave it blank if failed
+        if let Ok(CompleteLoadBucketVersioningResult { name, versioning }) = result {
+            self.app_objects
+                .set_bucket_versioning(&name, versioning.clone());
+            for page_stack in self.all_tabs_mut() {
+                if let Page::BucketList(page) = page_stack.head_mut() {
+                    page.set_bucket_versioning(&name, versioning.clone());
+                }
+            }
+        }
+    }
+
+    fn load_bucket_publics(&self) {
+        self.spawn_for_each_bucket(|client, tx, name| async move {
+            let public = client.load_bucket_public(&name).await;
+            let result = CompleteLoadBucketPublicResult::new(name, public);
+            tx.send(AppEventType::CompleteLoadBucketPublic(result));
+        });
+    }
+
+    pub fn complete_load_bucket_public(&mut self, result: Result<CompleteLoadBucketPublicResult>) {
+        // not shown as public if failed, the same as when the check is denied
+        if let Ok(CompleteLoadBucketPublicResult { name, public }) = result {
+            self.app_objects.set_bucket_public(&name, public);
+            for page_stack in self.all_tabs_mut() {
+                if let Page::BucketList(page) = page_stack.head_mut() {
+                    page.set_bucket_public(&name, public);
+                }
+            }
+        }
+    }
+
+    pub fn read_only(&self) -> bool {
+        self.config.read_only
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.client.as_ref().map(|c| c.region())
+    }
+
+    pub fn account_id(&self) -> Option<&str> {
+        self.account_id.as_deref()
+    }
+
+    pub fn status_bar_enabled(&self) -> bool {
+        self.config.status_bar
+    }
+
+    pub fn min_terminal_size(&self) -> (u16, u16) {
+        (
+            self.config.terminal.min_width,
+            self.config.terminal.min_height,
+        )
+    }
+
+    // the bucket and the number of folders opened in it, none while the bucket list is shown
+    pub fn current_location(&self) -> Option<(String, usize)> {
+        let object_list_count = self
+            .page_stack
+            .iter()
+            .filter(|page| matches!(page, Page::ObjectList(_)))
+            .count();
+        if object_list_count == 0 {
+            return None;
+        }
+        // the first object list is the top folder of the bucket
+        Some((self.current_bucket(), object_list_count - 1))
+    }
+
     pub fn resize(&mut self, width: usize, height: usize) {
         self.app_view_state.reset_size(width, height);
     }
@@ -156,6 +544,11 @@ impl App {
         self.app_objects.get_bucket_items()
     }
 
+    // the only bucket is opened on start, and the bucket list is not shown
+    fn is_single_bucket_entered(&self) -> bool {
+        self.config.single_bucket.auto_enter && self.bucket_items().len() == 1
+    }
+
     fn current_object_items(&self) -> Option<Vec<ObjectItem>> {
         self.app_objects
             .get_object_items(&self.current_object_key())
@@ -164,8 +557,7 @@ impl App {
     pub fn bucket_list_move_down(&mut self) {
         if let Some(current_object_items) = self.current_object_items() {
             // object list has been already loaded
-            let object_list_page = Page::of_object_list(current_object_items, self.tx.clone());
-            self.page_stack.push(object_list_page);
+            self.push_object_list_page(current_object_items);
         } else {
             self.tx.send(AppEventType::LoadObjects);
             self.app_view_state.is_loading = true;
@@ -184,14 +576,7 @@ impl App {
 
                 if let (Some(detail), Some(versions)) = (detail, versions) {
                     // object has been already loaded
-                    let object_detail_page = Page::of_object_detail(
-                        detail.clone(),
-                        versions.clone(),
-                        object_page.object_list(),
-                        object_page.list_state(),
-                        self.tx.clone(),
-                    );
-                    self.page_stack.push(object_detail_page);
+                    self.push_object_detail_page(detail.clone(), versions.clone());
                 } else {
                     self.tx.send(AppEventType::LoadObject);
                     self.app_view_state.is_loading = true;
@@ -200,9 +585,7 @@ impl App {
             ObjectItem::Dir { .. } => {
                 if let Some(current_object_items) = self.current_object_items() {
                     // object list has been already loaded
-                    let object_list_page =
-                        Page::of_object_list(current_object_items, self.tx.clone());
-                    self.page_stack.push(object_list_page);
+                    self.push_object_list_page(current_object_items);
                 } else {
                     self.tx.send(AppEventType::LoadObjects);
                     self.app_view_state.is_loading = true;
@@ -211,41 +594,261 @@ impl App {
         }
     }
 
+    fn push_object_list_page(&mut self, items: Vec<ObjectItem>) {
+        let mut object_list_page =
+            Page::of_object_list(items, self.config.list.clone(), self.tx.clone());
+        if let Page::ObjectList(page) = &mut object_list_page {
+            page.set_prefix(self.current_object_prefix());
+            // only the top folder of the bucket has the remembered filter
+            if self.config.list.remember_filter && self.page_stack.len() == 1 {
+                if let Some(filter) = self.app_objects.get_filter(&self.current_bucket()) {
+                    page.set_filter(filter);
+                }
+            }
+            let next_token = self.app_objects.get_next_token(&self.current_object_key());
+            page.set_next_token(next_token.map(String::from));
+            if let Some(list_state) = self.app_objects.get_list_state(&self.current_object_key()) {
+                // restore the position when the list was last left
+                page.set_list_state(list_state);
+            }
+        }
+        self.page_stack.push(object_list_page);
+    }
+
     pub fn object_list_move_up(&mut self) {
-        if self.page_stack.len() == 2 /* bucket list and object list */ && self.bucket_items().len() == 1
+        if self.page_stack.len() == 2 /* bucket list and object list */ && self.is_single_bucket_entered()
         {
             return;
         }
-        self.page_stack.pop();
+        if let Page::ObjectList(page) = self.page_stack.pop() {
+            // after popping, the current key points to the popped list
+            if !page.is_flat() {
+                self.app_objects
+                    .set_list_state(self.current_object_key(), page.list_state());
+            }
+            if self.page_stack.len() == 1 {
+                self.remember_filter(page.filter());
+            }
+        }
+    }
+
+    pub fn back_to_bucket_root(&mut self) {
+        // the first object list is the top folder of the bucket, and is kept even if there is only one bucket
+        while self.page_stack.len() > 2
+        /* bucket list and object list */
+        {
+            self.object_list_move_up();
+        }
     }
 
     pub fn back_to_bucket_list(&mut self) {
-        if self.bucket_items().len() == 1 {
+        if self.is_single_bucket_entered() {
             return;
         }
+        let filter = match self.page_stack.iter().nth(1) {
+            Some(Page::ObjectList(page)) => Some(page.filter().to_string()),
+            _ => None,
+        };
+        if let Some(filter) = filter {
+            self.remember_filter(&filter);
+        }
         self.page_stack.clear();
     }
 
+    // the filter of the top folder is remembered, and forgotten if it was cleared with Esc
+    fn remember_filter(&mut self, filter: &str) {
+        if self.config.list.remember_filter {
+            self.app_objects.set_filter(self.current_bucket(), filter);
+        }
+    }
+
     pub fn load_objects(&self) {
         let bucket = self.current_bucket();
         let prefix = self.current_object_prefix();
+        self.spawn_load_objects(bucket, prefix, false, AppEventType::CompleteLoadObjects);
+    }
+
+    fn spawn_load_objects(
+        &self,
+        bucket: String,
+        prefix: String,
+        flat: bool,
+        event: fn(Result<CompleteLoadObjectsResult>) -> AppEventType,
+    ) {
+        let max_keys = self.config.list.max_keys();
+        let load_all_pages = self.config.list.load_all_pages;
         let (client, tx) = self.unwrap_client_tx();
         spawn(async move {
-            let items = client.load_objects(&bucket, &prefix).await;
-            let result = CompleteLoadObjectsResult::new(items);
-            tx.send(AppEventType::CompleteLoadObjects(result));
+            let result = if load_all_pages {
+                let items = client.load_objects(&bucket, &prefix, max_keys, flat).await;
+                CompleteLoadObjectsResult::new(items, flat)
+            } else {
+                let page = client
+                    .load_objects_page(&bucket, &prefix, max_keys, flat, None)
+                    .await;
+                CompleteLoadObjectsResult::new_page(page, flat)
+            };
+            tx.send(event(result));
         });
     }
 
-    pub fn complete_load_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+    // the current key and prefix point to the selected item while the list is shown,
+    // so they are taken with the list popped temporarily
+    fn with_current_list_popped<T>(&mut self, f: impl FnOnce(&Self) -> T) -> T {
+        let page = self.page_stack.pop();
+        let t = f(self);
+        self.page_stack.push(page);
+        t
+    }
+
+    pub fn object_list_toggle_flat(&mut self) {
+        let object_page = self.page_stack.current_page().as_object_list();
+        if object_page.is_flat() {
+            // the hierarchical list has been loaded before switching to the flat one
+            self.page_stack.pop();
+            match self.current_object_items() {
+                Some(items) => self.push_object_list_page(items),
+                None => {
+                    self.tx.send(AppEventType::LoadObjects);
+                    self.app_view_state.is_loading = true;
+                }
+            }
+            return;
+        }
+
+        let (bucket, prefix) = self
+            .with_current_list_popped(|app| (app.current_bucket(), app.current_object_prefix()));
+        self.spawn_load_objects(bucket, prefix, true, AppEventType::CompleteLoadObjects);
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn object_list_load_more(&mut self) {
+        let object_page = self.page_stack.current_page().as_object_list();
+        let Some(token) = object_page.next_token().map(String::from) else {
+            return;
+        };
+        let flat = object_page.is_flat();
+
+        let (bucket, prefix) = self
+            .with_current_list_popped(|app| (app.current_bucket(), app.current_object_prefix()));
+        let max_keys = self.config.list.max_keys();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let page = client
+                .load_objects_page(&bucket, &prefix, max_keys, flat, Some(token))
+                .await;
+            let result = CompleteLoadObjectsResult::new_page(page, flat);
+            tx.send(AppEventType::CompleteLoadMoreObjects(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_load_more_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
         match result {
-            Ok(CompleteLoadObjectsResult { items }) => {
-                self.app_objects
-                    .set_object_items(self.current_object_key().to_owned(), items.clone());
+            Ok(CompleteLoadObjectsResult {
+                items,
+                flat,
+                next_token,
+            }) => {
+                if !flat {
+                    let key = self.with_current_list_popped(|app| app.current_object_key());
+                    if let Some(mut cached_items) = self.app_objects.get_object_items(&key) {
+                        cached_items.extend(items.clone());
+                        self.app_objects.set_object_items(key.clone(), cached_items);
+                    }
+                    self.app_objects.set_next_token(key, next_token.clone());
+                }
 
-                let object_list_page = Page::of_object_list(items, self.tx.clone());
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.append_items(items, next_token);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn complete_load_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+        match result {
+            Ok(CompleteLoadObjectsResult {
+                items,
+                flat: true,
+                next_token,
+            }) => {
+                // the flat list replaces the current one, and is not cached as it is loaded on each switch
+                self.page_stack.pop();
+                let mut object_list_page =
+                    Page::of_object_list(items, self.config.list.clone(), self.tx.clone());
+                if let Page::ObjectList(page) = &mut object_list_page {
+                    page.set_flat(true);
+                    page.set_prefix(self.current_object_prefix());
+                    page.set_next_token(next_token);
+                }
                 self.page_stack.push(object_list_page);
             }
+            Ok(CompleteLoadObjectsResult {
+                items, next_token, ..
+            }) => {
+                let key = self.current_object_key();
+                self.app_objects.set_next_token(key.clone(), next_token);
+                self.app_objects.set_object_items(key, items.clone());
+
+                self.push_object_list_page(items);
+            }
+            Err(e) => {
+                self.clear_restore_path();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+
+        self.continue_restore_session();
+    }
+
+    // the cached list is not used, unlike moving into the folder
+    pub fn object_list_reload(&mut self) {
+        self.tx.send(AppEventType::ReloadObjects);
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn reload_objects(&mut self) {
+        let flat = self.page_stack.current_page().as_object_list().is_flat();
+        let (bucket, prefix) = self
+            .with_current_list_popped(|app| (app.current_bucket(), app.current_object_prefix()));
+        self.spawn_load_objects(bucket, prefix, flat, AppEventType::CompleteReloadObjects);
+    }
+
+    pub fn complete_reload_objects(&mut self, result: Result<CompleteLoadObjectsResult>) {
+        match result {
+            Ok(CompleteLoadObjectsResult {
+                items,
+                flat,
+                next_token,
+            }) => {
+                let (key, file_keys) = self.with_current_list_popped(|app| {
+                    let file_keys: Vec<ObjectKey> = items
+                        .iter()
+                        .filter_map(
+                            |item| if_match! { item: ObjectItem::File { name, .. } => name },
+                        )
+                        .map(|name| app.current_object_key_with_name(name.to_string()))
+                        .collect();
+                    (app.current_object_key(), file_keys)
+                });
+                // the files may have been updated as well as the list
+                for file_key in &file_keys {
+                    self.app_objects.invalidate_object_detail(file_key);
+                }
+                if !flat {
+                    self.app_objects
+                        .set_next_token(key.clone(), next_token.clone());
+                    self.app_objects.set_object_items(key, items.clone());
+                }
+
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.replace_items(items, next_token);
+            }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
             }
@@ -254,6 +857,10 @@ impl App {
     }
 
     pub fn load_object(&self) {
+        self.load_selected_object(AppEventType::CompleteLoadObject);
+    }
+
+    fn load_selected_object(&self, event: fn(Result<CompleteLoadObjectResult>) -> AppEventType) {
         let object_page = self.page_stack.current_page().as_object_list();
 
         if let ObjectItem::File {
@@ -269,14 +876,23 @@ impl App {
 
             let map_key = self.current_object_key_with_name(name.to_string());
 
+            let public_access = self.config.list.public_access;
+
             let (client, tx) = self.unwrap_client_tx();
             spawn(async move {
-                let detail = client
+                let mut detail = client
                     .load_object_detail(&bucket, &key, &name, size_byte)
                     .await;
+                if let (Ok(detail), true) = (&mut detail, public_access) {
+                    // the check is only for a warning, so it does not fail the detail
+                    detail.public = client
+                        .load_object_public(&bucket, &key)
+                        .await
+                        .unwrap_or_default();
+                }
                 let versions = client.load_object_versions(&bucket, &key).await;
                 let result = CompleteLoadObjectResult::new(detail, versions, map_key);
-                tx.send(AppEventType::CompleteLoadObject(result));
+                tx.send(event(result));
             });
         }
     }
@@ -290,17 +906,7 @@ impl App {
             }) => {
                 self.app_objects
                     .set_object_details(map_key, *detail.clone(), versions.clone());
-
-                let object_page = self.page_stack.current_page().as_object_list();
-
-                let object_detail_page = Page::of_object_detail(
-                    *detail.clone(),
-                    versions.clone(),
-                    object_page.object_list(),
-                    object_page.list_state(),
-                    self.tx.clone(),
-                );
-                self.page_stack.push(object_detail_page);
+                self.push_object_detail_page(*detail, versions);
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
@@ -309,32 +915,174 @@ impl App {
         self.app_view_state.is_loading = false;
     }
 
-    pub fn open_help(&mut self) {
-        let helps = match self.page_stack.current_page() {
-            Page::Initializing(_) | Page::Help(_) => {
-                return;
+    fn push_object_detail_page(&mut self, detail: FileDetail, versions: Vec<FileVersion>) {
+        let object_page = self.page_stack.current_page().as_object_list();
+        let mut object_detail_page = Page::of_object_detail(
+            detail,
+            versions,
+            object_page.object_list(),
+            object_page.list_state(),
+            self.config.download_base_dir(),
+            self.config.list.clone(),
+            self.config.detail.clone(),
+            self.config.clipboard.clone(),
+            self.tx.clone(),
+        );
+        if let Page::ObjectDetail(page) = &mut object_detail_page {
+            page.set_key_prefix(object_page.key_prefix().map(String::from));
+            if let Some(version_id) = self.restore_version.take() {
+                if !page.select_version(&version_id) {
+                    let msg = format!("Version not found: {}", version_id);
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
             }
-            Page::BucketList(page) => page.helps(),
-            Page::ObjectList(page) => page.helps(),
-            Page::ObjectDetail(page) => page.helps(),
-            Page::ObjectPreview(page) => page.helps(),
-        };
-        let help_page = Page::of_help(helps, self.tx.clone());
-        self.page_stack.push(help_page);
+        }
+        self.page_stack.push(object_detail_page);
     }
 
-    pub fn close_current_page(&mut self) {
-        self.page_stack.pop();
-    }
+    pub fn peek_object(&mut self) {
+        let object_page = self.page_stack.current_page().as_object_list();
+        let ObjectItem::File { name, .. } = object_page.current_selected_item() else {
+            return;
+        };
+        let map_key = self.current_object_key_with_name(name.to_string());
 
-    pub fn detail_download_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
-        self.tx
+        if let Some(detail) = self.app_objects.get_object_detail(&map_key) {
+            // object has been already loaded
+            let detail = detail.clone();
+            if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+                page.set_peek_detail(detail);
+            }
+        } else {
+            // loading is shown in the dialog, so is_loading is not set
+            self.load_selected_object(AppEventType::CompletePeekObject);
+        }
+    }
+
+    pub fn complete_peek_object(&mut self, result: Result<CompleteLoadObjectResult>) {
+        match result {
+            Ok(CompleteLoadObjectResult {
+                detail,
+                versions,
+                map_key,
+            }) => {
+                // cache it so that the detail page can be opened immediately
+                self.app_objects
+                    .set_object_details(map_key, *detail.clone(), versions);
+
+                if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+                    page.set_peek_detail(*detail);
+                }
+            }
+            Err(e) => {
+                if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+                    page.close_peek_dialog();
+                }
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn open_help(&mut self) {
+        let helps = match self.page_stack.current_page() {
+            Page::Initializing(_) | Page::Help(_) => {
+                return;
+            }
+            Page::BucketList(page) => page.helps(),
+            Page::ObjectList(page) => page.helps(),
+            Page::ObjectDetail(page) => page.helps(),
+            Page::ObjectPreview(page) => page.helps(),
+        };
+        let help_page = Page::of_help(helps, self.tx.clone());
+        self.page_stack.push(help_page);
+    }
+
+    pub fn open_new_tab(&mut self) {
+        if matches!(self.page_stack.head(), Page::Initializing(_)) {
+            return;
+        }
+        let mut page_stack = PageStack::new(self.tx.clone());
+        page_stack.pop(); // remove initializing page
+        page_stack.push(Page::of_bucket_list(
+            self.bucket_items(),
+            self.config.list.clone(),
+            self.tx.clone(),
+        ));
+        let index = self.inactive_tabs.len() + 1;
+        self.inactive_tabs.push(page_stack);
+        self.switch_tab(index);
+    }
+
+    pub fn next_tab(&mut self) {
+        let count = self.inactive_tabs.len() + 1;
+        self.switch_tab((self.current_tab + 1) % count);
+    }
+
+    pub fn close_current_tab(&mut self) {
+        if self.inactive_tabs.is_empty() {
+            let msg = "The last tab cannot be closed".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        // the next tab takes the place of the closed one, or the previous if it was the last
+        let index = self.current_tab.min(self.inactive_tabs.len() - 1);
+        self.page_stack = self.inactive_tabs.remove(index);
+        self.current_tab = index;
+    }
+
+    fn switch_tab(&mut self, index: usize) {
+        if index == self.current_tab {
+            return;
+        }
+        // the active tab is held in page_stack, so the other tabs are kept in order without it
+        let page_stack = std::mem::replace(&mut self.page_stack, PageStack::new(self.tx.clone()));
+        self.inactive_tabs.insert(self.current_tab, page_stack);
+        self.page_stack = self.inactive_tabs.remove(index);
+        self.current_tab = index;
+    }
+
+    pub fn tabs(&self) -> Vec<&PageStack> {
+        let mut tabs: Vec<&PageStack> = self.inactive_tabs.iter().collect();
+        tabs.insert(self.current_tab, &self.page_stack);
+        tabs
+    }
+
+    pub fn current_tab(&self) -> usize {
+        self.current_tab
+    }
+
+    fn all_tabs_mut(&mut self) -> impl Iterator<Item = &mut PageStack> {
+        std::iter::once(&mut self.page_stack).chain(self.inactive_tabs.iter_mut())
+    }
+
+    pub fn close_current_page(&mut self) {
+        self.page_stack.pop();
+    }
+
+    pub fn detail_download_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
+        self.tx
             .send(AppEventType::DownloadObject(file_detail, version_id));
         self.app_view_state.is_loading = true;
     }
 
+    pub fn detail_download_and_open_object(
+        &mut self,
+        file_detail: FileDetail,
+        version_id: Option<String>,
+    ) {
+        self.tx
+            .send(AppEventType::DownloadAndOpenObject(file_detail, version_id));
+        self.app_view_state.is_loading = true;
+    }
+
     pub fn preview_download_object(&self, obj: RawObject, path: String) {
-        let result = CompleteDownloadObjectResult::new(Ok(obj), path);
+        let result = CompleteDownloadObjectResult::new(Ok(DownloadedObject::Bytes(obj)), path);
+        self.tx.send(AppEventType::CompleteDownloadObject(result));
+    }
+
+    pub fn preview_download_and_open_object(&self, obj: RawObject, path: String) {
+        let obj = Ok(DownloadedObject::Bytes(obj));
+        let result = CompleteDownloadObjectResult::new_and_open(obj, path);
         self.tx.send(AppEventType::CompleteDownloadObject(result));
     }
 
@@ -344,19 +1092,53 @@ impl App {
         self.app_view_state.is_loading = true;
     }
 
+    pub fn open_preview_range(
+        &mut self,
+        file_detail: FileDetail,
+        version_id: Option<String>,
+        range: ByteRange,
+    ) {
+        // the range is loaded into memory like the preview, so it is limited in the same way
+        let max_bytes = self.config.preview.max_bytes.max(1);
+        let range = if range.length > max_bytes {
+            self.tx.send(AppEventType::NotifyWarn(format!(
+                "The range is limited to {} bytes (preview.max_bytes)",
+                max_bytes
+            )));
+            ByteRange {
+                length: max_bytes,
+                ..range
+            }
+        } else {
+            range
+        };
+        self.tx.send(AppEventType::PreviewObjectRange(
+            file_detail,
+            version_id,
+            range,
+        ));
+        self.app_view_state.is_loading = true;
+    }
+
     pub fn download_object(&self, file_detail: FileDetail, version_id: Option<String>) {
-        let object_name = file_detail.name;
-        let size_byte = file_detail.size_byte;
+        let path = self.download_file_path(&file_detail.name, None);
+        self.download_object_to_file_and(
+            &file_detail,
+            path,
+            version_id,
+            false,
+            AppEventType::CompleteDownloadObject,
+        )
+    }
 
-        self.download_object_and(
-            &object_name,
-            size_byte,
-            None,
+    pub fn download_and_open_object(&self, file_detail: FileDetail, version_id: Option<String>) {
+        let path = self.download_file_path(&file_detail.name, None);
+        self.download_object_to_file_and(
+            &file_detail,
+            path,
             version_id,
-            |tx, obj, path| {
-                let result = CompleteDownloadObjectResult::new(obj, path);
-                tx.send(AppEventType::CompleteDownloadObject(result));
-            },
+            true,
+            AppEventType::CompleteDownloadObject,
         )
     }
 
@@ -366,32 +1148,132 @@ impl App {
         input: String,
         version_id: Option<String>,
     ) {
-        let object_name = file_detail.name;
-        let size_byte = file_detail.size_byte;
-
-        self.download_object_and(
-            &object_name,
-            size_byte,
-            Some(&input),
+        let path = self.download_file_path(&file_detail.name, Some(&input));
+        self.download_object_to_file_and(
+            &file_detail,
+            path,
             version_id,
-            |tx, obj, path| {
-                let result = CompleteDownloadObjectResult::new(obj, path);
-                tx.send(AppEventType::CompleteDownloadObject(result));
-            },
+            false,
+            AppEventType::CompleteDownloadObject,
         )
     }
 
     pub fn complete_download_object(&mut self, result: Result<CompleteDownloadObjectResult>) {
-        let result = match result {
-            Ok(CompleteDownloadObjectResult { obj, path }) => {
-                save_binary(&path, &obj.bytes).map(|_| path)
+        match result {
+            Ok(result) if exists_file(&result.path) => match self.config.download.on_conflict {
+                DownloadConflictAction::Overwrite => {
+                    self.save_downloaded_object(result);
+                }
+                DownloadConflictAction::Rename => {
+                    self.save_downloaded_object_with_new_name(result);
+                }
+                DownloadConflictAction::Prompt => {
+                    self.app_view_state.dialog = AppDialog::OverwriteConfirm(result);
+                }
+            },
+            Ok(result) => {
+                self.save_downloaded_object(result);
             }
-            Err(e) => Err(e),
-        };
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    // the marked files are downloaded one by one, without asking about the existing files
+    pub fn download_objects(&mut self, items: Vec<ObjectItem>) {
+        let (bucket, prefix) = self
+            .with_current_list_popped(|app| (app.current_bucket(), app.current_object_prefix()));
+        let files: Vec<(String, String, usize, String)> = items
+            .iter()
+            .filter_map(|item| match item {
+                ObjectItem::File {
+                    name, size_byte, ..
+                } => {
+                    let key = format!("{}{}", prefix, name);
+                    let path = self.config.default_download_file_path(&bucket, &key);
+                    Some((name.clone(), key, *size_byte, path))
+                }
+                ObjectItem::Dir { .. } => None,
+            })
+            .collect();
+        let overwrite = self.config.download.on_conflict == DownloadConflictAction::Overwrite;
+
+        let (client, tx) = self.unwrap_client_tx();
+        let total = files.len();
+        let total_size = files.iter().map(|(_, _, size_byte, _)| size_byte).sum();
+        let loading = Mutex::new(self.handle_loading_size(total_size, tx.clone()));
+        spawn(async move {
+            let mut saved = Vec::new();
+            let mut downloaded = 0;
+            for (name, key, size_byte, path) in files {
+                let partial_path = partial_file_path(&path);
+                let loading = &loading;
+                let f = move |n| (loading.lock().unwrap())(downloaded + n);
+                let result = client
+                    .download_object_to_file(&bucket, &key, None, &partial_path, f)
+                    .await
+                    .and_then(|_| {
+                        let path = if overwrite || !exists_file(&path) {
+                            path
+                        } else {
+                            available_file_path(&path)
+                        };
+                        move_file(&partial_path, &path).map(|_| path)
+                    });
+                match result {
+                    Ok(path) => saved.push((name, path)),
+                    Err(e) => {
+                        // the files saved so far are reported, so that they are not downloaded again
+                        let result =
+                            CompleteDownloadObjectsResult::new(saved, total, Some((key, e)));
+                        tx.send(AppEventType::CompleteDownloadObjects(result));
+                        return;
+                    }
+                }
+                downloaded += size_byte;
+            }
+            let result = CompleteDownloadObjectsResult::new(saved, total, None);
+            tx.send(AppEventType::CompleteDownloadObjects(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_download_objects(&mut self, result: Result<CompleteDownloadObjectsResult>) {
         match result {
-            Ok(path) => {
-                let msg = format!("Download completed successfully: {}", path);
-                self.tx.send(AppEventType::NotifySuccess(msg));
+            Ok(CompleteDownloadObjectsResult {
+                saved,
+                total,
+                failed,
+            }) => {
+                if let Page::ObjectList(page) = self.page_stack.current_page_mut() {
+                    let names: Vec<&str> = saved.iter().map(|(name, _)| name.as_str()).collect();
+                    page.unmark_items(&names);
+                }
+                let dir = self.config.download_base_dir();
+                match failed {
+                    Some((key, e)) => {
+                        let msg = format!(
+                            "Downloaded {} of {} objects to {}, but failed to download {}: {}",
+                            saved.len(),
+                            total,
+                            dir,
+                            key,
+                            e.msg
+                        );
+                        let e = AppError { msg, ..e };
+                        self.tx.send(AppEventType::NotifyError(e));
+                    }
+                    None => {
+                        let msg = format!(
+                            "Download completed successfully: {} objects to {}",
+                            saved.len(),
+                            dir
+                        );
+                        self.tx.send(AppEventType::NotifySuccess(msg));
+                    }
+                }
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
@@ -400,6 +1282,100 @@ impl App {
         self.app_view_state.is_loading = false;
     }
 
+    pub fn overwrite_downloaded_object(&mut self) {
+        if let AppDialog::OverwriteConfirm(result) = self.take_dialog() {
+            self.save_downloaded_object(result);
+        }
+    }
+
+    pub fn rename_downloaded_object(&mut self) {
+        if let AppDialog::OverwriteConfirm(result) = self.take_dialog() {
+            self.save_downloaded_object_with_new_name(result);
+        }
+    }
+
+    pub fn cancel_downloaded_object(&mut self) {
+        if let AppDialog::OverwriteConfirm(result) = self.take_dialog() {
+            if let DownloadedObject::File(path) = result.obj {
+                let _ = remove_file(&path);
+            }
+            let msg = "Download cancelled".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        }
+    }
+
+    fn save_downloaded_object_with_new_name(&self, result: CompleteDownloadObjectResult) {
+        let path = available_file_path(&result.path);
+        self.save_downloaded_object(CompleteDownloadObjectResult { path, ..result });
+    }
+
+    fn save_downloaded_object(&self, result: CompleteDownloadObjectResult) {
+        let path = result.path.clone();
+        match self.write_and_open_downloaded_object(result) {
+            Ok(Some(warning)) => self.notify_download_warning(&warning, &path),
+            Ok(None) => {
+                let msg = format!("Download completed successfully: {}", path);
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    // returns the warning of the download to be shown instead of the success message
+    fn write_and_open_downloaded_object(
+        &self,
+        result: CompleteDownloadObjectResult,
+    ) -> Result<Option<String>> {
+        let CompleteDownloadObjectResult {
+            obj,
+            path,
+            open,
+            warning,
+        } = result;
+        write_downloaded_object(obj, &path)?;
+        if open {
+            let command = self.config.open.command.as_deref();
+            open_file(&path, command)?;
+        }
+        Ok(warning)
+    }
+
+    fn notify_download_warning(&self, warning: &str, path: &str) {
+        let msg = format!("Download completed, but {}: {}", warning, path);
+        self.tx.send(AppEventType::NotifyWarn(msg));
+    }
+
+    // returns whether to quit now, otherwise the confirmation is shown
+    pub fn request_quit(&mut self) -> bool {
+        if self.config.confirm_quit {
+            self.app_view_state.dialog = AppDialog::QuitConfirm;
+            return false;
+        }
+        true
+    }
+
+    // returns whether the quit is confirmed
+    pub fn handle_quit_confirm_key(&mut self, key: KeyEvent) -> bool {
+        match key {
+            key_code_char!('y') | key_code!(KeyCode::Enter) => true,
+            key_code_char!('n') | key_code!(KeyCode::Esc) => {
+                self.close_quit_confirm();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn close_quit_confirm(&mut self) {
+        if let AppDialog::QuitConfirm = self.app_view_state.dialog {
+            self.app_view_state.dialog = AppDialog::None;
+        }
+    }
+
+    fn take_dialog(&mut self) -> AppDialog {
+        std::mem::replace(&mut self.app_view_state.dialog, AppDialog::None)
+    }
+
     pub fn preview_object(&self, file_detail: FileDetail, version_id: Option<String>) {
         let object_name = file_detail.name.clone();
         let size_byte = file_detail.size_byte;
@@ -409,6 +1385,7 @@ impl App {
             size_byte,
             None,
             version_id.clone(),
+            Some(self.config.preview.max_bytes),
             |tx, obj, path| {
                 let result = CompletePreviewObjectResult::new(obj, file_detail, version_id, path);
                 tx.send(AppEventType::CompletePreviewObject(result));
@@ -429,7 +1406,9 @@ impl App {
                     file_version_id,
                     obj,
                     path,
+                    self.config.download_base_dir(),
                     self.config.preview.clone(),
+                    self.config.clipboard.clone(),
                     self.tx.clone(),
                 );
                 self.page_stack.push(object_preview_page);
@@ -442,82 +1421,710 @@ impl App {
         self.app_view_state.is_loading = false;
     }
 
-    fn download_object_and<F>(
+    pub fn preview_object_range(
         &self,
-        object_name: &str,
-        size_byte: usize,
-        save_file_name: Option<&str>,
+        file_detail: FileDetail,
         version_id: Option<String>,
-        f: F,
-    ) where
-        F: FnOnce(Sender, Result<RawObject>, String) + Send + 'static,
-    {
+        range: ByteRange,
+    ) {
         let bucket = self.current_bucket();
-        let prefix = self.current_object_prefix();
-        let key = format!("{}{}", prefix, object_name);
-
-        let path = self
-            .config
-            .download_file_path(save_file_name.unwrap_or(object_name));
+        let key = self.current_object_key_string(&file_detail.name);
+        // the range is saved separately so as not to be mistaken for the whole object
+        let file_name = format!("{}.{}-{}", file_detail.name, range.offset, range.end());
+        let path = self.download_file_path(&file_detail.name, Some(&file_name));
 
         let (client, tx) = self.unwrap_client_tx();
-        let loading = self.handle_loading_size(size_byte, tx.clone());
         spawn(async move {
             let obj = client
-                .download_object(&bucket, &key, version_id, size_byte, loading)
+                .download_object_range(&bucket, &key, version_id.clone(), range)
                 .await;
-            f(tx, obj, path);
+            let result =
+                CompletePreviewObjectRangeResult::new(obj, file_detail, version_id, range, path);
+            tx.send(AppEventType::CompletePreviewObjectRange(result));
         });
     }
 
-    fn handle_loading_size(&self, total_size: usize, tx: Sender) -> Box<dyn Fn(usize) + Send> {
-        if total_size < 10_000_000 {
-            return Box::new(|_| {});
-        }
-        let decimal_places = if total_size > 1_000_000_000 { 1 } else { 0 };
-        let opt =
-            humansize::FormatSizeOptions::from(humansize::DECIMAL).decimal_places(decimal_places);
-        let total_s = humansize::format_size_i(total_size, opt);
-        let f = move |current| {
-            let percent = (current * 100) / total_size;
-            let cur_s = humansize::format_size_i(current, opt);
-            let msg = format!("{:3}% downloaded ({} out of {})", percent, cur_s, total_s);
-            tx.send(AppEventType::NotifyInfo(msg));
+    pub fn complete_preview_object_range(
+        &mut self,
+        result: Result<CompletePreviewObjectRangeResult>,
+    ) {
+        match result {
+            Ok(CompletePreviewObjectRangeResult {
+                obj,
+                file_detail,
+                file_version_id,
+                range,
+                path,
+            }) => {
+                let mut object_preview_page = Page::of_object_preview(
+                    file_detail,
+                    file_version_id,
+                    obj,
+                    path,
+                    self.config.download_base_dir(),
+                    self.config.preview.clone(),
+                    self.config.clipboard.clone(),
+                    self.tx.clone(),
+                );
+                if let Page::ObjectPreview(page) = &mut object_preview_page {
+                    page.set_range(range);
+                }
+                self.page_stack.push(object_preview_page);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
         };
-        Box::new(f)
+        self.app_view_state.is_loading = false;
     }
 
-    pub fn bucket_list_open_management_console(&self) {
-        let (client, _) = self.unwrap_client_tx();
-        let result = client.open_management_console_buckets();
-        if let Err(e) = result {
-            self.tx.send(AppEventType::NotifyError(e));
+    pub fn tick_tail(&mut self) {
+        let Page::ObjectPreview(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        if !page.poll_tail(Instant::now()) {
+            return;
         }
-    }
+        let (name, current_size) = page.tail_target();
 
-    pub fn object_list_open_management_console(&self) {
-        let (client, _) = self.unwrap_client_tx();
-        let bucket = &self.current_bucket();
-        let prefix = self.current_object_prefix();
-        let result = client.open_management_console_list(bucket, &prefix);
-        if let Err(e) = result {
-            self.tx.send(AppEventType::NotifyError(e));
-        }
+        let bucket = self.current_bucket();
+        let key = self.current_object_key_string(&name);
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let update = tail_object(&client, &bucket, &key, current_size).await;
+            let result = CompleteTailObjectResult::new(name, update);
+            tx.send(AppEventType::CompleteTailObject(result));
+        });
     }
 
-    pub fn object_detail_open_management_console(&self, name: String) {
-        let (client, _) = self.unwrap_client_tx();
-        let prefix = self.current_object_prefix();
+    pub fn tick_head_preview(&mut self) {
+        let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        let Some(name) = page.poll_head_preview(Instant::now()) else {
+            return;
+        };
 
-        let result = client.open_management_console_object(&self.current_bucket(), &prefix, &name);
-        if let Err(e) = result {
-            self.tx.send(AppEventType::NotifyError(e));
-        }
+        let bucket = self.current_bucket();
+        let key = self.current_object_key_string(&name);
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let head = client
+                .load_object_head(&bucket, &key, HEAD_PREVIEW_MAX_BYTES)
+                .await;
+            let result = CompleteLoadObjectHeadResult::new(name, head);
+            tx.send(AppEventType::CompleteLoadObjectHead(result));
+        });
     }
 
-    pub fn detail_download_object_as(
-        &mut self,
-        file_detail: FileDetail,
+    pub fn complete_load_object_head(&mut self, result: Result<CompleteLoadObjectHeadResult>) {
+        // the list may have been left while loading
+        let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        match result {
+            Ok(CompleteLoadObjectHeadResult { name, head }) => {
+                page.set_head_preview(&name, head);
+            }
+            Err(e) => {
+                // shown in the pane, not to interrupt moving through the list
+                page.set_head_preview_error(&e.msg);
+            }
+        }
+    }
+
+    pub fn complete_tail_object(&mut self, result: Result<CompleteTailObjectResult>) {
+        // the preview may have been closed while loading
+        let Page::ObjectPreview(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        match result {
+            Ok(CompleteTailObjectResult { name, update }) => {
+                page.complete_tail(&name, update);
+            }
+            Err(e) => {
+                page.stop_tail();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    fn download_object_and<F>(
+        &self,
+        object_name: &str,
+        size_byte: usize,
+        save_file_name: Option<&str>,
+        version_id: Option<String>,
+        max_bytes: Option<usize>,
+        f: F,
+    ) where
+        F: FnOnce(Sender, Result<RawObject>, String) + Send + 'static,
+    {
+        let bucket = self.current_bucket();
+        let key = self.current_object_key_string(object_name);
+        let path = self.download_file_path(object_name, save_file_name);
+
+        let (client, tx) = self.unwrap_client_tx();
+        let loading_size = max_bytes.map_or(size_byte, |max| size_byte.min(max));
+        let loading = self.handle_loading_size(loading_size, tx.clone());
+        spawn(async move {
+            let obj = client
+                .download_object(&bucket, &key, version_id, size_byte, max_bytes, loading)
+                .await;
+            f(tx, obj, path);
+        });
+    }
+
+    // the object is streamed to a partial file, and moved to the path when it is saved
+    fn download_object_to_file_and(
+        &self,
+        file_detail: &FileDetail,
+        path: String,
+        version_id: Option<String>,
+        open: bool,
+        event: fn(Result<CompleteDownloadObjectResult>) -> AppEventType,
+    ) {
+        let bucket = self.current_bucket();
+        let key = self.current_object_key_string(&file_detail.name);
+        let partial_path = partial_file_path(&path);
+        let checksum = self
+            .config
+            .download
+            .verify_checksum
+            .then(|| expected_checksum(file_detail, version_id.as_deref()))
+            .flatten();
+
+        let (client, tx) = self.unwrap_client_tx();
+        let loading = self.handle_loading_size(file_detail.size_byte, tx.clone());
+        spawn(async move {
+            let result = client
+                .download_object_to_file(&bucket, &key, version_id, &partial_path, loading)
+                .await;
+            let warning = match (result.is_ok(), checksum) {
+                (true, Some((algorithm, expected))) => {
+                    let path = partial_path.clone();
+                    tokio::task::spawn_blocking(move || {
+                        checksum_warning(&path, &algorithm, &expected)
+                    })
+                    .await
+                    .unwrap_or_default()
+                }
+                _ => None,
+            };
+            let obj = result.map(|_| DownloadedObject::File(partial_path));
+            let result = if open {
+                CompleteDownloadObjectResult::new_and_open(obj, path)
+            } else {
+                CompleteDownloadObjectResult::new(obj, path)
+            };
+            tx.send(event(result.map(|r| r.with_warning(warning))));
+        });
+    }
+
+    fn current_object_key_string(&self, object_name: &str) -> String {
+        format!("{}{}", self.current_object_prefix(), object_name)
+    }
+
+    fn download_file_path(&self, object_name: &str, save_file_name: Option<&str>) -> String {
+        match save_file_name {
+            Some(name) => self.config.download_file_path(name),
+            None => {
+                let key = self.current_object_key_string(object_name);
+                self.config
+                    .default_download_file_path(&self.current_bucket(), &key)
+            }
+        }
+    }
+
+    fn handle_loading_size(&self, total_size: usize, tx: Sender) -> Box<dyn Fn(usize) + Send> {
+        if total_size < 10_000_000 {
+            return Box::new(|_| {});
+        }
+        let decimal_places = if total_size > 1_000_000_000 { 1 } else { 0 };
+        let opt = size_format_options().decimal_places(decimal_places);
+        let total_s = humansize::format_size_i(total_size, opt);
+        let rate = Mutex::new(TransferRate::new(total_size));
+        let f = move |current| {
+            let percent = (current * 100) / total_size;
+            let cur_s = humansize::format_size_i(current, opt);
+            let mut rate = rate.lock().unwrap();
+            rate.update(Instant::now(), current);
+            let msg = match (rate.rate(), rate.eta()) {
+                (Some(r), Some(eta)) => format!(
+                    "{:3}% downloaded ({} out of {}, {}/s, ~{} left)",
+                    percent,
+                    cur_s,
+                    total_s,
+                    humansize::format_size_i(r as usize, opt),
+                    format_eta(eta)
+                ),
+                _ => format!("{:3}% downloaded ({} out of {})", percent, cur_s, total_s),
+            };
+            tx.send(AppEventType::NotifyInfo(msg));
+        };
+        Box::new(f)
+    }
+
+    pub fn open_in_external_app(&mut self, file_detail: FileDetail, version_id: Option<String>) {
+        let path = match temp_file_path(&file_detail.name) {
+            Ok(path) => path,
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
+        };
+        self.download_object_to_file_and(
+            &file_detail,
+            path,
+            version_id,
+            true,
+            AppEventType::CompleteOpenInExternalApp,
+        );
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_open_in_external_app(&mut self, result: Result<CompleteDownloadObjectResult>) {
+        match result {
+            Ok(result) => self.save_and_open_object(result),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn preview_open_in_external_app(&self, obj: RawObject, name: String) {
+        // object has been already downloaded
+        let path = match temp_file_path(&name) {
+            Ok(path) => path,
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
+        };
+        let obj = DownloadedObject::Bytes(obj);
+        self.save_and_open_object(CompleteDownloadObjectResult {
+            obj,
+            path,
+            open: true,
+            warning: None,
+        });
+    }
+
+    // the file is only opened, so nothing is notified unless there is a warning
+    fn save_and_open_object(&self, result: CompleteDownloadObjectResult) {
+        let path = result.path.clone();
+        match self.write_and_open_downloaded_object(result) {
+            Ok(Some(warning)) => self.notify_download_warning(&warning, &path),
+            Ok(None) => {}
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    pub fn bucket_list_open_management_console(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let result = client.open_management_console_buckets();
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
+    pub fn bucket_list_copy_management_console_url(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        match client.management_console_buckets_url() {
+            Ok(url) => self.copy_to_clipboard("Management console URL".to_string(), url),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    pub fn object_list_open_management_console(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let bucket = &self.current_bucket();
+        let prefix = self.current_object_prefix();
+        let result = client.open_management_console_list(bucket, &prefix);
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
+    pub fn object_list_copy_management_console_url(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let bucket = &self.current_bucket();
+        let prefix = self.current_object_prefix();
+        match client.management_console_list_url(bucket, &prefix) {
+            Ok(url) => self.copy_to_clipboard("Management console URL".to_string(), url),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    pub fn bucket_list_copy_s3_uri(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let uri = client.build_s3_uri(&self.current_bucket(), "");
+        self.copy_to_clipboard("S3 URI".to_string(), uri);
+    }
+
+    pub fn bucket_list_load_policy(&self) {
+        let name = self.current_bucket();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let policy = client.load_bucket_policy(&name).await;
+            let acl = client.load_bucket_acl(&name).await;
+            let result = CompleteLoadBucketPolicyResult::new(name, policy, acl);
+            tx.send(AppEventType::CompleteBucketListLoadPolicy(result));
+        });
+    }
+
+    pub fn complete_bucket_list_load_policy(
+        &mut self,
+        result: Result<CompleteLoadBucketPolicyResult>,
+    ) {
+        // the bucket list may not be the current page if the dialog was closed and a bucket opened
+        let Page::BucketList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        match result {
+            Ok(CompleteLoadBucketPolicyResult { name, policy, acl }) => {
+                page.set_policy(&name, &policy, &acl);
+            }
+            Err(e) => {
+                page.close_policy_dialog();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn bucket_list_load_properties(&self) {
+        let item = self
+            .page_stack
+            .head()
+            .as_bucket_list()
+            .current_selected_item()
+            .clone();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            // the properties already loaded are cached in the item
+            let region = match item.region {
+                Some(region) => Ok(region),
+                None => client.load_bucket_region(&item.name).await,
+            };
+            let versioning = match item.versioning {
+                Some(versioning) => Ok(versioning),
+                None => client.load_bucket_versioning(&item.name).await,
+            };
+            let encryption = match item.encryption {
+                Some(encryption) => Ok(encryption),
+                None => client.load_bucket_encryption(&item.name).await,
+            };
+            let result =
+                CompleteLoadBucketPropertiesResult::new(item.name, region, versioning, encryption);
+            tx.send(AppEventType::CompleteBucketListLoadProperties(result));
+        });
+    }
+
+    pub fn complete_bucket_list_load_properties(
+        &mut self,
+        result: Result<CompleteLoadBucketPropertiesResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadBucketPropertiesResult {
+                name,
+                region,
+                versioning,
+                encryption,
+            }) => {
+                if let Some(region) = &region {
+                    self.app_objects.set_bucket_region(&name, region.clone());
+                }
+                if let Some(versioning) = &versioning {
+                    self.app_objects
+                        .set_bucket_versioning(&name, versioning.clone());
+                }
+                if let Some(encryption) = &encryption {
+                    self.app_objects
+                        .set_bucket_encryption(&name, encryption.clone());
+                }
+                for page_stack in self.all_tabs_mut() {
+                    if let Page::BucketList(page) = page_stack.head_mut() {
+                        page.set_bucket_properties(
+                            &name,
+                            region.clone(),
+                            versioning.clone(),
+                            encryption.clone(),
+                        );
+                    }
+                }
+            }
+            Err(e) => {
+                if let Page::BucketList(page) = self.page_stack.current_page_mut() {
+                    page.close_properties_dialog();
+                }
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn object_list_copy_s3_uri(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let uri = client.build_s3_uri(&self.current_bucket(), &self.current_object_prefix());
+        self.copy_to_clipboard("S3 URI".to_string(), uri);
+    }
+
+    pub fn object_list_copy_key(&self) {
+        let key = self.selected_object_key_string();
+        self.copy_to_clipboard("Key".to_string(), key);
+    }
+
+    // the prefix with a trailing slash if a dir is selected
+    fn selected_object_key_string(&self) -> String {
+        let page = self.page_stack.current_page().as_object_list();
+        match page.current_selected_item() {
+            ObjectItem::File { name, .. } => self.current_object_key_string(name),
+            // the current path already includes the selected dir
+            ObjectItem::Dir { .. } => self.current_object_prefix(),
+        }
+    }
+
+    pub fn object_list_count_objects(&mut self) {
+        let bucket = self.current_bucket();
+        let prefix = self.current_object_prefix();
+        let concurrency = self.config.list.concurrency;
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let keys = client
+                .load_objects_recursive(&bucket, &prefix, concurrency)
+                .await;
+            let result = CompleteCountObjectsResult::new(prefix, keys);
+            tx.send(AppEventType::CompleteCountObjects(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_count_objects(&mut self, result: Result<CompleteCountObjectsResult>) {
+        match result {
+            Ok(CompleteCountObjectsResult { prefix, keys }) => {
+                let msg = format!("{} objects under {}", keys.len(), prefix);
+                self.tx.send(AppEventType::NotifyInfo(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.app_view_state.is_loading = false;
+    }
+
+    pub fn export_listing(&self, format: ExportFormat) {
+        let items = self
+            .page_stack
+            .current_page()
+            .as_object_list()
+            .object_list();
+        let name = format!(
+            "{}_{}.{}",
+            self.current_bucket(),
+            Local::now().format("%Y%m%d%H%M%S"),
+            format.extension()
+        );
+        let path = self.config.download_file_path(&name);
+
+        let result =
+            export_object_items(&items, format).and_then(|s| save_binary(&path, s.as_bytes()));
+        match result {
+            Ok(_) => {
+                let msg = format!("Exported listing successfully: {}", path);
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn object_detail_open_management_console(&self, name: String) {
+        let (client, _) = self.unwrap_client_tx();
+        let prefix = self.current_object_prefix();
+
+        let result = client.open_management_console_object(&self.current_bucket(), &prefix, &name);
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
+    pub fn object_detail_open_parent_management_console(&self) {
+        let (client, _) = self.unwrap_client_tx();
+        let prefix = self.current_object_prefix();
+        let result = client.open_management_console_list(&self.current_bucket(), &prefix);
+        if let Err(e) = result {
+            self.tx.send(AppEventType::NotifyError(e));
+        }
+    }
+
+    pub fn object_detail_copy_management_console_url(&self, name: String) {
+        let (client, _) = self.unwrap_client_tx();
+        let prefix = self.current_object_prefix();
+        match client.management_console_object_url(&self.current_bucket(), &prefix, &name) {
+            Ok(url) => self.copy_to_clipboard("Management console URL".to_string(), url),
+            Err(e) => self.tx.send(AppEventType::NotifyError(e)),
+        }
+    }
+
+    pub fn object_detail_load_acl(&self, key: String, version_id: Option<String>) {
+        let bucket = self.current_bucket();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let acl = client.load_object_acl(&bucket, &key, version_id).await;
+            let result = CompleteLoadObjectAclResult::new(key, acl);
+            tx.send(AppEventType::CompleteObjectDetailLoadAcl(result));
+        });
+    }
+
+    pub fn complete_object_detail_load_acl(&mut self, result: Result<CompleteLoadObjectAclResult>) {
+        // the detail page may be closed before the ACL is loaded
+        let Page::ObjectDetail(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        match result {
+            Ok(CompleteLoadObjectAclResult { key, acl }) => {
+                page.set_acl(&key, &acl);
+            }
+            Err(e) => {
+                page.close_acl_dialog();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn object_list_load_prefix_versions(&self, range: TimeRange) {
+        let bucket = self.current_bucket();
+        let prefix = self.current_object_prefix();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let versions = client
+                .list_object_versions_by_prefix(&bucket, &prefix, range)
+                .await;
+            let result = CompleteLoadPrefixVersionsResult::new(prefix, versions);
+            tx.send(AppEventType::CompleteObjectListLoadPrefixVersions(result));
+        });
+    }
+
+    pub fn complete_object_list_load_prefix_versions(
+        &mut self,
+        result: Result<CompleteLoadPrefixVersionsResult>,
+    ) {
+        let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        match result {
+            Ok(CompleteLoadPrefixVersionsResult { prefix, versions }) => {
+                page.set_prefix_versions(&prefix, versions);
+            }
+            Err(e) => {
+                page.close_prefix_versions_dialog();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    // the key is relative to the current prefix, and the folders to the object are opened in turn
+    pub fn object_list_open_prefix_version(&mut self, key: String, version_id: String) {
+        let Page::ObjectList(page) = self.page_stack.current_page_mut() else {
+            return;
+        };
+        page.close_prefix_versions_dialog();
+        self.restore_version = Some(version_id);
+        if page.is_flat() {
+            // the flat list has the objects under the sub folders as is
+            self.restore_path = Vec::new();
+            self.restore_file = Some(key);
+        } else {
+            let mut path: Vec<String> = key.split('/').map(String::from).collect();
+            self.restore_file = path.pop();
+            self.restore_path = path;
+        }
+        self.continue_restore_session();
+    }
+
+    pub fn load_multipart_uploads(&self) {
+        let bucket = self.current_bucket();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let uploads = client.list_multipart_uploads(&bucket).await;
+            let result = CompleteLoadMultipartUploadsResult::new(bucket, uploads);
+            tx.send(AppEventType::CompleteLoadMultipartUploads(result));
+        });
+    }
+
+    pub fn complete_load_multipart_uploads(
+        &mut self,
+        result: Result<CompleteLoadMultipartUploadsResult>,
+    ) {
+        // the dialog can be opened from both the bucket list and the object list
+        match (self.page_stack.current_page_mut(), result) {
+            (
+                Page::BucketList(page),
+                Ok(CompleteLoadMultipartUploadsResult { bucket, uploads }),
+            ) => {
+                page.set_multipart_uploads(&bucket, uploads);
+            }
+            (
+                Page::ObjectList(page),
+                Ok(CompleteLoadMultipartUploadsResult { bucket, uploads }),
+            ) => {
+                page.set_multipart_uploads(&bucket, uploads);
+            }
+            (Page::BucketList(page), Err(e)) => {
+                page.close_multipart_uploads_dialog();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+            (Page::ObjectList(page), Err(e)) => {
+                page.close_multipart_uploads_dialog();
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn abort_multipart_upload(&mut self, key: String, upload_id: String) {
+        if self.read_only() {
+            let msg = "Multipart uploads cannot be aborted in read-only mode".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+
+        let bucket = self.current_bucket();
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = client
+                .abort_multipart_upload(&bucket, &key, &upload_id)
+                .await;
+            let result = CompleteAbortMultipartUploadResult::new(key, upload_id, result);
+            tx.send(AppEventType::CompleteAbortMultipartUpload(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_abort_multipart_upload(
+        &mut self,
+        result: Result<CompleteAbortMultipartUploadResult>,
+    ) {
+        self.app_view_state.is_loading = false;
+
+        match result {
+            Ok(CompleteAbortMultipartUploadResult { key, upload_id }) => {
+                match self.page_stack.current_page_mut() {
+                    Page::BucketList(page) => page.remove_multipart_upload(&upload_id),
+                    Page::ObjectList(page) => page.remove_multipart_upload(&upload_id),
+                    _ => {}
+                }
+                let msg = format!("Aborted multipart upload successfully: {}", key);
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    pub fn detail_download_object_as(
+        &mut self,
+        file_detail: FileDetail,
         input: String,
         version_id: Option<String>,
     ) {
@@ -550,7 +2157,7 @@ impl App {
     }
 
     pub fn copy_to_clipboard(&self, name: String, value: String) {
-        match copy_to_clipboard(value) {
+        match copy_to_clipboard(value, self.config.clipboard.backend) {
             Ok(_) => {
                 let msg = format!("Copied '{}' to clipboard successfully", name);
                 self.tx.send(AppEventType::NotifySuccess(msg));
@@ -561,6 +2168,42 @@ impl App {
         }
     }
 
+    pub fn copy_as_data_uri(&mut self, file_detail: FileDetail) {
+        let object_name = file_detail.name.clone();
+        let size_byte = file_detail.size_byte;
+
+        self.download_object_and(&object_name, size_byte, None, None, None, |tx, obj, _| {
+            let result = CompleteCopyAsDataUriResult::new(obj, file_detail);
+            tx.send(AppEventType::CompleteCopyAsDataUri(result));
+        });
+        self.app_view_state.is_loading = true;
+    }
+
+    pub fn complete_copy_as_data_uri(&mut self, result: Result<CompleteCopyAsDataUriResult>) {
+        self.clear_notification();
+        self.app_view_state.is_loading = false;
+
+        match result {
+            Ok(CompleteCopyAsDataUriResult { obj, file_detail }) => {
+                // the object may have been overwritten after the detail was loaded
+                let max_size = self.config.clipboard.data_uri_max_size;
+                if obj.bytes.len() > max_size {
+                    let msg = format!(
+                        "Object is larger than expected ({}), not copied as a data URI",
+                        format_size_byte(obj.bytes.len())
+                    );
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                    return;
+                }
+                let value = data_uri(&file_detail.content_type, &obj.bytes);
+                self.copy_to_clipboard("Data URI".into(), value);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
     pub fn clear_notification(&mut self) {
         self.app_view_state.notification = Notification::None;
     }
@@ -579,7 +2222,79 @@ impl App {
 
     pub fn error_notification(&mut self, e: AppError) {
         self.handle_error(&e);
-        self.app_view_state.notification = Notification::Error(e.msg);
+        self.notify_error(e);
+    }
+
+    fn notify_error(&mut self, e: AppError) {
+        if e.is_expired_credentials() && self.client.is_some() {
+            let recently_reloaded = self
+                .credentials_reloaded_at
+                .is_some_and(|t| t.elapsed() < CREDENTIALS_RELOAD_INTERVAL);
+            if !recently_reloaded {
+                // the credentials may have been refreshed outside (e.g. `aws sso login`)
+                self.reload_credentials();
+                let msg = "Credentials have expired, reloading them".to_string();
+                self.app_view_state.notification = Notification::Warn(msg);
+                return;
+            }
+            if let AppDialog::None = self.app_view_state.dialog {
+                self.app_view_state.dialog = AppDialog::CredentialsExpired;
+            }
+        }
+        self.app_view_state.notification = Notification::Error(e.summary());
+        self.app_view_state.error_full_msg = Some(e.full_msg());
+    }
+
+    // the error is copied with the details and the log file, to paste it into a bug report
+    fn notification_text(&self) -> Option<String> {
+        match &self.app_view_state.notification {
+            Notification::None => None,
+            Notification::Info(msg) | Notification::Success(msg) | Notification::Warn(msg) => {
+                Some(msg.clone())
+            }
+            Notification::Error(msg) => {
+                let msg = self.app_view_state.error_full_msg.as_ref().unwrap_or(msg);
+                match self.config.error_log_path() {
+                    Ok(path) => Some(format!("{}\nSee {} for more details", msg, path)),
+                    Err(_) => Some(msg.clone()),
+                }
+            }
+        }
+    }
+
+    pub fn copy_notification(&mut self) {
+        match self.notification_text() {
+            Some(text) => self.copy_to_clipboard("Notification".into(), text),
+            None => {
+                let msg = "No notification to copy".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
+    pub fn reload_credentials(&mut self) {
+        if let AppDialog::CredentialsExpired = self.app_view_state.dialog {
+            self.app_view_state.dialog = AppDialog::None;
+        }
+        self.credentials_reloaded_at = Some(Instant::now());
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let client = client.reload().await;
+            tx.send(AppEventType::CompleteReloadCredentials(client));
+        });
+    }
+
+    pub fn complete_reload_credentials(&mut self, client: Client) {
+        self.client = Some(Arc::new(client));
+        let msg = "Credentials have been reloaded, please try again".to_string();
+        self.tx.send(AppEventType::NotifyInfo(msg));
+    }
+
+    pub fn close_credentials_expired(&mut self) {
+        if let AppDialog::CredentialsExpired = self.app_view_state.dialog {
+            self.app_view_state.dialog = AppDialog::None;
+        }
     }
 
     fn handle_error(&self, e: &AppError) {
@@ -598,3 +2313,793 @@ impl App {
         (self.client.as_ref().unwrap().clone(), self.tx.clone())
     }
 }
+
+async fn tail_object(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    current_size: usize,
+) -> Result<TailUpdate> {
+    let size = client.load_object_size(bucket, key).await?;
+    match size.cmp(&current_size) {
+        Ordering::Equal => Ok(TailUpdate::Unchanged),
+        Ordering::Greater => client
+            .download_object_from(bucket, key, current_size)
+            .await
+            .map(|obj| TailUpdate::Appended(obj.bytes)),
+        Ordering::Less => client
+            .download_object(bucket, key, None, size, None, |_| {})
+            .await
+            .map(TailUpdate::Reloaded),
+    }
+}
+
+// the detail is of the version loaded with it, so the checksum of another version is unknown
+fn expected_checksum(
+    file_detail: &FileDetail,
+    version_id: Option<&str>,
+) -> Option<(String, String)> {
+    if file_detail.checksum.is_empty() {
+        return None;
+    }
+    if version_id.is_some_and(|id| id != file_detail.version_id) {
+        return None;
+    }
+    Some((
+        file_detail.checksum_algorithm.clone(),
+        file_detail.checksum.clone(),
+    ))
+}
+
+// the mismatch is only warned, as the object may have been modified after the detail was loaded
+fn checksum_warning(path: &str, algorithm: &str, expected: &str) -> Option<String> {
+    match verify_file_checksum(path, algorithm, expected) {
+        Ok(Some(false)) => Some(format!("the {} checksum does not match", algorithm)),
+        Ok(_) => None,
+        Err(e) => Some(format!("the checksum could not be verified ({})", e.msg)),
+    }
+}
+
+fn write_downloaded_object(obj: DownloadedObject, path: &str) -> Result<()> {
+    match obj {
+        DownloadedObject::Bytes(obj) => save_binary(path, &obj.bytes),
+        DownloadedObject::File(partial_path) => move_file(&partial_path, path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent};
+    use ratatui::{backend::TestBackend, Terminal};
+    use rstest::rstest;
+
+    use crate::{
+        config::{ListConfig, PreviewConfig, SingleBucketConfig},
+        error::ErrorDetails,
+        event,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_app_view_state_tick() {
+        let mut state = AppViewState::new(100, 30);
+
+        // the frame does not advance while not loading
+        state.is_loading = false;
+        state.tick();
+        assert_eq!(state.loading_frame, 0);
+
+        state.is_loading = true;
+        state.tick();
+        state.tick();
+        assert_eq!(state.loading_frame, 2);
+
+        // the header takes the frame modulo the number of the spinner frames
+        state.loading_frame = usize::MAX;
+        state.tick();
+        assert_eq!(state.loading_frame, 0);
+
+        // reset when the loading finishes
+        state.tick();
+        state.is_loading = false;
+        state.tick();
+        assert_eq!(state.loading_frame, 0);
+    }
+
+    #[test]
+    fn test_restore_list_position_when_moving_back() {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b", "c"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["c"]), dir_items(&["x", "y"]));
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+
+        app.bucket_list_move_down();
+        select(&mut app, "c");
+        app.object_list_move_down();
+        select(&mut app, "y");
+
+        // the parent list keeps its position
+        app.object_list_move_up();
+        assert_eq!(selected_index(&app), 2);
+
+        // entering the same list again restores the position
+        app.object_list_move_down();
+        assert_eq!(selected_index(&app), 1);
+
+        app.object_list_move_up();
+        app.object_list_move_up();
+        app.bucket_list_move_down();
+        assert_eq!(selected_index(&app), 2);
+    }
+
+    #[test]
+    fn test_back_to_bucket_root() {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b", "c"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["c"]), dir_items(&["x", "y"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["c", "y"]), dir_items(&["z"]));
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+
+        app.bucket_list_move_down();
+        select(&mut app, "c");
+        app.object_list_move_down();
+        select(&mut app, "y");
+        app.object_list_move_down();
+        assert_eq!(app.page_stack.len(), 4);
+
+        app.back_to_bucket_root();
+        assert_eq!(app.page_stack.len(), 2);
+        assert_eq!(selected_index(&app), 2);
+
+        // already at the top folder, and the single bucket is not left
+        app.back_to_bucket_root();
+        assert_eq!(app.page_stack.len(), 2);
+    }
+
+    #[rstest]
+    #[case(true, 2)]
+    #[case(false, 1)]
+    fn test_single_bucket_auto_enter(#[case] auto_enter: bool, #[case] expected_len: usize) {
+        let (tx, _rx) = event::new();
+        let config = Config {
+            single_bucket: SingleBucketConfig {
+                auto_enter,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut app = App::new(config, tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+
+        // the bucket list can be shown only if the bucket is not entered automatically
+        app.bucket_list_move_down();
+        app.object_list_move_up();
+        assert_eq!(app.page_stack.len(), expected_len);
+
+        app.page_stack.clear();
+        app.bucket_list_move_down();
+        app.back_to_bucket_list();
+        assert_eq!(app.page_stack.len(), expected_len);
+    }
+
+    #[rstest]
+    #[case("b/c/", 4, None)]
+    #[case("/b//c", 4, None)]
+    #[case("b/x/y/", 3, Some("Prefix not found: x/"))]
+    #[case("", 2, None)]
+    fn test_enter_single_bucket_default_prefix(
+        #[case] default_prefix: &str,
+        #[case] expected_len: usize,
+        #[case] expected_warn: Option<&str>,
+    ) {
+        let (tx, rx) = event::new();
+        let config = Config {
+            single_bucket: SingleBucketConfig {
+                default_prefix: Some(default_prefix.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut app = App::new(config, tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["b"]), dir_items(&["c", "d"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["b", "c"]), dir_items(&["e"]));
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+
+        app.enter_single_bucket();
+        assert_eq!(app.page_stack.len(), expected_len);
+        assert!(app.restore_path.is_empty());
+
+        if let Some(expected_warn) = expected_warn {
+            rx.recv_find(
+                |event| matches!(event, AppEventType::NotifyWarn(msg) if msg == expected_warn),
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_prefix_version() {
+        let (tx, rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+        let mut items = dir_items(&["c"]);
+        items.push(ObjectItem::File {
+            name: "file1.txt".to_string(),
+            size_byte: 1024,
+            last_modified: chrono::Local::now(),
+        });
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["b"]), items);
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+        app.bucket_list_move_down();
+
+        // the folders already loaded are opened, and then the object is loaded
+        app.object_list_open_prefix_version("b/file1.txt".to_string(), "v1".to_string());
+        assert_eq!(app.page_stack.len(), 3);
+        assert_eq!(selected_index(&app), 1);
+        rx.recv_find(|event| matches!(event, AppEventType::LoadObject));
+
+        // the version is selected when the detail is opened
+        let versions = ["v2", "v1"]
+            .map(|version_id| FileVersion {
+                version_id: version_id.to_string(),
+                size_byte: 1024,
+                last_modified: chrono::Local::now(),
+                is_latest: version_id == "v2",
+                is_delete_marker: false,
+            })
+            .to_vec();
+        let map_key = object_key("bucket-1", &["b", "file1.txt"]);
+        let result =
+            CompleteLoadObjectResult::new(Ok(FileDetail::default()), Ok(versions), map_key);
+        app.complete_load_object(result);
+        assert_eq!(app.page_stack.len(), 4);
+        assert!(app.restore_version.is_none());
+
+        // the folder is not loaded yet, so it is opened after loading
+        app.back_to_bucket_root();
+        app.object_list_open_prefix_version("b/c/file2.txt".to_string(), "v2".to_string());
+        assert_eq!(app.page_stack.len(), 3);
+        rx.recv_find(|event| matches!(event, AppEventType::LoadObjects));
+        assert_eq!(app.restore_file.as_deref(), Some("file2.txt"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_notify_expired_credentials() {
+        let (tx, rx) = event::new();
+        let mut app = App::new(Config::default(), tx, 100, 30);
+        let client = Client::new(
+            Some("us-east-1".to_string()),
+            Some("http://localhost:9000".to_string()),
+            None,
+            1,
+            false,
+            None,
+        )
+        .await;
+        app.client = Some(Arc::new(client));
+        let expired = || {
+            AppError::msg("Failed to load objects").with_details(ErrorDetails {
+                code: Some("ExpiredToken".to_string()),
+                ..Default::default()
+            })
+        };
+
+        // the credentials are reloaded automatically at first
+        app.notify_error(expired());
+        assert!(matches!(
+            app.app_view_state.notification,
+            Notification::Warn(_)
+        ));
+        assert!(matches!(app.app_view_state.dialog, AppDialog::None));
+        let client = match rx.recv_find(|e| matches!(e, AppEventType::CompleteReloadCredentials(_)))
+        {
+            AppEventType::CompleteReloadCredentials(client) => client,
+            _ => panic!("credentials are not reloaded"),
+        };
+        app.complete_reload_credentials(client);
+
+        // expired again soon after reloading, so the user is asked to sign in
+        app.notify_error(expired());
+        assert!(matches!(
+            app.app_view_state.notification,
+            Notification::Error(_)
+        ));
+        assert!(matches!(
+            app.app_view_state.dialog,
+            AppDialog::CredentialsExpired
+        ));
+
+        // the other errors do not open the dialog again after it is closed
+        app.close_credentials_expired();
+        app.notify_error(AppError::msg("Failed to load objects"));
+        assert!(matches!(app.app_view_state.dialog, AppDialog::None));
+
+        // reloaded automatically again after the interval
+        app.credentials_reloaded_at =
+            Some(Instant::now() - CREDENTIALS_RELOAD_INTERVAL - Duration::from_secs(1));
+        app.notify_error(expired());
+        assert!(matches!(
+            app.app_view_state.notification,
+            Notification::Warn(_)
+        ));
+        assert!(matches!(app.app_view_state.dialog, AppDialog::None));
+        rx.recv_find(|e| matches!(e, AppEventType::CompleteReloadCredentials(_)));
+    }
+
+    #[test]
+    fn test_notification_text() {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx, 100, 30);
+        assert_eq!(app.notification_text(), None);
+
+        app.warn_notification("Object not found: a.txt".to_string());
+        assert_eq!(
+            app.notification_text().as_deref(),
+            Some("Object not found: a.txt")
+        );
+
+        // the error is copied with the details, not the summary shown
+        app.app_view_state.notification =
+            Notification::Error("Failed to load: AccessDenied".to_string());
+        app.app_view_state.error_full_msg =
+            Some("Failed to load [code=AccessDenied, request_id=req-1]".to_string());
+        let path = app.config.error_log_path().unwrap();
+        let expected = format!(
+            "Failed to load [code=AccessDenied, request_id=req-1]\nSee {} for more details",
+            path
+        );
+        assert_eq!(app.notification_text(), Some(expected));
+    }
+
+    #[test]
+    fn test_reload_objects() {
+        let (tx, rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+        app.bucket_list_move_down();
+        select(&mut app, "b");
+        app.app_view_state.is_loading = false;
+
+        // the list is loaded again even though it is cached
+        app.object_list_reload();
+        assert_eq!(app.page_stack.len(), 2);
+        assert!(app.app_view_state.is_loading);
+        rx.recv_find(|event| matches!(event, AppEventType::ReloadObjects));
+
+        let items = dir_items(&["a", "a2", "b"]);
+        let result = CompleteLoadObjectsResult::new(Ok(items), false);
+        app.complete_reload_objects(result);
+        assert!(!app.app_view_state.is_loading);
+        assert_eq!(app.page_stack.len(), 2);
+        assert_eq!(item_names(&app), ["a", "a2", "b"]);
+        // the selected item is kept
+        assert_eq!(selected_index(&app), 2);
+
+        // the cache is also updated, so the reloaded list is shown when coming back
+        app.object_list_move_up();
+        app.bucket_list_move_down();
+        assert_eq!(item_names(&app), ["a", "a2", "b"]);
+    }
+
+    #[test]
+    fn test_reload_objects_invalidates_object_detail() {
+        let (tx, rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        let items = vec![ObjectItem::File {
+            name: "file1.txt".to_string(),
+            size_byte: 1024,
+            last_modified: chrono::Local::now(),
+        }];
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), items.clone());
+        let file_key = object_key("bucket-1", &["file1.txt"]);
+        app.app_objects
+            .set_object_details(file_key.clone(), FileDetail::default(), Vec::new());
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+        app.bucket_list_move_down();
+
+        // the cached detail is shown
+        app.object_list_move_down();
+        assert_eq!(app.page_stack.len(), 3);
+        app.close_current_page();
+
+        let result = CompleteLoadObjectsResult::new(Ok(items), false);
+        app.complete_reload_objects(result);
+        assert!(app.app_objects.get_object_detail(&file_key).is_none());
+
+        // the detail is loaded again
+        app.object_list_move_down();
+        assert_eq!(app.page_stack.len(), 2);
+        rx.recv_find(|event| matches!(event, AppEventType::LoadObject));
+    }
+
+    #[test]
+    fn test_selected_object_key_string() {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+        let mut items = vec![ObjectItem::File {
+            name: "file1.txt".to_string(),
+            size_byte: 1024,
+            last_modified: chrono::Local::now(),
+        }];
+        items.extend(dir_items(&["x"]));
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &["b"]), items);
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+
+        app.bucket_list_move_down();
+        select(&mut app, "b");
+        assert_eq!(app.selected_object_key_string(), "b/");
+
+        app.object_list_move_down();
+        assert_eq!(app.selected_object_key_string(), "b/file1.txt");
+
+        select(&mut app, "x");
+        assert_eq!(app.selected_object_key_string(), "b/x/");
+    }
+
+    #[test]
+    fn test_complete_open_in_external_app_with_warning() {
+        let (tx, rx) = event::new();
+        let mut config = Config::default();
+        config.open.command = Some("true".to_string());
+        let mut app = App::new(config, tx, 100, 30);
+
+        let dir = tempfile::tempdir().unwrap();
+        let partial_path = dir.path().join("a.txt.partial");
+        std::fs::write(&partial_path, "abc").unwrap();
+        let path = dir.path().join("a.txt").to_string_lossy().to_string();
+        let obj = DownloadedObject::File(partial_path.to_string_lossy().to_string());
+        let result = CompleteDownloadObjectResult::new_and_open(Ok(obj), path.clone())
+            .map(|r| r.with_warning(Some("the CRC32 checksum does not match".to_string())));
+
+        app.complete_open_in_external_app(result);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "abc");
+        let event = rx.recv_find(|e| matches!(e, AppEventType::NotifyWarn(_)));
+        let expected = format!(
+            "Download completed, but the CRC32 checksum does not match: {}",
+            path
+        );
+        assert!(matches!(event, AppEventType::NotifyWarn(msg) if msg == expected));
+    }
+
+    #[test]
+    fn test_complete_download_objects_partially() {
+        let (tx, rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let items = ["file1", "file2", "file3"]
+            .map(|name| ObjectItem::File {
+                name: name.to_string(),
+                size_byte: 10,
+                last_modified: Local::now(),
+            })
+            .to_vec();
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_object_list(items, ListConfig::default(), tx));
+        // the list needs its height to move the selection
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        terminal.draw(|f| crate::ui::render(f, &mut app)).unwrap();
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Char(' '));
+        }
+
+        let saved = vec![("file1".to_string(), "/tmp/file1".to_string())];
+        let failed = Some((
+            "dir/file2".to_string(),
+            AppError::msg("Failed to download object"),
+        ));
+        let result = CompleteDownloadObjectsResult::new(saved, 3, failed);
+        app.complete_download_objects(result);
+
+        let expected = format!(
+            "Downloaded 1 of 3 objects to {}, but failed to download dir/file2: Failed to download object",
+            app.config.download_base_dir()
+        );
+        rx.recv_find(|e| matches!(e, AppEventType::NotifyError(e) if e.msg == expected));
+
+        // only the saved file is unmarked, so the rest can be downloaded again
+        press(&mut app, KeyCode::Char('s'));
+        let event = rx.recv_find(|e| matches!(e, AppEventType::DownloadObjects(_)));
+        let AppEventType::DownloadObjects(items) = event else {
+            unreachable!()
+        };
+        let names: Vec<&str> = items.iter().map(|item| item.name()).collect();
+        assert_eq!(names, vec!["file2", "file3"]);
+    }
+
+    #[test]
+    fn test_quit_confirm() {
+        let (tx, _rx) = event::new();
+        let config = Config {
+            confirm_quit: true,
+            ..Config::default()
+        };
+        let mut app = App::new(config, tx.clone(), 100, 30);
+
+        for code in [KeyCode::Char('n'), KeyCode::Esc] {
+            assert!(!app.request_quit());
+            assert!(matches!(app.app_view_state.dialog, AppDialog::QuitConfirm));
+            assert!(!app.handle_quit_confirm_key(KeyEvent::from(code)));
+            assert!(matches!(app.app_view_state.dialog, AppDialog::None));
+        }
+
+        // other keys are ignored
+        assert!(!app.request_quit());
+        assert!(!app.handle_quit_confirm_key(KeyEvent::from(KeyCode::Char('q'))));
+        assert!(matches!(app.app_view_state.dialog, AppDialog::QuitConfirm));
+
+        assert!(app.handle_quit_confirm_key(KeyEvent::from(KeyCode::Char('y'))));
+        assert!(app.handle_quit_confirm_key(KeyEvent::from(KeyCode::Enter)));
+
+        // quit without the confirmation if disabled
+        let mut app = App::new(Config::default(), tx, 100, 30);
+        assert!(app.request_quit());
+        assert!(matches!(app.app_view_state.dialog, AppDialog::None));
+    }
+
+    #[test]
+    fn test_tabs() {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b"]));
+
+        // tabs cannot be opened before initialized
+        app.open_new_tab();
+        assert_eq!(app.tabs().len(), 1);
+
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+        app.bucket_list_move_down();
+        assert_eq!(app.page_stack.len(), 2);
+
+        app.open_new_tab();
+        assert_eq!(app.tabs().len(), 2);
+        assert_eq!(app.current_tab(), 1);
+        assert_eq!(app.page_stack.len(), 1);
+
+        // each tab keeps its own pages
+        app.next_tab();
+        assert_eq!(app.current_tab(), 0);
+        assert_eq!(app.page_stack.len(), 2);
+        app.next_tab();
+        assert_eq!(app.current_tab(), 1);
+        assert_eq!(app.page_stack.len(), 1);
+
+        app.close_current_tab();
+        assert_eq!(app.tabs().len(), 1);
+        assert_eq!(app.current_tab(), 0);
+        assert_eq!(app.page_stack.len(), 2);
+
+        // the last tab is kept
+        app.close_current_tab();
+        assert_eq!(app.tabs().len(), 1);
+    }
+
+    #[test]
+    fn test_remember_filter() {
+        let (tx, _rx) = event::new();
+        let mut config = Config::default();
+        config.list.remember_filter = true;
+        let mut app = App::new(config, tx.clone(), 100, 30);
+
+        let buckets = ["bucket-1", "bucket-2"].map(bucket_item).to_vec();
+        app.app_objects.set_bucket_items(buckets.clone());
+        app.app_objects
+            .set_object_items(object_key("bucket-1", &[]), dir_items(&["a", "b", "c"]));
+
+        app.page_stack.pop(); // remove initializing page
+        app.page_stack
+            .push(Page::of_bucket_list(buckets, ListConfig::default(), tx));
+        app.bucket_list_move_down();
+        press(&mut app, KeyCode::Char('/'));
+        press(&mut app, KeyCode::Char('b'));
+        press(&mut app, KeyCode::Enter);
+
+        // the filter is applied again when the bucket is reopened
+        app.back_to_bucket_list();
+        app.bucket_list_move_down();
+        let page = app.page_stack.current_page().as_object_list();
+        assert_eq!(page.filter(), "b");
+        assert_eq!(page.current_selected_item().name(), "b");
+        assert_eq!(page.object_list().len(), 1);
+
+        // and forgotten after cleared
+        press(&mut app, KeyCode::Esc);
+        app.object_list_move_up();
+        app.bucket_list_move_down();
+        let page = app.page_stack.current_page().as_object_list();
+        assert_eq!(page.filter(), "");
+        assert_eq!(page.current_selected_item().name(), "a");
+        assert_eq!(page.object_list().len(), 3);
+    }
+
+    #[rstest]
+    #[case(100, 16, Some("The range is limited to 16 bytes (preview.max_bytes)"))]
+    #[case(16, 16, None)]
+    #[case(10, 10, None)]
+    fn test_open_preview_range(
+        #[case] length: usize,
+        #[case] expected_length: usize,
+        #[case] expected_warn: Option<&str>,
+    ) {
+        let (tx, rx) = event::new();
+        let config = Config {
+            preview: PreviewConfig {
+                max_bytes: 16,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut app = App::new(config, tx, 100, 30);
+
+        let range = ByteRange { offset: 8, length };
+        app.open_preview_range(FileDetail::default(), None, range);
+
+        let event = rx.recv_find(|event| {
+            matches!(
+                event,
+                AppEventType::NotifyWarn(_) | AppEventType::PreviewObjectRange(_, _, _)
+            )
+        });
+        let event = match (event, expected_warn) {
+            (AppEventType::NotifyWarn(msg), Some(expected_warn)) => {
+                assert_eq!(msg, expected_warn);
+                rx.recv_find(|event| matches!(event, AppEventType::PreviewObjectRange(_, _, _)))
+            }
+            (event, None) => event,
+            _ => panic!("the range is not limited"),
+        };
+        match event {
+            AppEventType::PreviewObjectRange(_, _, range) => {
+                assert_eq!(range.offset, 8);
+                assert_eq!(range.length, expected_length);
+            }
+            _ => panic!("the range is not previewed"),
+        }
+    }
+
+    #[rstest]
+    #[case("4waSgw==", "v2", None, true)]
+    #[case("4waSgw==", "v2", Some("v2"), true)]
+    #[case("4waSgw==", "v2", Some("v1"), false)]
+    #[case("4waSgw==", "", Some("v1"), false)]
+    #[case("", "v2", None, false)]
+    fn test_expected_checksum(
+        #[case] checksum: &str,
+        #[case] detail_version_id: &str,
+        #[case] version_id: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let file_detail = FileDetail {
+            checksum_algorithm: "CRC32C".to_string(),
+            checksum: checksum.to_string(),
+            version_id: detail_version_id.to_string(),
+            ..Default::default()
+        };
+        let actual = expected_checksum(&file_detail, version_id);
+        let expected = expected.then(|| ("CRC32C".to_string(), checksum.to_string()));
+        assert_eq!(actual, expected);
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        if let Page::ObjectList(page) = app.page_stack.current_page_mut() {
+            page.handle_key(KeyEvent::from(code));
+        }
+    }
+
+    fn select(app: &mut App, name: &str) {
+        if let Page::ObjectList(page) = app.page_stack.current_page_mut() {
+            assert!(page.select_dir_by_name(name));
+        }
+    }
+
+    fn selected_index(app: &App) -> usize {
+        app.page_stack
+            .current_page()
+            .as_object_list()
+            .list_state()
+            .selected
+    }
+
+    fn item_names(app: &App) -> Vec<String> {
+        let page = app.page_stack.current_page().as_object_list();
+        page.object_list()
+            .iter()
+            .map(|item| item.name().to_string())
+            .collect()
+    }
+
+    fn bucket_item(name: &str) -> BucketItem {
+        BucketItem {
+            name: name.to_string(),
+            creation_date: None,
+            region: None,
+            versioning: None,
+            encryption: None,
+            public: None,
+        }
+    }
+
+    fn dir_items(names: &[&str]) -> Vec<ObjectItem> {
+        names
+            .iter()
+            .map(|name| ObjectItem::Dir {
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    fn object_key(bucket: &str, path: &[&str]) -> ObjectKey {
+        ObjectKey {
+            bucket_name: bucket.to_string(),
+            object_path: path.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
@@ -1,20 +1,61 @@
-use std::fmt::Debug;
+use std::{collections::VecDeque, error::Error, fmt::Debug, future::Future, time::Duration};
 
-use aws_config::{meta::region::RegionProviderChain, BehaviorVersion};
-use aws_sdk_s3::{config::Region, operation::list_objects_v2::ListObjectsV2Output};
+use aws_config::{
+    meta::region::RegionProviderChain, sts::AssumeRoleProvider, timeout::TimeoutConfig,
+    BehaviorVersion, SdkConfig,
+};
+use aws_sdk_s3::{
+    config::{Region, SharedCredentialsProvider},
+    error::{ProvideErrorMetadata, SdkError},
+    operation::{
+        get_bucket_encryption::GetBucketEncryptionOutput, head_object::HeadObjectOutput,
+        list_multipart_uploads::ListMultipartUploadsOutput,
+        list_object_versions::ListObjectVersionsOutput, list_objects_v2::ListObjectsV2Output,
+        RequestId, RequestIdExt,
+    },
+    primitives::ByteStream,
+    types::{ChecksumMode, Grant, Grantee, Owner, RequestPayer},
+};
+use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
 use chrono::TimeZone;
+use tokio::{
+    io::{AsyncWriteExt, BufWriter},
+    task::JoinSet,
+};
 
 use crate::{
-    error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, RawObject},
+    config::AssumeRoleConfig,
+    error::{AppError, ErrorDetails, Result},
+    file::{create_binary_file, remove_file},
+    object::{
+        Acl, AclGrant, BucketItem, BucketPolicy, FileDetail, FileVersion, MultipartUpload,
+        ObjectHead, ObjectItem, PrefixVersion, RawObject,
+    },
+    util::{ByteRange, TimeRange},
 };
 
 const DELIMITER: &str = "/";
 const DEFAULT_REGION: &str = "ap-northeast-1";
 
+#[derive(Clone)]
 pub struct Client {
     pub client: aws_sdk_s3::Client,
+    sts_client: aws_sdk_sts::Client,
     region: String,
+    timeout: Duration,
+    request_payer: Option<RequestPayer>,
+    options: ClientOptions,
+}
+
+// kept to build the client again, to load the credentials again after they expired
+#[derive(Clone, Default)]
+struct ClientOptions {
+    region: Option<String>,
+    endpoint_url: Option<String>,
+    profile: Option<String>,
+    timeout_secs: u64,
+    requester_pays: bool,
+    assume_role: Option<AssumeRoleConfig>,
 }
 
 impl Debug for Client {
@@ -28,20 +69,53 @@ impl Client {
         region: Option<String>,
         endpoint_url: Option<String>,
         profile: Option<String>,
+        timeout_secs: u64,
+        requester_pays: bool,
+        assume_role: Option<AssumeRoleConfig>,
     ) -> Client {
+        let options = ClientOptions {
+            region,
+            endpoint_url,
+            profile,
+            timeout_secs,
+            requester_pays,
+            assume_role,
+        };
+        Client::build(options).await
+    }
+
+    // runs the credential provider chain again, e.g. after signing in with `aws sso login`
+    pub async fn reload(&self) -> Client {
+        Client::build(self.options.clone()).await
+    }
+
+    async fn build(options: ClientOptions) -> Client {
+        let ClientOptions {
+            region,
+            endpoint_url,
+            profile,
+            timeout_secs,
+            requester_pays,
+            assume_role,
+        } = options.clone();
+        let timeout = Duration::from_secs(timeout_secs);
         let region_provider = RegionProviderChain::first_try(region.map(Region::new))
             .or_default_provider()
             .or_else(DEFAULT_REGION);
 
-        let mut config_loader =
-            aws_config::defaults(BehaviorVersion::latest()).region(region_provider);
+        let mut config_loader = aws_config::defaults(BehaviorVersion::latest())
+            .region(region_provider)
+            .timeout_config(build_timeout_config(timeout));
         if let Some(url) = &endpoint_url {
             config_loader = config_loader.endpoint_url(url);
         }
         if let Some(profile) = &profile {
             config_loader = config_loader.profile_name(profile);
         }
-        let sdk_config = config_loader.load().await;
+        let mut sdk_config = config_loader.load().await;
+        if let Some(assume_role) = assume_role {
+            sdk_config = with_assumed_role(sdk_config, assume_role).await;
+        }
 
         let mut config_builder = aws_sdk_s3::config::Builder::from(&sdk_config);
         if endpoint_url.is_some() {
@@ -50,21 +124,50 @@ impl Client {
         let config = config_builder.build();
 
         let client = aws_sdk_s3::Client::from_conf(config);
+        let sts_client = aws_sdk_sts::Client::new(&sdk_config);
         let region = sdk_config.region().unwrap().to_string();
 
-        Client { client, region }
+        Client {
+            client,
+            sts_client,
+            region,
+            timeout,
+            request_payer: requester_pays.then_some(RequestPayer::Requester),
+            options,
+        }
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    pub async fn whoami(&self) -> Result<String> {
+        let result = self.sts_client.get_caller_identity().send().await;
+        let output = result.map_err(|e| sdk_error("Failed to get caller identity", e))?;
+        output
+            .account()
+            .map(String::from)
+            .ok_or_else(|| AppError::msg("Failed to get account id"))
     }
 
     pub async fn load_all_buckets(&self) -> Result<Vec<BucketItem>> {
         let result = self.client.list_buckets().send().await;
-        let output = result.map_err(|e| AppError::new("Failed to load buckets", e))?;
+        let output = result.map_err(|e| sdk_error("Failed to load buckets", e))?;
 
         let buckets: Vec<BucketItem> = output
             .buckets()
             .iter()
             .map(|bucket| {
                 let name = bucket.name().unwrap().to_string();
-                BucketItem { name }
+                let creation_date = bucket.creation_date().map(convert_datetime);
+                BucketItem {
+                    name,
+                    creation_date,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                }
             })
             .collect();
 
@@ -78,17 +181,326 @@ impl Client {
     pub async fn load_bucket(&self, name: &str) -> Result<BucketItem> {
         let result = self.client.head_bucket().bucket(name).send().await;
         // Check only existence and accessibility
-        result.map_err(|e| AppError::new(format!("Failed to load bucket '{}'", name), e))?;
+        result.map_err(|e| sdk_error(format!("Failed to load bucket '{}'", name), e))?;
 
         let bucket = BucketItem {
             name: name.to_string(),
+            creation_date: None,
+            region: None,
+            versioning: None,
+            encryption: None,
+            public: None,
         };
         Ok(bucket)
     }
 
-    pub async fn load_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<ObjectItem>> {
-        let mut dirs_vec: Vec<Vec<ObjectItem>> = Vec::new();
-        let mut files_vec: Vec<Vec<ObjectItem>> = Vec::new();
+    pub async fn load_bucket_region(&self, name: &str) -> Result<String> {
+        let result = self.client.get_bucket_location().bucket(name).send().await;
+        let output = result
+            .map_err(|e| sdk_error(format!("Failed to load bucket location '{}'", name), e))?;
+
+        // buckets in us-east-1 have no location constraint
+        let region = match output.location_constraint().map(|c| c.as_str()) {
+            None | Some("") => "us-east-1",
+            Some("EU") => "eu-west-1",
+            Some(region) => region,
+        };
+        Ok(region.to_string())
+    }
+
+    pub async fn load_bucket_versioning(&self, name: &str) -> Result<String> {
+        let result = self
+            .client
+            .get_bucket_versioning()
+            .bucket(name)
+            .send()
+            .await;
+        let output = result
+            .map_err(|e| sdk_error(format!("Failed to load bucket versioning '{}'", name), e))?;
+
+        // buckets that have never enabled versioning have no status
+        let versioning = output
+            .status()
+            .map_or("disabled", |s| s.as_str())
+            .to_lowercase();
+        Ok(versioning)
+    }
+
+    pub async fn load_bucket_encryption(&self, name: &str) -> Result<String> {
+        let result = self
+            .client
+            .get_bucket_encryption()
+            .bucket(name)
+            .send()
+            .await;
+        match result {
+            Ok(output) => Ok(bucket_encryption_output_to_string(&output)),
+            // only returned by S3-compatible storages, buckets in AWS are always encrypted
+            Err(e) if e.code() == Some("ServerSideEncryptionConfigurationNotFoundError") => {
+                Ok("none".to_string())
+            }
+            Err(e) => Err(sdk_error(
+                format!("Failed to load bucket encryption '{}'", name),
+                e,
+            )),
+        }
+    }
+
+    pub async fn load_bucket_policy(&self, name: &str) -> Result<BucketPolicy> {
+        let result = self.client.get_bucket_policy().bucket(name).send().await;
+        match result {
+            Ok(output) => {
+                let policy = format_policy_document(output.policy().unwrap_or_default());
+                Ok(BucketPolicy::Document(policy))
+            }
+            Err(e) if e.code() == Some("NoSuchBucketPolicy") => Ok(BucketPolicy::NotSet),
+            Err(e) if is_forbidden(&e) => Ok(BucketPolicy::AccessDenied),
+            Err(e) => Err(sdk_error(
+                format!("Failed to load bucket policy '{}'", name),
+                e,
+            )),
+        }
+    }
+
+    pub async fn load_bucket_acl(&self, name: &str) -> Result<Acl> {
+        let result = self.client.get_bucket_acl().bucket(name).send().await;
+        match result {
+            Ok(output) => Ok(build_acl(output.owner(), output.grants())),
+            Err(e) if is_forbidden(&e) => Ok(Acl::AccessDenied),
+            Err(e) => Err(sdk_error(
+                format!("Failed to load bucket ACL '{}'", name),
+                e,
+            )),
+        }
+    }
+
+    pub async fn load_object_acl(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+    ) -> Result<Acl> {
+        let result = self
+            .client
+            .get_object_acl()
+            .bucket(bucket)
+            .key(key)
+            .set_version_id(version_id)
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await;
+        match result {
+            Ok(output) => Ok(build_acl(output.owner(), output.grants())),
+            Err(e) if is_forbidden(&e) => Ok(Acl::AccessDenied),
+            Err(e) => Err(sdk_error(format!("Failed to load object ACL '{}'", key), e)),
+        }
+    }
+
+    // the account level block public access settings are not taken into account
+    pub async fn load_bucket_public(&self, name: &str) -> Result<Option<bool>> {
+        let block = self.load_public_access_block(name).await?;
+        let policy = self.load_bucket_policy_public(name).await?;
+        let acl = self.load_bucket_acl(name).await?.is_public();
+        Ok(effective_public(block, policy, acl))
+    }
+
+    // the bucket policy can also grant access to the objects
+    pub async fn load_object_public(&self, bucket: &str, key: &str) -> Result<Option<bool>> {
+        let block = self.load_public_access_block(bucket).await?;
+        let policy = self.load_bucket_policy_public(bucket).await?;
+        let acl = self.load_object_acl(bucket, key, None).await?.is_public();
+        Ok(effective_public(block, policy, acl))
+    }
+
+    async fn load_public_access_block(&self, name: &str) -> Result<PublicAccessBlock> {
+        let result = self
+            .client
+            .get_public_access_block()
+            .bucket(name)
+            .send()
+            .await;
+        match result {
+            Ok(output) => {
+                let config = output.public_access_block_configuration();
+                Ok(PublicAccessBlock {
+                    ignore_public_acls: config
+                        .and_then(|c| c.ignore_public_acls())
+                        .unwrap_or_default(),
+                    restrict_public_buckets: config
+                        .and_then(|c| c.restrict_public_buckets())
+                        .unwrap_or_default(),
+                })
+            }
+            Err(e) if e.code() == Some("NoSuchPublicAccessBlockConfiguration") => {
+                Ok(PublicAccessBlock::default())
+            }
+            // assume nothing is blocked, so as not to hide the warning
+            Err(e) if is_forbidden(&e) => Ok(PublicAccessBlock::default()),
+            Err(e) => Err(sdk_error(
+                format!("Failed to load public access block '{}'", name),
+                e,
+            )),
+        }
+    }
+
+    async fn load_bucket_policy_public(&self, name: &str) -> Result<Option<bool>> {
+        let result = self
+            .client
+            .get_bucket_policy_status()
+            .bucket(name)
+            .send()
+            .await;
+        match result {
+            Ok(output) => {
+                let public = output.policy_status().and_then(|s| s.is_public());
+                Ok(Some(public.unwrap_or_default()))
+            }
+            Err(e) if e.code() == Some("NoSuchBucketPolicy") => Ok(Some(false)),
+            Err(e) if is_forbidden(&e) => Ok(None),
+            Err(e) => Err(sdk_error(
+                format!("Failed to load bucket policy status '{}'", name),
+                e,
+            )),
+        }
+    }
+
+    pub async fn list_multipart_uploads(&self, bucket: &str) -> Result<Vec<MultipartUpload>> {
+        let mut uploads: Vec<MultipartUpload> = Vec::new();
+
+        let mut key_marker: Option<String> = None;
+        let mut upload_id_marker: Option<String> = None;
+        loop {
+            let result = self
+                .client
+                .list_multipart_uploads()
+                .bucket(bucket)
+                .set_key_marker(key_marker)
+                .set_upload_id_marker(upload_id_marker)
+                .set_request_payer(self.request_payer.clone())
+                .send()
+                .await;
+            let output = result.map_err(|e| {
+                sdk_error(format!("Failed to list multipart uploads '{}'", bucket), e)
+            })?;
+
+            uploads.extend(multipart_uploads_output_to_uploads(&output));
+
+            if !output.is_truncated().unwrap_or_default() {
+                break;
+            }
+            key_marker = output.next_key_marker().map(String::from);
+            upload_id_marker = output.next_upload_id_marker().map(String::from);
+        }
+        Ok(uploads)
+    }
+
+    pub async fn abort_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+    ) -> Result<()> {
+        let result = self
+            .client
+            .abort_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await;
+        result.map_err(|e| sdk_error(format!("Failed to abort multipart upload '{}'", key), e))?;
+        Ok(())
+    }
+
+    pub async fn load_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        max_keys: Option<i32>,
+        flat: bool,
+    ) -> Result<Vec<ObjectItem>> {
+        let mut dirs: Vec<ObjectItem> = Vec::new();
+        let mut files: Vec<ObjectItem> = Vec::new();
+
+        let mut token: Option<String> = None;
+        loop {
+            let (items, next_token) = self
+                .load_objects_page(bucket, prefix, max_keys, flat, token)
+                .await?;
+            let (ds, fs): (Vec<_>, Vec<_>) = items
+                .into_iter()
+                .partition(|item| matches!(item, ObjectItem::Dir { .. }));
+            dirs.extend(ds);
+            files.extend(fs);
+
+            token = next_token;
+            if token.is_none() {
+                break;
+            }
+        }
+
+        Ok(dirs.into_iter().chain(files).collect())
+    }
+
+    // returns the items of a single list request, and the token to load the next page if truncated
+    pub async fn load_objects_page(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        max_keys: Option<i32>,
+        flat: bool,
+        token: Option<String>,
+    ) -> Result<(Vec<ObjectItem>, Option<String>)> {
+        // without the delimiter, all objects under the prefix are returned as they are
+        let delimiter = (!flat).then(|| DELIMITER.to_string());
+
+        let result = self
+            .client
+            .list_objects_v2()
+            .bucket(bucket)
+            .prefix(prefix)
+            .set_delimiter(delimiter)
+            .set_max_keys(max_keys)
+            .set_continuation_token(token)
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await;
+        let output = result.map_err(|e| self.object_error("Failed to load objects", e))?;
+
+        let dirs = objects_output_to_dirs(&output);
+        let files = if flat {
+            objects_output_to_flat_files(&output, prefix)
+        } else {
+            objects_output_to_files(&output)
+        };
+        let next_token = output.next_continuation_token().map(String::from);
+        Ok((dirs.into_iter().chain(files).collect(), next_token))
+    }
+
+    // returns the keys of all objects under the prefix, listing up to `concurrency` prefixes at a time
+    pub async fn load_objects_recursive(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        concurrency: usize,
+    ) -> Result<Vec<String>> {
+        let list = |prefix: String| {
+            let client = self.clone();
+            let bucket = bucket.to_string();
+            async move { client.load_object_keys(&bucket, &prefix).await }
+        };
+        list_recursive(prefix.to_string(), concurrency, list).await
+    }
+
+    // returns the common prefixes and the object keys directly under the prefix
+    async fn load_object_keys(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let mut prefixes: Vec<String> = Vec::new();
+        let mut keys: Vec<String> = Vec::new();
 
         let mut token: Option<String> = None;
         loop {
@@ -99,25 +511,30 @@ impl Client {
                 .prefix(prefix)
                 .delimiter(DELIMITER)
                 .set_continuation_token(token)
+                .set_request_payer(self.request_payer.clone())
                 .send()
                 .await;
-            let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
+            let output = result.map_err(|e| self.object_error("Failed to load objects", e))?;
 
-            let dirs = objects_output_to_dirs(&output);
-            dirs_vec.push(dirs);
-
-            let files = objects_output_to_files(&output);
-            files_vec.push(files);
+            prefixes.extend(
+                output
+                    .common_prefixes()
+                    .iter()
+                    .filter_map(|p| p.prefix().map(String::from)),
+            );
+            keys.extend(
+                output
+                    .contents()
+                    .iter()
+                    .filter_map(|o| o.key().map(String::from)),
+            );
 
             token = output.next_continuation_token().map(String::from);
             if token.is_none() {
                 break;
             }
         }
-
-        let di = dirs_vec.into_iter().flatten();
-        let fi = files_vec.into_iter().flatten();
-        Ok(di.chain(fi).collect())
+        Ok((prefixes, keys))
     }
 
     pub async fn load_object_detail(
@@ -132,37 +549,73 @@ impl Client {
             .head_object()
             .bucket(bucket)
             .key(key)
+            .checksum_mode(ChecksumMode::Enabled)
+            .set_request_payer(self.request_payer.clone())
             .send()
             .await;
-        let output = result.map_err(|e| AppError::new("Failed to load object detail", e))?;
+        let output = result.map_err(|e| self.object_error("Failed to load object detail", e))?;
 
         let name = name.to_owned();
         let last_modified = convert_datetime(output.last_modified().unwrap());
         let e_tag = output.e_tag().unwrap().trim_matches('"').to_string();
+        let (checksum_algorithm, checksum) = head_object_checksum(&output);
+        let version_id = output.version_id().unwrap_or_default().to_string();
         let content_type = output.content_type().unwrap().to_string();
+        let content_encoding = output.content_encoding().unwrap_or_default().to_string();
+        let content_disposition = output.content_disposition().unwrap_or_default().to_string();
+        let cache_control = output.cache_control().unwrap_or_default().to_string();
         let storage_class = output
             .storage_class()
             .map_or("", |s| s.as_str())
             .to_string();
+        let server_side_encryption = output
+            .server_side_encryption()
+            .map_or("", |s| s.as_str())
+            .to_string();
+        let sse_kms_key_id = output.ssekms_key_id().unwrap_or_default().to_string();
+        let bucket = bucket.to_owned();
         let key = key.to_owned();
-        let s3_uri = self.build_s3_uri(bucket, &key);
-        let arn = self.build_arn(bucket, &key);
-        let object_url = self.build_object_url(bucket, &key);
+        let s3_uri = self.build_s3_uri(&bucket, &key);
+        let arn = self.build_arn(&bucket, &key);
+        let object_url = self.build_object_url(&bucket, &key);
         Ok(FileDetail {
             name,
             size_byte,
             last_modified,
             e_tag,
+            checksum_algorithm,
+            checksum,
+            version_id,
             content_type,
+            content_encoding,
+            content_disposition,
+            cache_control,
             storage_class,
+            server_side_encryption,
+            sse_kms_key_id,
+            bucket,
             key,
             s3_uri,
             arn,
             object_url,
+            public: None,
         })
     }
 
-    fn build_s3_uri(&self, bucket: &str, key: &str) -> String {
+    pub async fn load_object_size(&self, bucket: &str, key: &str) -> Result<usize> {
+        let result = self
+            .client
+            .head_object()
+            .bucket(bucket)
+            .key(key)
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await;
+        let output = result.map_err(|e| self.object_error("Failed to load object size", e))?;
+        Ok(output.content_length().unwrap_or_default() as usize)
+    }
+
+    pub fn build_s3_uri(&self, bucket: &str, key: &str) -> String {
         format!("s3://{}/{}", bucket, key)
     }
 
@@ -183,26 +636,83 @@ impl Client {
             .list_object_versions()
             .bucket(bucket)
             .prefix(key)
+            .set_request_payer(self.request_payer.clone())
             .send()
             .await;
-        let output = result.map_err(|e| AppError::new("Failed to load object versions", e))?;
+        let output = result.map_err(|e| self.object_error("Failed to load object versions", e))?;
 
-        let versions = output
-            .versions()
-            .iter()
-            .map(|v| {
-                let version_id = v.version_id().unwrap().to_string(); // returns "null" if empty...
-                let size_byte = v.size().unwrap() as usize;
-                let last_modified = convert_datetime(v.last_modified().unwrap());
-                let is_latest = v.is_latest().unwrap();
-                FileVersion {
-                    version_id,
-                    size_byte,
-                    last_modified,
-                    is_latest,
-                }
-            })
-            .collect();
+        let versions = output.versions().iter().map(|v| {
+            let version_id = v.version_id().unwrap().to_string(); // returns "null" if empty...
+            let size_byte = v.size().unwrap() as usize;
+            let last_modified = convert_datetime(v.last_modified().unwrap());
+            let is_latest = v.is_latest().unwrap();
+            FileVersion {
+                version_id,
+                size_byte,
+                last_modified,
+                is_latest,
+                is_delete_marker: false,
+            }
+        });
+        let delete_markers = output.delete_markers().iter().map(|m| {
+            let version_id = m.version_id().unwrap().to_string();
+            let last_modified = convert_datetime(m.last_modified().unwrap());
+            let is_latest = m.is_latest().unwrap();
+            FileVersion {
+                version_id,
+                size_byte: 0,
+                last_modified,
+                is_latest,
+                is_delete_marker: true,
+            }
+        });
+
+        // versions and delete markers are returned separately, so merge them newest first
+        let mut versions: Vec<FileVersion> = versions.chain(delete_markers).collect();
+        versions.sort_by_key(|v| std::cmp::Reverse(v.last_modified));
+        Ok(versions)
+    }
+
+    // all versions of the objects under the prefix modified in the range, newest first
+    pub async fn list_object_versions_by_prefix(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        range: TimeRange,
+    ) -> Result<Vec<PrefixVersion>> {
+        let mut versions: Vec<PrefixVersion> = Vec::new();
+
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+        loop {
+            let result = self
+                .client
+                .list_object_versions()
+                .bucket(bucket)
+                .prefix(prefix)
+                .set_key_marker(key_marker)
+                .set_version_id_marker(version_id_marker)
+                .set_request_payer(self.request_payer.clone())
+                .send()
+                .await;
+            let output =
+                result.map_err(|e| self.object_error("Failed to load object versions", e))?;
+
+            // only the matched versions are kept, as there can be a huge number of versions
+            versions.extend(
+                object_versions_output_to_prefix_versions(&output)
+                    .into_iter()
+                    .filter(|v| range.contains(&v.last_modified)),
+            );
+
+            if !output.is_truncated().unwrap_or_default() {
+                break;
+            }
+            key_marker = output.next_key_marker().map(String::from);
+            version_id_marker = output.next_version_id_marker().map(String::from);
+        }
+
+        versions.sort_by_key(|v| std::cmp::Reverse(v.last_modified));
         Ok(versions)
     }
 
@@ -212,26 +722,80 @@ impl Client {
         key: &str,
         version_id: Option<String>,
         size_byte: usize,
+        max_bytes: Option<usize>,
         f: F,
     ) -> Result<RawObject>
     where
         F: Fn(usize),
     {
-        let mut request = self.client.get_object().bucket(bucket).key(key);
-        if let Some(version_id) = version_id {
-            request = request.version_id(version_id);
-        }
+        // only the first bytes are fetched if the object is larger than the limit
+        let range = max_bytes
+            .filter(|&max| size_byte > max)
+            .map(|max| format!("bytes=0-{}", max.saturating_sub(1)));
+        let size_byte = max_bytes.map_or(size_byte, |max| size_byte.min(max));
+        let stream = self.get_object_body(bucket, key, version_id, range).await?;
+        self.collect_object(stream, size_byte, f).await
+    }
 
-        let result = request.send().await;
-        let output = result.map_err(|e| AppError::new("Failed to download object", e))?;
+    // fetches the bytes after `start`, used to follow an object that keeps growing
+    pub async fn download_object_from(
+        &self,
+        bucket: &str,
+        key: &str,
+        start: usize,
+    ) -> Result<RawObject> {
+        let range = format!("bytes={}-", start);
+        let stream = self.get_object_body(bucket, key, None, Some(range)).await?;
+        self.collect_object(stream, 0, |_| {}).await
+    }
+
+    pub async fn download_object_range(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        range: ByteRange,
+    ) -> Result<RawObject> {
+        let stream = self
+            .get_object_body(bucket, key, version_id, Some(range.header()))
+            .await?;
+        self.collect_object(stream, range.length, |_| {}).await
+    }
 
+    // fetches only the first bytes, to show the head of an object without downloading all of it
+    pub async fn load_object_head(
+        &self,
+        bucket: &str,
+        key: &str,
+        max_bytes: usize,
+    ) -> Result<ObjectHead> {
+        let result = self
+            .client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .range(format!("bytes=0-{}", max_bytes.saturating_sub(1)))
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await;
+        let output = result.map_err(|e| self.object_error("Failed to load object", e))?;
+        let content_type = output.content_type.unwrap_or_default();
+        let obj = self.collect_object(output.body, max_bytes, |_| {}).await?;
+        Ok(ObjectHead { content_type, obj })
+    }
+
+    async fn collect_object<F>(
+        &self,
+        mut stream: ByteStream,
+        size_byte: usize,
+        f: F,
+    ) -> Result<RawObject>
+    where
+        F: Fn(usize),
+    {
         let mut bytes: Vec<u8> = Vec::with_capacity(size_byte);
-        let mut stream = output.body;
-        while let Some(buf) = stream // buf: 32 KiB
-            .try_next()
-            .await
-            .map_err(|e| AppError::new("Failed to collect body", e))?
-        {
+        // buf: 32 KiB
+        while let Some(buf) = next_chunk(stream.try_next(), self.timeout).await? {
             bytes.extend(buf.to_vec());
             f(bytes.len())
         }
@@ -239,19 +803,116 @@ impl Client {
         Ok(RawObject { bytes })
     }
 
+    pub async fn download_object_to_file<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        path: &str,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let stream = self.get_object_body(bucket, key, version_id, None).await?;
+        let result = write_stream_to_file(stream, path, self.timeout, f).await;
+        if result.is_err() {
+            // do not leave the incomplete file
+            let _ = remove_file(path);
+        }
+        result
+    }
+
+    async fn get_object_body(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        range: Option<String>,
+    ) -> Result<ByteStream> {
+        let mut request = self
+            .client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .set_range(range)
+            .set_request_payer(self.request_payer.clone());
+        if let Some(version_id) = version_id {
+            request = request.version_id(version_id);
+        }
+
+        let result = request.send().await;
+        let output = result.map_err(|e| self.object_error("Failed to download object", e))?;
+        Ok(output.body)
+    }
+
+    // requester pays buckets deny requests without the header, but S3 does not tell why
+    fn object_error<E>(&self, msg: &str, e: SdkError<E, HttpResponse>) -> AppError
+    where
+        E: Error + ProvideErrorMetadata + Send + Sync + 'static,
+    {
+        if is_forbidden(&e) && self.request_payer.is_none() {
+            let msg = format!(
+                "{} (access denied, if the bucket is requester pays, try `--requester-pays`)",
+                msg
+            );
+            sdk_error(msg, e)
+        } else {
+            sdk_error(msg, e)
+        }
+    }
+
+    // the console URLs point to AWS, so they are meaningless for S3-compatible endpoints
+    pub fn is_management_console_available(&self) -> bool {
+        self.options.endpoint_url.is_none()
+    }
+
+    fn check_management_console_available(&self) -> Result<()> {
+        if self.is_management_console_available() {
+            Ok(())
+        } else {
+            Err(AppError::msg(
+                "Management console is not available for a custom endpoint",
+            ))
+        }
+    }
+
+    pub fn management_console_buckets_url(&self) -> Result<String> {
+        self.check_management_console_available()?;
+        Ok(build_management_console_buckets_url(&self.region))
+    }
+
+    pub fn management_console_list_url(&self, bucket: &str, prefix: &str) -> Result<String> {
+        self.check_management_console_available()?;
+        Ok(build_management_console_list_url(
+            &self.region,
+            bucket,
+            prefix,
+        ))
+    }
+
+    pub fn management_console_object_url(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        name: &str,
+    ) -> Result<String> {
+        self.check_management_console_available()?;
+        Ok(build_management_console_object_url(
+            &self.region,
+            bucket,
+            prefix,
+            name,
+        ))
+    }
+
     pub fn open_management_console_buckets(&self) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/buckets?region={}",
-            self.region
-        );
+        let path = self.management_console_buckets_url()?;
         open::that(path).map_err(AppError::error)
     }
 
     pub fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
-            bucket, self.region, prefix
-        );
+        let path = self.management_console_list_url(bucket, prefix)?;
         open::that(path).map_err(AppError::error)
     }
 
@@ -261,14 +922,65 @@ impl Client {
         prefix: &str,
         name: &str,
     ) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}{}",
-            bucket, self.region, prefix, name
-        );
+        let path = self.management_console_object_url(bucket, prefix, name)?;
         open::that(path).map_err(AppError::error)
     }
 }
 
+fn build_management_console_buckets_url(region: &str) -> String {
+    format!(
+        "https://s3.console.aws.amazon.com/s3/buckets?region={}",
+        region
+    )
+}
+
+fn build_management_console_list_url(region: &str, bucket: &str, prefix: &str) -> String {
+    format!(
+        "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
+        bucket, region, prefix
+    )
+}
+
+fn build_management_console_object_url(
+    region: &str,
+    bucket: &str,
+    prefix: &str,
+    name: &str,
+) -> String {
+    format!(
+        "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}{}",
+        bucket, region, prefix, name
+    )
+}
+
+// breadth-first, the prefixes found are queued and listed by at most `concurrency` tasks at once
+async fn list_recursive<F, Fut>(root: String, concurrency: usize, list: F) -> Result<Vec<String>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<(Vec<String>, Vec<String>)>> + Send + 'static,
+{
+    let mut keys: Vec<String> = Vec::new();
+    let mut pending: VecDeque<String> = VecDeque::from([root]);
+    let mut running = JoinSet::new();
+    loop {
+        while running.len() < concurrency.max(1) {
+            let Some(prefix) = pending.pop_front() else {
+                break;
+            };
+            running.spawn(list(prefix));
+        }
+        let Some(result) = running.join_next().await else {
+            break;
+        };
+        let (prefixes, found) =
+            result.map_err(|e| AppError::new("Failed to load objects", e))??;
+        pending.extend(prefixes);
+        keys.extend(found);
+    }
+    keys.sort();
+    Ok(keys)
+}
+
 fn objects_output_to_dirs(output: &ListObjectsV2Output) -> Vec<ObjectItem> {
     let objects = output.common_prefixes();
     objects
@@ -301,6 +1013,177 @@ fn objects_output_to_files(output: &ListObjectsV2Output) -> Vec<ObjectItem> {
         .collect()
 }
 
+// the names are the keys relative to the prefix, so that the key can be built in the same way
+fn objects_output_to_flat_files(output: &ListObjectsV2Output, prefix: &str) -> Vec<ObjectItem> {
+    let objects = output.contents();
+    objects
+        .iter()
+        .filter_map(|file| {
+            let key = file.key().unwrap();
+            let name = key.strip_prefix(prefix).unwrap_or(key);
+            if name.is_empty() {
+                // the object of the prefix itself, e.g. created as a folder in the console
+                return None;
+            }
+            let size_byte = file.size().unwrap() as usize;
+            let last_modified = convert_datetime(file.last_modified().unwrap());
+            Some(ObjectItem::File {
+                name: name.to_string(),
+                size_byte,
+                last_modified,
+            })
+        })
+        .collect()
+}
+
+// an object has at most one of the additional checksums, which is returned only if the checksum mode is enabled
+fn head_object_checksum(output: &HeadObjectOutput) -> (String, String) {
+    [
+        ("CRC32C", output.checksum_crc32_c()),
+        ("CRC32", output.checksum_crc32()),
+        ("SHA256", output.checksum_sha256()),
+        ("SHA1", output.checksum_sha1()),
+    ]
+    .into_iter()
+    .find_map(|(algorithm, checksum)| checksum.map(|c| (algorithm.to_string(), c.to_string())))
+    .unwrap_or_default()
+}
+
+fn multipart_uploads_output_to_uploads(
+    output: &ListMultipartUploadsOutput,
+) -> Vec<MultipartUpload> {
+    output
+        .uploads()
+        .iter()
+        .filter_map(|upload| {
+            let key = upload.key()?.to_string();
+            let upload_id = upload.upload_id()?.to_string();
+            let initiated = upload.initiated().map(convert_datetime);
+            Some(MultipartUpload {
+                key,
+                upload_id,
+                initiated,
+            })
+        })
+        .collect()
+}
+
+// folder placeholders (keys ending with `/`) are not files, so they are skipped
+fn object_versions_output_to_prefix_versions(
+    output: &ListObjectVersionsOutput,
+) -> Vec<PrefixVersion> {
+    let versions = output.versions().iter().filter_map(|v| {
+        Some(PrefixVersion {
+            key: v.key()?.to_string(),
+            version_id: v.version_id()?.to_string(),
+            size_byte: v.size().unwrap_or_default() as usize,
+            last_modified: convert_datetime(v.last_modified()?),
+            is_delete_marker: false,
+        })
+    });
+    let delete_markers = output.delete_markers().iter().filter_map(|m| {
+        Some(PrefixVersion {
+            key: m.key()?.to_string(),
+            version_id: m.version_id()?.to_string(),
+            size_byte: 0,
+            last_modified: convert_datetime(m.last_modified()?),
+            is_delete_marker: true,
+        })
+    });
+    versions
+        .chain(delete_markers)
+        .filter(|v| !v.key.ends_with(DELIMITER))
+        .collect()
+}
+
+fn bucket_encryption_output_to_string(output: &GetBucketEncryptionOutput) -> String {
+    let default = output
+        .server_side_encryption_configuration()
+        .and_then(|c| c.rules().first())
+        .and_then(|r| r.apply_server_side_encryption_by_default());
+    match default {
+        Some(d) => match d.kms_master_key_id() {
+            Some(key_id) => format!("{} ({})", d.sse_algorithm().as_str(), key_id),
+            None => d.sse_algorithm().as_str().to_string(),
+        },
+        None => "none".to_string(),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PublicAccessBlock {
+    ignore_public_acls: bool,
+    restrict_public_buckets: bool,
+}
+
+// public if either the policy or the ACL is public and not blocked,
+// unknown if the other one is denied
+fn effective_public(
+    block: PublicAccessBlock,
+    policy: Option<bool>,
+    acl: Option<bool>,
+) -> Option<bool> {
+    let policy = if block.restrict_public_buckets {
+        Some(false)
+    } else {
+        policy
+    };
+    let acl = if block.ignore_public_acls {
+        Some(false)
+    } else {
+        acl
+    };
+    match (policy, acl) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+// the ACLs of buckets and objects have the same structure
+fn build_acl(owner: Option<&Owner>, grants: &[Grant]) -> Acl {
+    let owner = owner.map(format_owner).unwrap_or_default();
+    let grants = grants
+        .iter()
+        .map(|grant| AclGrant {
+            grantee: grant.grantee().map(format_grantee).unwrap_or_default(),
+            permission: grant.permission().map_or("", |p| p.as_str()).to_string(),
+        })
+        .collect();
+    Acl::Grants { owner, grants }
+}
+
+fn format_owner(owner: &Owner) -> String {
+    owner
+        .display_name()
+        .or(owner.id())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn format_grantee(grantee: &Grantee) -> String {
+    if let Some(uri) = grantee.uri() {
+        // predefined groups, e.g. http://acs.amazonaws.com/groups/global/AllUsers
+        let group = uri.rsplit('/').next().unwrap_or(uri);
+        return format!("{} (group)", group);
+    }
+    if let Some(email) = grantee.email_address() {
+        return email.to_string();
+    }
+    grantee
+        .display_name()
+        .or(grantee.id())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn format_policy_document(policy: &str) -> String {
+    // show the document as is if it cannot be parsed
+    serde_json::from_str::<serde_json::Value>(policy)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or_else(|_| policy.to_string())
+}
+
 fn parse_path(path: &str, dir: bool) -> Vec<String> {
     let ss: Vec<String> = path.split(DELIMITER).map(String::from).collect();
     if dir {
@@ -315,3 +1198,816 @@ fn convert_datetime(dt: &aws_smithy_types::DateTime) -> chrono::DateTime<chrono:
     let nanos = dt.as_nanos();
     chrono::Local.timestamp_nanos(nanos as i64)
 }
+
+// the assumed credentials are cached by the identity cache of the config,
+// which assumes the role again before they expire
+async fn with_assumed_role(sdk_config: SdkConfig, assume_role: AssumeRoleConfig) -> SdkConfig {
+    let mut builder = AssumeRoleProvider::builder(assume_role.arn).configure(&sdk_config);
+    if let Some(external_id) = assume_role.external_id {
+        builder = builder.external_id(external_id);
+    }
+    if let Some(session_name) = assume_role.session_name {
+        builder = builder.session_name(session_name);
+    }
+    let provider = builder.build().await;
+    sdk_config
+        .into_builder()
+        .credentials_provider(SharedCredentialsProvider::new(provider))
+        .build()
+}
+
+fn build_timeout_config(timeout: Duration) -> TimeoutConfig {
+    // the operation timeout does not cover reading the body, see `next_chunk`
+    TimeoutConfig::builder()
+        .connect_timeout(timeout)
+        .operation_timeout(timeout)
+        .build()
+}
+
+fn sdk_error<E>(msg: impl Into<String>, e: SdkError<E, HttpResponse>) -> AppError
+where
+    E: Error + ProvideErrorMetadata + Send + Sync + 'static,
+{
+    let msg = msg.into();
+    if let SdkError::TimeoutError(_) = e {
+        return AppError::new(format!("{} (request timed out)", msg), e);
+    }
+    let details = error_details(&e);
+    if details.is_empty() {
+        AppError::new(msg, e)
+    } else {
+        AppError::new(msg, e).with_details(details)
+    }
+}
+
+fn error_details<E>(e: &SdkError<E, HttpResponse>) -> ErrorDetails
+where
+    E: ProvideErrorMetadata,
+{
+    ErrorDetails {
+        status: e.raw_response().map(|r| r.status().as_u16()),
+        code: e.code().map(String::from),
+        message: e.message().map(String::from),
+        request_id: e.request_id().map(String::from),
+        extended_request_id: e.extended_request_id().map(String::from),
+    }
+}
+
+fn is_forbidden<E>(e: &SdkError<E, HttpResponse>) -> bool {
+    e.raw_response().is_some_and(|r| r.status().as_u16() == 403)
+}
+
+async fn next_chunk<T, E>(
+    chunk: impl Future<Output = std::result::Result<T, E>>,
+    timeout: Duration,
+) -> Result<T>
+where
+    E: Error + Send + Sync + 'static,
+{
+    match tokio::time::timeout(timeout, chunk).await {
+        Ok(result) => result.map_err(|e| AppError::new("Failed to collect body", e)),
+        Err(e) => Err(AppError::new(
+            "Failed to collect body (request timed out)",
+            e,
+        )),
+    }
+}
+
+// write each chunk as it arrives so that the whole object is never held in memory
+async fn write_stream_to_file<F>(
+    mut stream: ByteStream,
+    path: &str,
+    timeout: Duration,
+    f: F,
+) -> Result<()>
+where
+    F: Fn(usize),
+{
+    let file = tokio::fs::File::from_std(create_binary_file(path)?);
+    let mut writer = BufWriter::new(file);
+    let mut written = 0;
+    while let Some(buf) = next_chunk(stream.try_next(), timeout).await? {
+        writer
+            .write_all(&buf)
+            .await
+            .map_err(|e| AppError::new("Failed to write file", e))?;
+        written += buf.len();
+        f(written)
+    }
+    writer
+        .flush()
+        .await
+        .map_err(|e| AppError::new("Failed to write file", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        io::{Read, Write},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use aws_sdk_s3::{
+        operation::get_bucket_acl::GetBucketAclOutput,
+        types::{
+            CommonPrefix, DeleteMarkerEntry, MultipartUpload as S3MultipartUpload, Object,
+            ObjectVersion, Permission, ServerSideEncryption, ServerSideEncryptionByDefault,
+            ServerSideEncryptionConfiguration, ServerSideEncryptionRule, Type,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_stream_to_file() {
+        let bytes: Vec<u8> = (0..8 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let stream = ByteStream::from(bytes.clone());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("large.bin");
+        let path = path.to_str().unwrap();
+
+        let written = AtomicUsize::new(0);
+        let result = write_stream_to_file(stream, path, Duration::from_secs(30), |n| {
+            written.store(n, Ordering::Relaxed);
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(written.load(Ordering::Relaxed), bytes.len());
+        assert_eq!(std::fs::read(path).unwrap(), bytes);
+    }
+
+    #[tokio::test]
+    async fn test_load_objects_timeout() {
+        // accepts connections (via the backlog) but never responds
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint_url = format!("http://{}", listener.local_addr().unwrap());
+        let client = test_client(&endpoint_url, Duration::from_millis(200), false);
+
+        let result = client.load_objects("bucket", "", None, false).await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.msg, "Failed to load objects (request timed out)");
+    }
+
+    #[tokio::test]
+    async fn test_load_object_detail_access_denied_hint() {
+        let endpoint_url = serve_forbidden();
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let result = client.load_object_detail("bucket", "key", "key", 0).await;
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.msg,
+            "Failed to load object detail (access denied, if the bucket is requester pays, try `--requester-pays`)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_object_detail_access_denied_requester_pays() {
+        let endpoint_url = serve_forbidden();
+        let client = test_client(&endpoint_url, Duration::from_secs(5), true);
+
+        let result = client.load_object_detail("bucket", "key", "key", 0).await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.msg, "Failed to load object detail");
+    }
+
+    #[tokio::test]
+    async fn test_load_bucket_policy_access_denied() {
+        let endpoint_url = serve_forbidden();
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let policy = client.load_bucket_policy("bucket").await.unwrap();
+        let acl = client.load_bucket_acl("bucket").await.unwrap();
+
+        assert_eq!(policy, BucketPolicy::AccessDenied);
+        assert_eq!(acl, Acl::AccessDenied);
+    }
+
+    #[tokio::test]
+    async fn test_load_bucket_encryption_not_found() {
+        let body = "<Error><Code>ServerSideEncryptionConfigurationNotFoundError</Code><Message>The server side encryption configuration was not found</Message></Error>";
+        let response = format!(
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/xml\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let endpoint_url = serve_response(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let encryption = client.load_bucket_encryption("bucket").await.unwrap();
+
+        assert_eq!(encryption, "none");
+    }
+
+    #[tokio::test]
+    async fn test_load_bucket_policy_not_set() {
+        let body = "<Error><Code>NoSuchBucketPolicy</Code><Message>The bucket policy does not exist</Message></Error>";
+        let response = format!(
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/xml\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let endpoint_url = serve_response(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let policy = client.load_bucket_policy("bucket").await.unwrap();
+
+        assert_eq!(policy, BucketPolicy::NotSet);
+    }
+
+    #[tokio::test]
+    async fn test_load_objects_error_details() {
+        let body = "<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>";
+        let response = format!(
+            "HTTP/1.1 403 Forbidden\r\nContent-Type: application/xml\r\nx-amz-request-id: REQ123\r\nx-amz-id-2: EXT456\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let endpoint_url = serve_response(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), true);
+
+        let err = client
+            .load_objects("bucket", "", None, false)
+            .await
+            .unwrap_err();
+
+        let expected = ErrorDetails {
+            status: Some(403),
+            code: Some("AccessDenied".to_string()),
+            message: Some("Access Denied".to_string()),
+            request_id: Some("REQ123".to_string()),
+            extended_request_id: Some("EXT456".to_string()),
+        };
+        assert_eq!(err.details.as_deref(), Some(&expected));
+        assert_eq!(
+            err.summary(),
+            "Failed to load objects: AccessDenied (Access Denied)"
+        );
+        assert_eq!(
+            err.details.unwrap().to_string(),
+            "status=403, code=AccessDenied, message=Access Denied, request_id=REQ123, extended_request_id=EXT456"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_objects_expired_credentials() {
+        let body = "<Error><Code>ExpiredToken</Code><Message>The provided token has expired.</Message></Error>";
+        let response = format!(
+            "HTTP/1.1 400 Bad Request\r\nContent-Type: application/xml\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let endpoint_url = serve_response(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let err = client
+            .load_objects("bucket", "", None, false)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_expired_credentials());
+    }
+
+    #[test]
+    fn test_build_management_console_urls() {
+        assert_eq!(
+            build_management_console_buckets_url("ap-northeast-1"),
+            "https://s3.console.aws.amazon.com/s3/buckets?region=ap-northeast-1"
+        );
+        assert_eq!(
+            build_management_console_list_url("ap-northeast-1", "bucket-1", "dir1/dir2/"),
+            "https://s3.console.aws.amazon.com/s3/buckets/bucket-1?region=ap-northeast-1&prefix=dir1/dir2/"
+        );
+        assert_eq!(
+            build_management_console_object_url(
+                "ap-northeast-1",
+                "bucket-1",
+                "dir1/",
+                "file.txt"
+            ),
+            "https://s3.console.aws.amazon.com/s3/object/bucket-1?region=ap-northeast-1&prefix=dir1/file.txt"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_management_console_urls_with_custom_endpoint() {
+        let client = test_client("http://localhost:9000", Duration::from_secs(5), false);
+
+        assert!(!client.is_management_console_available());
+        assert!(client.management_console_buckets_url().is_err());
+        assert!(client
+            .management_console_list_url("bucket-1", "dir1/")
+            .is_err());
+        assert!(client
+            .management_console_object_url("bucket-1", "dir1/", "file.txt")
+            .is_err());
+    }
+
+    #[test]
+    fn test_format_policy_document() {
+        let policy =
+            r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow","Action":"s3:GetObject"}]}"#;
+        let expected = r#"{
+  "Statement": [
+    {
+      "Action": "s3:GetObject",
+      "Effect": "Allow"
+    }
+  ],
+  "Version": "2012-10-17"
+}"#;
+        assert_eq!(format_policy_document(policy), expected);
+
+        // invalid documents are shown as is
+        assert_eq!(format_policy_document("{invalid"), "{invalid");
+    }
+
+    #[test]
+    fn test_bucket_encryption_output_to_string() {
+        let output = |algorithm: ServerSideEncryption, key_id: Option<&str>| {
+            let default = ServerSideEncryptionByDefault::builder()
+                .sse_algorithm(algorithm)
+                .set_kms_master_key_id(key_id.map(String::from))
+                .build()
+                .unwrap();
+            let rule = ServerSideEncryptionRule::builder()
+                .apply_server_side_encryption_by_default(default)
+                .build();
+            let config = ServerSideEncryptionConfiguration::builder()
+                .rules(rule)
+                .build()
+                .unwrap();
+            GetBucketEncryptionOutput::builder()
+                .server_side_encryption_configuration(config)
+                .build()
+        };
+
+        assert_eq!(
+            bucket_encryption_output_to_string(&output(ServerSideEncryption::Aes256, None)),
+            "AES256"
+        );
+        assert_eq!(
+            bucket_encryption_output_to_string(&output(
+                ServerSideEncryption::AwsKms,
+                Some("key-id")
+            )),
+            "aws:kms (key-id)"
+        );
+        assert_eq!(
+            bucket_encryption_output_to_string(&GetBucketEncryptionOutput::builder().build()),
+            "none"
+        );
+    }
+
+    #[test]
+    fn test_build_acl() {
+        let output = GetBucketAclOutput::builder()
+            .owner(Owner::builder().id("owner-id").build())
+            .grants(
+                Grant::builder()
+                    .grantee(
+                        Grantee::builder()
+                            .r#type(Type::CanonicalUser)
+                            .id("owner-id")
+                            .build()
+                            .unwrap(),
+                    )
+                    .permission(Permission::FullControl)
+                    .build(),
+            )
+            .grants(
+                Grant::builder()
+                    .grantee(
+                        Grantee::builder()
+                            .r#type(Type::Group)
+                            .uri("http://acs.amazonaws.com/groups/global/AllUsers")
+                            .build()
+                            .unwrap(),
+                    )
+                    .permission(Permission::Read)
+                    .build(),
+            )
+            .build();
+
+        let actual = build_acl(output.owner(), output.grants());
+
+        let expected = Acl::Grants {
+            owner: "owner-id".to_string(),
+            grants: vec![
+                AclGrant {
+                    grantee: "owner-id".to_string(),
+                    permission: "FULL_CONTROL".to_string(),
+                },
+                AclGrant {
+                    grantee: "AllUsers (group)".to_string(),
+                    permission: "READ".to_string(),
+                },
+            ],
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(false, false, Some(false), Some(false), Some(false))]
+    #[case(false, false, Some(true), Some(false), Some(true))]
+    #[case(false, false, Some(false), Some(true), Some(true))]
+    #[case(false, false, None, Some(true), Some(true))]
+    #[case(false, false, None, Some(false), None)]
+    #[case(false, false, Some(false), None, None)]
+    #[case(true, false, Some(false), Some(true), Some(false))]
+    #[case(false, true, Some(true), Some(false), Some(false))]
+    #[case(true, true, None, None, Some(false))]
+    fn test_effective_public(
+        #[case] ignore_public_acls: bool,
+        #[case] restrict_public_buckets: bool,
+        #[case] policy: Option<bool>,
+        #[case] acl: Option<bool>,
+        #[case] expected: Option<bool>,
+    ) {
+        let block = PublicAccessBlock {
+            ignore_public_acls,
+            restrict_public_buckets,
+        };
+        assert_eq!(effective_public(block, policy, acl), expected);
+    }
+
+    #[tokio::test]
+    async fn test_list_recursive() {
+        // a/ has many children so that the listing can run in parallel
+        let tree: HashMap<String, (Vec<String>, Vec<String>)> = [
+            ("", (vec!["a/", "b/"], vec!["root.txt"])),
+            ("a/", (vec!["a/1/", "a/2/", "a/3/", "a/4/", "a/5/"], vec![])),
+            ("a/1/", (vec![], vec!["a/1/x.txt"])),
+            ("a/2/", (vec![], vec!["a/2/x.txt", "a/2/y.txt"])),
+            ("a/3/", (vec!["a/3/deep/"], vec![])),
+            ("a/3/deep/", (vec![], vec!["a/3/deep/z.txt"])),
+            ("a/4/", (vec![], vec![])),
+            ("a/5/", (vec![], vec!["a/5/x.txt"])),
+            ("b/", (vec![], vec!["b/x.txt"])),
+        ]
+        .into_iter()
+        .map(|(prefix, (prefixes, keys))| {
+            let to_strings = |v: Vec<&str>| v.into_iter().map(String::from).collect();
+            (prefix.to_string(), (to_strings(prefixes), to_strings(keys)))
+        })
+        .collect();
+        let tree = Arc::new(tree);
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let list = |prefix: String| {
+            let tree = tree.clone();
+            let running = running.clone();
+            let max_running = max_running.clone();
+            async move {
+                let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(n, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(tree[&prefix].clone())
+            }
+        };
+
+        let actual = list_recursive("".to_string(), 3, list).await.unwrap();
+
+        let expected = [
+            "a/1/x.txt",
+            "a/2/x.txt",
+            "a/2/y.txt",
+            "a/3/deep/z.txt",
+            "a/5/x.txt",
+            "b/x.txt",
+            "root.txt",
+        ];
+        assert_eq!(actual, expected);
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    // responds 403 with an empty body to every request, like HEAD to a requester pays bucket
+    fn serve_forbidden() -> String {
+        serve_response("HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_load_objects_max_keys() {
+        let body = "<ListBucketResult></ListBucketResult>";
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/xml\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (endpoint_url, requests) = serve_response_and_record(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let items = client
+            .load_objects("bucket", "", Some(200), false)
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+        let request = requests.recv().unwrap();
+        assert!(request.contains("max-keys=200"), "{}", request);
+    }
+
+    #[rstest]
+    #[case(Some(100), 1000, Some("bytes=0-99"), 100)]
+    #[case(Some(100), 100, None, 100)]
+    #[case(None, 1000, None, 1000)]
+    #[tokio::test]
+    async fn test_download_object_max_bytes(
+        #[case] max_bytes: Option<usize>,
+        #[case] size_byte: usize,
+        #[case] expected_range: Option<&str>,
+        #[case] expected_len: usize,
+    ) {
+        let body = "x".repeat(expected_len);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (endpoint_url, requests) = serve_response_and_record(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let obj = client
+            .download_object("bucket", "file.txt", None, size_byte, max_bytes, |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(obj.bytes.len(), expected_len);
+        let request = requests.recv().unwrap().to_lowercase();
+        match expected_range {
+            Some(range) => assert!(
+                request.contains(&format!("range: {}", range)),
+                "{}",
+                request
+            ),
+            None => assert!(!request.contains("range:"), "{}", request),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_object_range() {
+        let body = "x".repeat(256);
+        let response = format!(
+            "HTTP/1.1 206 Partial Content\r\nContent-Type: application/octet-stream\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (endpoint_url, requests) = serve_response_and_record(response);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+
+        let range = ByteRange::parse("1024+256", 4096).unwrap();
+        let obj = client
+            .download_object_range("bucket", "file.bin", Some("v1".to_string()), range)
+            .await
+            .unwrap();
+
+        assert_eq!(obj.bytes.len(), 256);
+        let request = requests.recv().unwrap().to_lowercase();
+        assert!(request.contains("range: bytes=1024-1279"), "{}", request);
+        assert!(request.contains("versionid=v1"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_load_objects_flat() {
+        // same objects, listed with and without the delimiter
+        let keys = ["top/", "top/a.txt", "top/dir/b.txt", "top/dir/c/d.txt"];
+        let folded = list_objects_response(&keys[1..2], &["top/dir/"]);
+        let flat = list_objects_response(&keys, &[]);
+
+        let (endpoint_url, requests) = serve_response_and_record(folded);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+        let items = client
+            .load_objects("bucket", "top/", None, false)
+            .await
+            .unwrap();
+        let names: Vec<&str> = items.iter().map(|item| item.name()).collect();
+        assert_eq!(names, vec!["dir", "a.txt"]);
+        assert!(requests.recv().unwrap().contains("delimiter=%2F"));
+
+        let (endpoint_url, requests) = serve_response_and_record(flat);
+        let client = test_client(&endpoint_url, Duration::from_secs(5), false);
+        let items = client
+            .load_objects("bucket", "top/", None, true)
+            .await
+            .unwrap();
+        let names: Vec<&str> = items.iter().map(|item| item.name()).collect();
+        assert_eq!(names, vec!["a.txt", "dir/b.txt", "dir/c/d.txt"]);
+        assert!(items
+            .iter()
+            .all(|item| matches!(item, ObjectItem::File { .. })));
+        assert!(!requests.recv().unwrap().contains("delimiter="));
+    }
+
+    fn list_objects_response(keys: &[&str], prefixes: &[&str]) -> String {
+        let contents: String = keys
+            .iter()
+            .map(|key| {
+                format!(
+                    "<Contents><Key>{}</Key><Size>10</Size><LastModified>2024-01-02T13:01:02.000Z</LastModified></Contents>",
+                    key
+                )
+            })
+            .collect();
+        let prefixes: String = prefixes
+            .iter()
+            .map(|prefix| {
+                format!(
+                    "<CommonPrefixes><Prefix>{}</Prefix></CommonPrefixes>",
+                    prefix
+                )
+            })
+            .collect();
+        let body = format!(
+            "<ListBucketResult>{}{}</ListBucketResult>",
+            contents, prefixes
+        );
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/xml\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    fn serve_response(response: String) -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0; 4096];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        endpoint_url
+    }
+
+    // also returns the received requests, to check what the client sent
+    fn serve_response_and_record(response: String) -> (String, std::sync::mpsc::Receiver<String>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let _ = tx.send(String::from_utf8_lossy(&buf[..n]).into_owned());
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (endpoint_url, rx)
+    }
+
+    #[test]
+    fn test_objects_output_with_same_name_file_and_dir() {
+        let output = ListObjectsV2Output::builder()
+            .common_prefixes(CommonPrefix::builder().prefix("dir/foo/").build())
+            .contents(
+                Object::builder()
+                    .key("dir/foo")
+                    .size(1024)
+                    .last_modified(aws_smithy_types::DateTime::from_secs(0))
+                    .build(),
+            )
+            .build();
+
+        let dirs = objects_output_to_dirs(&output);
+        let files = objects_output_to_files(&output);
+
+        assert_eq!(dirs.len(), 1);
+        assert!(matches!(&dirs[0], ObjectItem::Dir { name } if name == "foo"));
+        assert_eq!(files.len(), 1);
+        assert!(
+            matches!(&files[0], ObjectItem::File { name, size_byte, .. } if name == "foo" && *size_byte == 1024)
+        );
+    }
+
+    #[test]
+    fn test_multipart_uploads_output_to_uploads() {
+        let output = ListMultipartUploadsOutput::builder()
+            .uploads(
+                S3MultipartUpload::builder()
+                    .key("dir/large.bin")
+                    .upload_id("upload-1")
+                    .initiated(aws_smithy_types::DateTime::from_secs(0))
+                    .build(),
+            )
+            .uploads(
+                S3MultipartUpload::builder()
+                    .key("other.bin")
+                    .upload_id("upload-2")
+                    .build(),
+            )
+            // uploads without the key or the upload id cannot be aborted, so they are skipped
+            .uploads(S3MultipartUpload::builder().key("broken.bin").build())
+            .build();
+
+        let uploads = multipart_uploads_output_to_uploads(&output);
+
+        assert_eq!(
+            uploads,
+            vec![
+                MultipartUpload {
+                    key: "dir/large.bin".to_string(),
+                    upload_id: "upload-1".to_string(),
+                    initiated: Some(convert_datetime(&aws_smithy_types::DateTime::from_secs(0))),
+                },
+                MultipartUpload {
+                    key: "other.bin".to_string(),
+                    upload_id: "upload-2".to_string(),
+                    initiated: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_object_versions_output_to_prefix_versions() {
+        let output = ListObjectVersionsOutput::builder()
+            .versions(
+                ObjectVersion::builder()
+                    .key("dir/a.txt")
+                    .version_id("v1")
+                    .size(10)
+                    .last_modified(aws_smithy_types::DateTime::from_secs(0))
+                    .build(),
+            )
+            // folder placeholder
+            .versions(
+                ObjectVersion::builder()
+                    .key("dir/")
+                    .version_id("v2")
+                    .size(0)
+                    .last_modified(aws_smithy_types::DateTime::from_secs(0))
+                    .build(),
+            )
+            .delete_markers(
+                DeleteMarkerEntry::builder()
+                    .key("dir/b.txt")
+                    .version_id("v3")
+                    .last_modified(aws_smithy_types::DateTime::from_secs(60))
+                    .build(),
+            )
+            .build();
+
+        let versions = object_versions_output_to_prefix_versions(&output);
+
+        assert_eq!(
+            versions,
+            vec![
+                PrefixVersion {
+                    key: "dir/a.txt".to_string(),
+                    version_id: "v1".to_string(),
+                    size_byte: 10,
+                    last_modified: convert_datetime(&aws_smithy_types::DateTime::from_secs(0)),
+                    is_delete_marker: false,
+                },
+                PrefixVersion {
+                    key: "dir/b.txt".to_string(),
+                    version_id: "v3".to_string(),
+                    size_byte: 0,
+                    last_modified: convert_datetime(&aws_smithy_types::DateTime::from_secs(60)),
+                    is_delete_marker: true,
+                },
+            ]
+        );
+    }
+
+    fn test_client(endpoint_url: &str, timeout: Duration, requester_pays: bool) -> Client {
+        let credentials = aws_sdk_s3::config::Credentials::new("test", "test", None, None, "test");
+        let config = aws_sdk_s3::config::Builder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(DEFAULT_REGION))
+            .credentials_provider(credentials)
+            .endpoint_url(endpoint_url)
+            .force_path_style(true)
+            .timeout_config(build_timeout_config(timeout))
+            .build();
+        let sts_config = aws_sdk_sts::config::Builder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(DEFAULT_REGION))
+            .build();
+        Client {
+            client: aws_sdk_s3::Client::from_conf(config),
+            sts_client: aws_sdk_sts::Client::from_conf(sts_config),
+            region: DEFAULT_REGION.to_string(),
+            timeout,
+            request_payer: requester_pays.then_some(RequestPayer::Requester),
+            options: ClientOptions {
+                endpoint_url: Some(endpoint_url.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
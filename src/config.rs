@@ -1,28 +1,436 @@
-use std::{env, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
 
 use anyhow::Context;
+use chrono::{DateTime, Local, TimeZone};
+use ratatui::style::{Color, Style, Stylize};
 use serde_derive::{Deserialize, Serialize};
 
+use crate::file::{download_file_path_in, expand_home_dir};
+
 const STU_ROOT_DIR_ENV_VAR: &str = "STU_ROOT_DIR";
 
 const APP_BASE_DIR: &str = ".stu";
 const CONFIG_FILE_NAME: &str = "config.toml";
 const ERROR_LOG_FILE_NAME: &str = "error.log";
 const DEBUG_LOG_FILE_NAME: &str = "debug.log";
+const SESSION_FILE_NAME: &str = "session.toml";
 const DOWNLOAD_DIR: &str = "download";
 
+const MAX_KEYS_LIMIT: usize = 1000;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
     #[serde(default = "default_download_dir")]
     pub download_dir: String,
     #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub requester_pays: bool,
+    #[serde(default)]
+    pub confirm_quit: bool,
+    #[serde(default)]
+    pub no_color: bool,
+    #[serde(default)]
+    pub no_management_console: bool,
+    #[serde(default)]
+    pub status_bar: bool,
+    #[serde(default)]
+    pub download: DownloadConfig,
+    #[serde(default)]
+    pub list: ListConfig,
+    #[serde(default)]
     pub preview: PreviewConfig,
+    #[serde(default)]
+    pub detail: DetailConfig,
+    #[serde(default)]
+    pub open: OpenConfig,
+    #[serde(default)]
+    pub session: SessionConfig,
+    #[serde(default)]
+    pub single_bucket: SingleBucketConfig,
+    #[serde(default)]
+    pub time: TimeConfig,
+    #[serde(default)]
+    pub size: SizeConfig,
+    #[serde(default)]
+    pub request: RequestConfig,
+    #[serde(default)]
+    pub assume_role: Option<AssumeRoleConfig>,
+    #[serde(default)]
+    pub clipboard: ClipboardConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub terminal: TerminalConfig,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct DownloadConfig {
+    #[serde(default)]
+    pub on_conflict: DownloadConflictAction,
+    #[serde(default)]
+    pub dir: Option<String>,
+    #[serde(default)]
+    pub filename_template: Option<String>,
+    #[serde(default)]
+    pub flatten_key: bool,
+    #[serde(default)]
+    pub verify_checksum: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadConflictAction {
+    Overwrite,
+    Rename,
+    #[default]
+    Prompt,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListConfig {
+    #[serde(default)]
+    pub icons: bool,
+    #[serde(default)]
+    pub bucket_versioning: bool,
+    #[serde(default)]
+    pub page_size: Option<usize>,
+    #[serde(default)]
+    pub numbers: bool,
+    #[serde(default = "default_list_concurrency")]
+    pub concurrency: usize,
+    #[serde(default)]
+    pub page_request_size: Option<usize>,
+    #[serde(default)]
+    pub remember_filter: bool,
+    #[serde(default = "default_load_all_pages")]
+    pub load_all_pages: bool,
+    #[serde(default = "default_list_wrap")]
+    pub wrap: bool,
+    #[serde(default)]
+    pub mark_newest: bool,
+    #[serde(default)]
+    pub full_key: bool,
+    #[serde(default)]
+    pub public_access: bool,
+}
+
+impl Default for ListConfig {
+    fn default() -> Self {
+        Self {
+            icons: false,
+            bucket_versioning: false,
+            page_size: None,
+            numbers: false,
+            concurrency: default_list_concurrency(),
+            page_request_size: None,
+            remember_filter: false,
+            load_all_pages: default_load_all_pages(),
+            wrap: default_list_wrap(),
+            mark_newest: false,
+            full_key: false,
+            public_access: false,
+        }
+    }
+}
+
+impl ListConfig {
+    // S3 returns at most 1000 keys per request, larger values are clamped to it
+    pub fn max_keys(&self) -> Option<i32> {
+        self.page_request_size
+            .map(|n| n.clamp(1, MAX_KEYS_LIMIT) as i32)
+    }
+}
+
+fn default_list_concurrency() -> usize {
+    8
+}
+
+fn default_load_all_pages() -> bool {
+    true
+}
+
+fn default_list_wrap() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PreviewConfig {
     #[serde(default)]
     pub highlight: bool,
+    #[serde(default = "default_preview_max_bytes")]
+    pub max_bytes: usize,
+    #[serde(default)]
+    pub handlers: BTreeMap<String, PreviewHandler>,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        Self {
+            highlight: false,
+            max_bytes: default_preview_max_bytes(),
+            handlers: BTreeMap::new(),
+        }
+    }
+}
+
+impl PreviewConfig {
+    // the extensions are compared ignoring the case and the leading dot, e.g. `.LOG` for `app.log`
+    pub fn handler(&self, file_name: &str) -> Option<PreviewHandler> {
+        let (_, extension) = file_name.rsplit_once('.')?;
+        self.handlers
+            .iter()
+            .find(|(ext, _)| ext.trim_start_matches('.').eq_ignore_ascii_case(extension))
+            .map(|(_, handler)| *handler)
+    }
+}
+
+fn default_preview_max_bytes() -> usize {
+    5 * 1024 * 1024
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewHandler {
+    Text,
+    Hex,
+    // any other value, so that a typo or a handler of a newer version does not fail to load the whole config.
+    // the content is sniffed as without a handler, with a warning.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct DetailConfig {
+    #[serde(default)]
+    pub default_tab: DetailDefaultTab,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DetailDefaultTab {
+    #[default]
+    Detail,
+    Version,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct OpenConfig {
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SessionConfig {
+    #[serde(default)]
+    pub restore: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SingleBucketConfig {
+    #[serde(default = "default_single_bucket_auto_enter")]
+    pub auto_enter: bool,
+    #[serde(default)]
+    pub default_prefix: Option<String>,
+}
+
+impl Default for SingleBucketConfig {
+    fn default() -> Self {
+        Self {
+            auto_enter: default_single_bucket_auto_enter(),
+            default_prefix: None,
+        }
+    }
+}
+
+fn default_single_bucket_auto_enter() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct TimeConfig {
+    #[serde(default)]
+    pub relative: bool,
+    #[serde(default)]
+    pub format: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SizeConfig {
+    #[serde(default)]
+    pub units: SizeUnits,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeUnits {
+    #[default]
+    Binary,
+    Decimal,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestConfig {
+    #[serde(default = "default_request_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_request_timeout_secs(),
+        }
+    }
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClipboardConfig {
+    #[serde(default)]
+    pub backend: ClipboardBackend,
+    #[serde(default = "default_clipboard_data_uri_max_size")]
+    pub data_uri_max_size: usize,
+    #[serde(default = "default_clipboard_content_max_size")]
+    pub content_max_size: usize,
+    #[serde(default)]
+    pub strip_trailing_newline: bool,
+    #[serde(default)]
+    pub templates: BTreeMap<String, String>,
+    #[serde(default)]
+    pub value_width: Option<usize>,
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        Self {
+            backend: ClipboardBackend::default(),
+            data_uri_max_size: default_clipboard_data_uri_max_size(),
+            content_max_size: default_clipboard_content_max_size(),
+            strip_trailing_newline: false,
+            templates: BTreeMap::new(),
+            value_width: None,
+        }
+    }
+}
+
+fn default_clipboard_data_uri_max_size() -> usize {
+    256 * 1024
+}
+
+fn default_clipboard_content_max_size() -> usize {
+    64 * 1024
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardBackend {
+    #[default]
+    Auto,
+    Native,
+    Osc52,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TerminalConfig {
+    #[serde(default = "default_terminal_min_width")]
+    pub min_width: u16,
+    #[serde(default = "default_terminal_min_height")]
+    pub min_height: u16,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        Self {
+            min_width: default_terminal_min_width(),
+            min_height: default_terminal_min_height(),
+        }
+    }
+}
+
+fn default_terminal_min_width() -> u16 {
+    40
+}
+
+fn default_terminal_min_height() -> u16 {
+    10
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub highlight: HighlightConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HighlightConfig {
+    #[serde(default = "default_highlight_fg")]
+    pub fg: Option<String>,
+    #[serde(default)]
+    pub bg: Option<String>,
+    #[serde(default)]
+    pub bold: bool,
+    #[serde(default)]
+    pub underline: bool,
+}
+
+impl Default for HighlightConfig {
+    fn default() -> Self {
+        Self {
+            fg: default_highlight_fg(),
+            bg: None,
+            bold: false,
+            underline: false,
+        }
+    }
+}
+
+impl HighlightConfig {
+    // colors that cannot be parsed are ignored, and the text keeps its own color
+    pub fn style(&self) -> Style {
+        let mut style = Style::default();
+        if let Some(fg) = self.fg.as_deref().and_then(|s| Color::from_str(s).ok()) {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = self.bg.as_deref().and_then(|s| Color::from_str(s).ok()) {
+            style = style.bg(bg);
+        }
+        if self.bold {
+            style = style.bold();
+        }
+        if self.underline {
+            style = style.underlined();
+        }
+        style
+    }
+}
+
+fn default_highlight_fg() -> Option<String> {
+    Some("red".to_string())
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct AssumeRoleConfig {
+    pub arn: String,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub session_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SessionState {
+    #[serde(default)]
+    pub bucket: Option<String>,
+    #[serde(default)]
+    pub path: Vec<String>,
 }
 
 impl Default for Config {
@@ -30,11 +438,84 @@ impl Default for Config {
         let download_dir = default_download_dir();
         Self {
             download_dir,
+            read_only: false,
+            requester_pays: false,
+            confirm_quit: false,
+            no_color: false,
+            no_management_console: false,
+            status_bar: false,
+            download: DownloadConfig::default(),
+            list: ListConfig::default(),
             preview: PreviewConfig::default(),
+            detail: DetailConfig::default(),
+            open: OpenConfig::default(),
+            session: SessionConfig::default(),
+            single_bucket: SingleBucketConfig::default(),
+            time: TimeConfig::default(),
+            size: SizeConfig::default(),
+            request: RequestConfig::default(),
+            assume_role: None,
+            clipboard: ClipboardConfig::default(),
+            theme: ThemeConfig::default(),
+            terminal: TerminalConfig::default(),
         }
     }
 }
 
+fn expand_filename_template(
+    template: &str,
+    bucket: &str,
+    key: &str,
+    timestamp: &DateTime<Local>,
+    flatten_key: bool,
+) -> String {
+    let name = key.rsplit('/').next().unwrap_or(key);
+    let key = if flatten_key {
+        key.replace('/', "_")
+    } else {
+        key.to_string()
+    };
+    template
+        .replace("{bucket}", bucket)
+        .replace("{key}", &key)
+        .replace("{name}", name)
+        .replace("{timestamp}", &timestamp.format("%Y%m%d%H%M%S").to_string())
+}
+
+// only the normal components are kept, so that the path cannot be absolute or go up (e.g. `/etc/x`, `../x`)
+fn sanitize_relative_path(name: &str) -> PathBuf {
+    Path::new(name)
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(s) => Some(s),
+            _ => None,
+        })
+        .collect()
+}
+
+// chrono reports an invalid specifier only when the date is actually formatted
+fn is_valid_datetime_format(format: &str) -> bool {
+    use std::fmt::Write;
+    let datetime = Local.timestamp_opt(0, 0).unwrap();
+    write!(String::new(), "{}", datetime.format(format)).is_ok()
+}
+
+// arn:{partition}:iam::{account id}:role/{path}{name}
+fn is_valid_role_arn(arn: &str) -> bool {
+    let parts: Vec<&str> = arn.splitn(6, ':').collect();
+    match parts[..] {
+        ["arn", partition, "iam", "", account_id, resource] => {
+            !partition.is_empty()
+                && account_id.len() == 12
+                && account_id.chars().all(|c| c.is_ascii_digit())
+                && resource
+                    .strip_prefix("role/")
+                    .is_some_and(|name| !name.is_empty() && !name.ends_with('/'))
+        }
+        _ => false,
+    }
+}
+
 fn default_download_dir() -> String {
     match Config::get_app_base_dir() {
         Ok(dir) => {
@@ -49,12 +530,53 @@ impl Config {
     pub fn load() -> anyhow::Result<Config> {
         let dir = Config::get_app_base_dir()?;
         let path = dir.join(CONFIG_FILE_NAME);
-        confy::load_path(path).context("Failed to load config file")
+        let config: Config = confy::load_path(path).context("Failed to load config file")?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Some(assume_role) = &self.assume_role {
+            if !is_valid_role_arn(&assume_role.arn) {
+                anyhow::bail!("Invalid role ARN in assume_role.arn: {}", assume_role.arn);
+            }
+        }
+        if let Some(format) = &self.time.format {
+            if !is_valid_datetime_format(format) {
+                anyhow::bail!("Invalid format in time.format: {}", format);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn download_base_dir(&self) -> String {
+        // `download.dir` takes precedence over `download_dir`
+        let dir = self.download.dir.as_ref().unwrap_or(&self.download_dir);
+        expand_home_dir(dir)
     }
 
     pub fn download_file_path(&self, name: &str) -> String {
-        let dir = PathBuf::from(self.download_dir.clone());
-        let path = dir.join(name);
+        download_file_path_in(&self.download_base_dir(), name)
+    }
+
+    pub fn default_download_file_path(&self, bucket: &str, key: &str) -> String {
+        let name = match &self.download.filename_template {
+            Some(template) => expand_filename_template(
+                template,
+                bucket,
+                key,
+                &Local::now(),
+                self.download.flatten_key,
+            ),
+            None => key.rsplit('/').next().unwrap_or(key).to_string(),
+        };
+        // the key comes from the bucket, so it must not point outside the download directory
+        let dir = PathBuf::from(self.download_base_dir());
+        let path = dir.join(sanitize_relative_path(&name));
+        if !path.starts_with(&dir) || path == dir {
+            // nothing is left of the name (e.g. `..`)
+            return String::from(dir.join("_").to_string_lossy());
+        }
         String::from(path.to_string_lossy())
     }
 
@@ -70,6 +592,18 @@ impl Config {
         Ok(String::from(path.to_string_lossy()))
     }
 
+    pub fn load_session_state(&self) -> anyhow::Result<SessionState> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(SESSION_FILE_NAME);
+        confy::load_path(path).context("Failed to load session file")
+    }
+
+    pub fn save_session_state(&self, state: SessionState) -> anyhow::Result<()> {
+        let dir = Config::get_app_base_dir()?;
+        let path = dir.join(SESSION_FILE_NAME);
+        confy::store_path(path, state).context("Failed to save session file")
+    }
+
     fn get_app_base_dir() -> anyhow::Result<PathBuf> {
         match env::var(STU_ROOT_DIR_ENV_VAR) {
             Ok(dir) => Ok(PathBuf::from(dir)),
@@ -82,3 +616,154 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(None, "dir/file.txt", "dl/file.txt")]
+    #[case(None, "../x", "dl/x")]
+    #[case(None, "/etc/x", "dl/x")]
+    #[case(None, "dir/..", "dl/_")]
+    #[case(Some("{key}"), "dir/file.txt", "dl/dir/file.txt")]
+    #[case(Some("{key}"), "../x", "dl/x")]
+    #[case(Some("{key}"), "a/../../x", "dl/a/x")]
+    #[case(Some("{key}"), "/etc/x", "dl/etc/x")]
+    #[case(Some("{key}"), "~/x", "dl/~/x")]
+    #[case(Some("/{bucket}/{key}"), "/etc/x", "dl/bucket-1/etc/x")]
+    fn test_default_download_file_path(
+        #[case] template: Option<&str>,
+        #[case] key: &str,
+        #[case] expected: &str,
+    ) {
+        let config = Config {
+            download: DownloadConfig {
+                dir: Some("dl".to_string()),
+                filename_template: template.map(String::from),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let actual = config.default_download_file_path("bucket-1", key);
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("{name}", "dir/file.txt", false, "file.txt")]
+    #[case("{bucket}/{key}", "dir/file.txt", false, "bucket-1/dir/file.txt")]
+    #[case("{bucket}/{key}", "dir/file.txt", true, "bucket-1/dir_file.txt")]
+    #[case("{timestamp}_{name}", "a/b/file.txt", false, "20240102130102_file.txt")]
+    #[case("{key}-{key}", "a/b", true, "a_b-a_b")]
+    #[case("fixed.txt", "dir/file.txt", false, "fixed.txt")]
+    fn test_expand_filename_template(
+        #[case] template: &str,
+        #[case] key: &str,
+        #[case] flatten_key: bool,
+        #[case] expected: &str,
+    ) {
+        let timestamp = NaiveDateTime::parse_from_str("2024-01-02 13:01:02", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap();
+        let actual = expand_filename_template(template, "bucket-1", key, &timestamp, flatten_key);
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("arn:aws:iam::123456789012:role/example", true)]
+    #[case("arn:aws:iam::123456789012:role/path/to/example", true)]
+    #[case("arn:aws-cn:iam::123456789012:role/example", true)]
+    #[case("arn:aws:iam::123456789012:user/example", false)]
+    #[case("arn:aws:iam::123456789012:role/", false)]
+    #[case("arn:aws:iam::12345:role/example", false)]
+    #[case("arn:aws:s3:::bucket", false)]
+    #[case("example", false)]
+    fn test_is_valid_role_arn(#[case] arn: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_role_arn(arn), expected);
+    }
+
+    #[rstest]
+    #[case("%Y-%m-%d %H:%M:%S", true)]
+    #[case("%Y-%m-%dT%H:%M:%S%:z", true)]
+    #[case("%c", true)]
+    #[case("plain text", true)]
+    #[case("%Y-%m-%d %Q", false)]
+    #[case("%", false)]
+    fn test_is_valid_datetime_format(#[case] format: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_datetime_format(format), expected);
+    }
+
+    #[rstest]
+    #[case(None, None)]
+    #[case(Some(200), Some(200))]
+    #[case(Some(0), Some(1))]
+    #[case(Some(5000), Some(1000))]
+    fn test_list_config_max_keys(#[case] size: Option<usize>, #[case] expected: Option<i32>) {
+        let config = ListConfig {
+            page_request_size: size,
+            ..Default::default()
+        };
+        assert_eq!(config.max_keys(), expected);
+    }
+
+    #[rstest]
+    #[case("app.log", Some(PreviewHandler::Text))]
+    #[case("APP.LOG", Some(PreviewHandler::Text))]
+    #[case("dump.bin", Some(PreviewHandler::Hex))]
+    #[case("archive.log.gz", None)]
+    #[case("log", None)]
+    #[case("file.txt", None)]
+    fn test_preview_config_handler(
+        #[case] file_name: &str,
+        #[case] expected: Option<PreviewHandler>,
+    ) {
+        let config = PreviewConfig {
+            handlers: BTreeMap::from([
+                ("log".to_string(), PreviewHandler::Text),
+                (".bin".to_string(), PreviewHandler::Hex),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(config.handler(file_name), expected);
+    }
+
+    #[test]
+    fn test_preview_config_unknown_handler() {
+        let config: PreviewConfig = serde_json::from_value(serde_json::json!({
+            "handlers": { "log": "text", "json": "json" },
+        }))
+        .unwrap();
+        assert_eq!(config.handler("app.log"), Some(PreviewHandler::Text));
+        assert_eq!(config.handler("data.json"), Some(PreviewHandler::Unknown));
+    }
+
+    #[test]
+    fn test_highlight_config_style() {
+        let config = HighlightConfig::default();
+        assert_eq!(config.style(), Style::default().fg(Color::Red));
+
+        let config = HighlightConfig {
+            fg: Some("black".to_string()),
+            bg: Some("#ffcc00".to_string()),
+            bold: true,
+            underline: true,
+        };
+        let expected = Style::default()
+            .fg(Color::Black)
+            .bg(Color::Rgb(0xff, 0xcc, 0x00))
+            .bold()
+            .underlined();
+        assert_eq!(config.style(), expected);
+
+        // invalid colors are ignored
+        let config = HighlightConfig {
+            fg: Some("not-a-color".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.style(), Style::default());
+    }
+}
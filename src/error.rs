@@ -1,11 +1,29 @@
-use std::error::Error;
+use std::{error::Error, fmt::Display};
 
 pub type Result<T> = std::result::Result<T, AppError>;
 
+// returned when temporary credentials (e.g. SSO or assumed role) are no longer valid
+const EXPIRED_CREDENTIALS_CODES: [&str; 3] = [
+    "ExpiredToken",
+    "ExpiredTokenException",
+    "TokenRefreshRequired",
+];
+
 #[derive(Debug)]
 pub struct AppError {
     pub msg: String,
     pub cause: Option<Box<dyn Error + Send + 'static>>,
+    pub details: Option<Box<ErrorDetails>>, // boxed to keep Result small
+}
+
+// details of the error returned from the service, used for debugging access issues
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ErrorDetails {
+    pub status: Option<u16>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub request_id: Option<String>,
+    pub extended_request_id: Option<String>,
 }
 
 impl AppError {
@@ -13,6 +31,7 @@ impl AppError {
         AppError {
             msg: msg.into(),
             cause: Some(Box::new(e)),
+            details: None,
         }
     }
 
@@ -20,6 +39,7 @@ impl AppError {
         AppError {
             msg: msg.into(),
             cause: None,
+            details: None,
         }
     }
 
@@ -27,6 +47,65 @@ impl AppError {
         AppError {
             msg: e.to_string(),
             cause: Some(Box::new(e)),
+            details: None,
         }
     }
+
+    pub fn with_details(self, details: ErrorDetails) -> AppError {
+        AppError {
+            details: Some(Box::new(details)),
+            ..self
+        }
+    }
+
+    pub fn is_expired_credentials(&self) -> bool {
+        self.details
+            .as_ref()
+            .and_then(|d| d.code.as_deref())
+            .is_some_and(|code| EXPIRED_CREDENTIALS_CODES.contains(&code))
+    }
+
+    // with the details, as written to the error log
+    pub fn full_msg(&self) -> String {
+        match &self.details {
+            Some(details) => format!("{} [{}]", self.msg, details),
+            None => self.msg.clone(),
+        }
+    }
+
+    // one-liner for the notification, the full details are written to the error log
+    pub fn summary(&self) -> String {
+        let Some(details) = &self.details else {
+            return self.msg.clone();
+        };
+        match (&details.code, &details.message) {
+            (Some(code), Some(message)) => format!("{}: {} ({})", self.msg, code, message),
+            (Some(code), None) => format!("{}: {}", self.msg, code),
+            _ => self.msg.clone(),
+        }
+    }
+}
+
+impl ErrorDetails {
+    pub fn is_empty(&self) -> bool {
+        self == &ErrorDetails::default()
+    }
+}
+
+impl Display for ErrorDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = [
+            ("status", self.status.map(|s| s.to_string())),
+            ("code", self.code.clone()),
+            ("message", self.message.clone()),
+            ("request_id", self.request_id.clone()),
+            ("extended_request_id", self.extended_request_id.clone()),
+        ];
+        let s = fields
+            .into_iter()
+            .filter_map(|(k, v)| v.map(|v| format!("{}={}", k, v)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{}", s)
+    }
 }
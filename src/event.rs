@@ -2,6 +2,7 @@ use std::{
     fmt::{self, Debug, Formatter},
     sync::mpsc,
     thread,
+    time::Duration,
 };
 
 use crossterm::event::KeyEvent;
@@ -9,39 +10,104 @@ use crossterm::event::KeyEvent;
 use crate::{
     client::Client,
     error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject},
+    export::ExportFormat,
+    object::{
+        Acl, BucketItem, BucketPolicy, FileDetail, FileVersion, MultipartUpload, ObjectHead,
+        ObjectItem, ObjectKey, PrefixVersion, RawObject,
+    },
+    util::{ByteRange, TimeRange},
 };
 
+const TICK_RATE: Duration = Duration::from_millis(100);
+
+#[cfg(test)]
+const RECV_FIND_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[derive(Debug)]
 pub enum AppEventType {
     Key(KeyEvent),
     Resize(usize, usize),
+    Tick,
     Initialize(Client, Option<String>),
     CompleteInitialize(Result<CompleteInitializeResult>),
+    CompleteReloadCredentials(Client),
+    CompleteLoadAccountId(Result<CompleteLoadAccountIdResult>),
+    CompleteLoadBucketRegion(Result<CompleteLoadBucketRegionResult>),
+    CompleteLoadBucketVersioning(Result<CompleteLoadBucketVersioningResult>),
+    CompleteLoadBucketPublic(Result<CompleteLoadBucketPublicResult>),
+    ReloadBuckets,
+    CompleteReloadBuckets(Result<CompleteInitializeResult>),
     LoadObjects,
     CompleteLoadObjects(Result<CompleteLoadObjectsResult>),
     LoadObject,
     CompleteLoadObject(Result<CompleteLoadObjectResult>),
+    PeekObject,
+    CompletePeekObject(Result<CompleteLoadObjectResult>),
     DownloadObject(FileDetail, Option<String>),
+    DownloadAndOpenObject(FileDetail, Option<String>),
     DownloadObjectAs(FileDetail, String, Option<String>),
     CompleteDownloadObject(Result<CompleteDownloadObjectResult>),
+    DownloadObjects(Vec<ObjectItem>),
+    CompleteDownloadObjects(Result<CompleteDownloadObjectsResult>),
     PreviewObject(FileDetail, Option<String>),
     CompletePreviewObject(Result<CompletePreviewObjectResult>),
+    PreviewObjectRange(FileDetail, Option<String>, ByteRange),
+    CompletePreviewObjectRange(Result<CompletePreviewObjectRangeResult>),
+    CompleteTailObject(Result<CompleteTailObjectResult>),
+    CompleteLoadObjectHead(Result<CompleteLoadObjectHeadResult>),
     BucketListMoveDown,
     ObjectListMoveDown,
     ObjectListMoveUp,
     BackToBucketList,
+    BackToBucketRoot,
     OpenPreview(FileDetail, Option<String>),
+    OpenPreviewRange(FileDetail, Option<String>, ByteRange),
     DetailDownloadObject(FileDetail, Option<String>),
+    DetailDownloadAndOpenObject(FileDetail, Option<String>),
     DetailDownloadObjectAs(FileDetail, String, Option<String>),
     PreviewDownloadObject(RawObject, String),
+    PreviewDownloadAndOpenObject(RawObject, String),
     PreviewDownloadObjectAs(FileDetail, String, Option<String>),
+    OpenInExternalApp(FileDetail, Option<String>),
+    CompleteOpenInExternalApp(Result<CompleteDownloadObjectResult>),
+    PreviewOpenInExternalApp(RawObject, String),
     BucketListOpenManagementConsole,
+    BucketListCopyManagementConsoleUrl,
     ObjectListOpenManagementConsole,
+    ObjectListCopyManagementConsoleUrl,
+    BucketListCopyS3Uri,
+    BucketListLoadPolicy,
+    CompleteBucketListLoadPolicy(Result<CompleteLoadBucketPolicyResult>),
+    BucketListLoadProperties,
+    CompleteBucketListLoadProperties(Result<CompleteLoadBucketPropertiesResult>),
+    ObjectListCopyS3Uri,
+    ObjectListCopyKey,
+    ObjectListToggleFlat,
+    ObjectListLoadMore,
+    ObjectListReload,
+    ReloadObjects,
+    CompleteReloadObjects(Result<CompleteLoadObjectsResult>),
+    CompleteLoadMoreObjects(Result<CompleteLoadObjectsResult>),
+    ObjectListCountObjects,
+    CompleteCountObjects(Result<CompleteCountObjectsResult>),
+    ObjectListLoadPrefixVersions(TimeRange),
+    CompleteObjectListLoadPrefixVersions(Result<CompleteLoadPrefixVersionsResult>),
+    ObjectListOpenPrefixVersion(String, String),
+    ExportListing(ExportFormat),
     ObjectDetailOpenManagementConsole(String),
+    ObjectDetailOpenParentManagementConsole,
+    ObjectDetailCopyManagementConsoleUrl(String),
+    ObjectDetailLoadAcl(String, Option<String>),
+    CompleteObjectDetailLoadAcl(Result<CompleteLoadObjectAclResult>),
+    LoadMultipartUploads,
+    CompleteLoadMultipartUploads(Result<CompleteLoadMultipartUploadsResult>),
+    AbortMultipartUpload(String, String),
+    CompleteAbortMultipartUpload(Result<CompleteAbortMultipartUploadResult>),
     CloseCurrentPage,
     OpenHelp,
     CopyToClipboard(String, String),
+    CopyAsDataUri(FileDetail),
+    CompleteCopyAsDataUri(Result<CompleteCopyAsDataUriResult>),
     NotifyInfo(String),
     NotifySuccess(String),
     NotifyWarn(String),
@@ -61,15 +127,196 @@ impl CompleteInitializeResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadAccountIdResult {
+    pub account_id: String,
+}
+
+impl CompleteLoadAccountIdResult {
+    pub fn new(account_id: Result<String>) -> Result<CompleteLoadAccountIdResult> {
+        let account_id = account_id?;
+        Ok(CompleteLoadAccountIdResult { account_id })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadBucketRegionResult {
+    pub name: String,
+    pub region: String,
+}
+
+impl CompleteLoadBucketRegionResult {
+    pub fn new(name: String, region: Result<String>) -> Result<CompleteLoadBucketRegionResult> {
+        let region = region?;
+        Ok(CompleteLoadBucketRegionResult { name, region })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadBucketVersioningResult {
+    pub name: String,
+    pub versioning: String,
+}
+
+impl CompleteLoadBucketVersioningResult {
+    pub fn new(
+        name: String,
+        versioning: Result<String>,
+    ) -> Result<CompleteLoadBucketVersioningResult> {
+        let versioning = versioning?;
+        Ok(CompleteLoadBucketVersioningResult { name, versioning })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadBucketPublicResult {
+    pub name: String,
+    pub public: Option<bool>,
+}
+
+impl CompleteLoadBucketPublicResult {
+    pub fn new(
+        name: String,
+        public: Result<Option<bool>>,
+    ) -> Result<CompleteLoadBucketPublicResult> {
+        let public = public?;
+        Ok(CompleteLoadBucketPublicResult { name, public })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadBucketPropertiesResult {
+    pub name: String,
+    pub region: Option<String>,
+    pub versioning: Option<String>,
+    pub encryption: Option<String>,
+}
+
+impl CompleteLoadBucketPropertiesResult {
+    pub fn new(
+        name: String,
+        region: Result<String>,
+        versioning: Result<String>,
+        encryption: Result<String>,
+    ) -> Result<CompleteLoadBucketPropertiesResult> {
+        // each property is shown as unknown if it fails (e.g. access denied), not to fail the others
+        Ok(CompleteLoadBucketPropertiesResult {
+            name,
+            region: region.ok(),
+            versioning: versioning.ok(),
+            encryption: encryption.ok(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadObjectAclResult {
+    pub key: String,
+    pub acl: Acl,
+}
+
+impl CompleteLoadObjectAclResult {
+    pub fn new(key: String, acl: Result<Acl>) -> Result<CompleteLoadObjectAclResult> {
+        let acl = acl?;
+        Ok(CompleteLoadObjectAclResult { key, acl })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadPrefixVersionsResult {
+    pub prefix: String,
+    pub versions: Vec<PrefixVersion>,
+}
+
+impl CompleteLoadPrefixVersionsResult {
+    pub fn new(
+        prefix: String,
+        versions: Result<Vec<PrefixVersion>>,
+    ) -> Result<CompleteLoadPrefixVersionsResult> {
+        let versions = versions?;
+        Ok(CompleteLoadPrefixVersionsResult { prefix, versions })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadMultipartUploadsResult {
+    pub bucket: String,
+    pub uploads: Vec<MultipartUpload>,
+}
+
+impl CompleteLoadMultipartUploadsResult {
+    pub fn new(
+        bucket: String,
+        uploads: Result<Vec<MultipartUpload>>,
+    ) -> Result<CompleteLoadMultipartUploadsResult> {
+        let uploads = uploads?;
+        Ok(CompleteLoadMultipartUploadsResult { bucket, uploads })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteAbortMultipartUploadResult {
+    pub key: String,
+    pub upload_id: String,
+}
+
+impl CompleteAbortMultipartUploadResult {
+    pub fn new(
+        key: String,
+        upload_id: String,
+        result: Result<()>,
+    ) -> Result<CompleteAbortMultipartUploadResult> {
+        result?;
+        Ok(CompleteAbortMultipartUploadResult { key, upload_id })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadBucketPolicyResult {
+    pub name: String,
+    pub policy: BucketPolicy,
+    pub acl: Acl,
+}
+
+impl CompleteLoadBucketPolicyResult {
+    pub fn new(
+        name: String,
+        policy: Result<BucketPolicy>,
+        acl: Result<Acl>,
+    ) -> Result<CompleteLoadBucketPolicyResult> {
+        let policy = policy?;
+        let acl = acl?;
+        Ok(CompleteLoadBucketPolicyResult { name, policy, acl })
+    }
+}
+
 #[derive(Debug)]
 pub struct CompleteLoadObjectsResult {
     pub items: Vec<ObjectItem>,
+    pub flat: bool,
+    pub next_token: Option<String>,
 }
 
 impl CompleteLoadObjectsResult {
-    pub fn new(items: Result<Vec<ObjectItem>>) -> Result<CompleteLoadObjectsResult> {
+    pub fn new(items: Result<Vec<ObjectItem>>, flat: bool) -> Result<CompleteLoadObjectsResult> {
         let items = items?;
-        Ok(CompleteLoadObjectsResult { items })
+        Ok(CompleteLoadObjectsResult {
+            items,
+            flat,
+            next_token: None,
+        })
+    }
+
+    pub fn new_page(
+        page: Result<(Vec<ObjectItem>, Option<String>)>,
+        flat: bool,
+    ) -> Result<CompleteLoadObjectsResult> {
+        let (items, next_token) = page?;
+        Ok(CompleteLoadObjectsResult {
+            items,
+            flat,
+            next_token,
+        })
     }
 }
 
@@ -96,16 +343,75 @@ impl CompleteLoadObjectResult {
     }
 }
 
+#[derive(Debug)]
+pub enum DownloadedObject {
+    Bytes(RawObject),
+    // already written to this file, to avoid holding large objects in memory
+    File(String),
+}
+
 #[derive(Debug)]
 pub struct CompleteDownloadObjectResult {
-    pub obj: RawObject,
+    pub obj: DownloadedObject,
     pub path: String,
+    // open the saved file with the external app
+    pub open: bool,
+    // shown instead of the success message, e.g. if the checksum does not match
+    pub warning: Option<String>,
 }
 
 impl CompleteDownloadObjectResult {
-    pub fn new(obj: Result<RawObject>, path: String) -> Result<CompleteDownloadObjectResult> {
+    pub fn new(
+        obj: Result<DownloadedObject>,
+        path: String,
+    ) -> Result<CompleteDownloadObjectResult> {
+        let obj = obj?;
+        Ok(CompleteDownloadObjectResult {
+            obj,
+            path,
+            open: false,
+            warning: None,
+        })
+    }
+
+    pub fn new_and_open(
+        obj: Result<DownloadedObject>,
+        path: String,
+    ) -> Result<CompleteDownloadObjectResult> {
         let obj = obj?;
-        Ok(CompleteDownloadObjectResult { obj, path })
+        Ok(CompleteDownloadObjectResult {
+            obj,
+            path,
+            open: true,
+            warning: None,
+        })
+    }
+
+    pub fn with_warning(self, warning: Option<String>) -> CompleteDownloadObjectResult {
+        CompleteDownloadObjectResult { warning, ..self }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteDownloadObjectsResult {
+    // names of the saved objects and the paths they are saved to
+    pub saved: Vec<(String, String)>,
+    pub total: usize,
+    // the key that failed and its error, the objects after it are not downloaded
+    pub failed: Option<(String, AppError)>,
+}
+
+impl CompleteDownloadObjectsResult {
+    pub fn new(
+        saved: Vec<(String, String)>,
+        total: usize,
+        failed: Option<(String, AppError)>,
+    ) -> Result<CompleteDownloadObjectsResult> {
+        Ok(CompleteDownloadObjectsResult {
+            saved,
+            total,
+            failed,
+        })
     }
 }
 
@@ -134,6 +440,97 @@ impl CompletePreviewObjectResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompletePreviewObjectRangeResult {
+    pub obj: RawObject,
+    pub file_detail: FileDetail,
+    pub file_version_id: Option<String>,
+    pub range: ByteRange,
+    pub path: String,
+}
+
+impl CompletePreviewObjectRangeResult {
+    pub fn new(
+        obj: Result<RawObject>,
+        file_detail: FileDetail,
+        file_version_id: Option<String>,
+        range: ByteRange,
+        path: String,
+    ) -> Result<CompletePreviewObjectRangeResult> {
+        let obj = obj?;
+        Ok(CompletePreviewObjectRangeResult {
+            obj,
+            file_detail,
+            file_version_id,
+            range,
+            path,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum TailUpdate {
+    Unchanged,
+    Appended(Vec<u8>),
+    // the object got smaller, so it is assumed to have been replaced
+    Reloaded(RawObject),
+}
+
+#[derive(Debug)]
+pub struct CompleteTailObjectResult {
+    pub name: String,
+    pub update: TailUpdate,
+}
+
+impl CompleteTailObjectResult {
+    pub fn new(name: String, update: Result<TailUpdate>) -> Result<CompleteTailObjectResult> {
+        let update = update?;
+        Ok(CompleteTailObjectResult { name, update })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteCountObjectsResult {
+    pub prefix: String,
+    pub keys: Vec<String>,
+}
+
+impl CompleteCountObjectsResult {
+    pub fn new(prefix: String, keys: Result<Vec<String>>) -> Result<CompleteCountObjectsResult> {
+        let keys = keys?;
+        Ok(CompleteCountObjectsResult { prefix, keys })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadObjectHeadResult {
+    pub name: String,
+    pub head: ObjectHead,
+}
+
+impl CompleteLoadObjectHeadResult {
+    pub fn new(name: String, head: Result<ObjectHead>) -> Result<CompleteLoadObjectHeadResult> {
+        let head = head?;
+        Ok(CompleteLoadObjectHeadResult { name, head })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteCopyAsDataUriResult {
+    pub obj: RawObject,
+    pub file_detail: FileDetail,
+}
+
+impl CompleteCopyAsDataUriResult {
+    pub fn new(
+        obj: Result<RawObject>,
+        file_detail: FileDetail,
+    ) -> Result<CompleteCopyAsDataUriResult> {
+        let obj = obj?;
+        Ok(CompleteCopyAsDataUriResult { obj, file_detail })
+    }
+}
+
 #[derive(Clone)]
 pub struct Sender {
     tx: mpsc::Sender<AppEventType>,
@@ -159,6 +556,23 @@ impl Receiver {
     pub fn recv(&self) -> AppEventType {
         self.rx.recv().unwrap()
     }
+
+    // The input thread keeps sending ticks, so `recv` never fails in tests.
+    // This waits for the matching event for a limited time instead of hanging.
+    #[cfg(test)]
+    pub fn recv_find(&self, f: impl Fn(&AppEventType) -> bool) -> AppEventType {
+        let deadline = std::time::Instant::now() + RECV_FIND_TIMEOUT;
+        loop {
+            let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+            match self.rx.recv_timeout(timeout) {
+                Ok(event) if f(&event) => return event,
+                Ok(_) if timeout.is_zero() => break,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        panic!("expected event is not received within {RECV_FIND_TIMEOUT:?}");
+    }
 }
 
 pub fn new() -> (Sender, Receiver) {
@@ -168,6 +582,18 @@ pub fn new() -> (Sender, Receiver) {
 
     let event_tx = tx.clone();
     thread::spawn(move || loop {
+        match crossterm::event::poll(TICK_RATE) {
+            Ok(true) => {}
+            Ok(false) => {
+                event_tx.send(AppEventType::Tick);
+                continue;
+            }
+            Err(e) => {
+                let e = AppError::new("Failed to poll event", e);
+                event_tx.send(AppEventType::NotifyError(e));
+                continue;
+            }
+        }
         match crossterm::event::read() {
             Ok(e) => match e {
                 crossterm::event::Event::Key(key) => {
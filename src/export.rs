@@ -0,0 +1,272 @@
+use itsuki::zero_indexed_enum;
+use serde_derive::Serialize;
+
+use crate::{
+    error::{AppError, Result},
+    object::{FileDetail, ObjectItem},
+};
+
+#[derive(Default)]
+#[zero_indexed_enum]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ListingRow<'a> {
+    name: &'a str,
+    size: Option<usize>,
+    last_modified: Option<String>,
+    #[serde(rename = "type")]
+    item_type: &'static str,
+}
+
+impl<'a> From<&'a ObjectItem> for ListingRow<'a> {
+    fn from(item: &'a ObjectItem) -> Self {
+        match item {
+            ObjectItem::Dir { name } => ListingRow {
+                name,
+                size: None,
+                last_modified: None,
+                item_type: "dir",
+            },
+            ObjectItem::File {
+                name,
+                size_byte,
+                last_modified,
+            } => ListingRow {
+                name,
+                size: Some(*size_byte),
+                last_modified: Some(last_modified.to_rfc3339()),
+                item_type: "file",
+            },
+        }
+    }
+}
+
+pub fn export_object_items(items: &[ObjectItem], format: ExportFormat) -> Result<String> {
+    let rows: Vec<ListingRow> = items.iter().map(ListingRow::from).collect();
+    match format {
+        ExportFormat::Csv => Ok(to_csv(&rows)),
+        ExportFormat::Json => serde_json::to_string_pretty(&rows)
+            .map_err(|e| AppError::new("Failed to serialize listing", e)),
+    }
+}
+
+// the fields are serialized in the declared order, so the output is stable
+#[derive(Serialize)]
+struct FileDetailJson<'a> {
+    name: &'a str,
+    key: &'a str,
+    bucket: &'a str,
+    size: usize,
+    last_modified: String,
+    e_tag: &'a str,
+    checksum_algorithm: &'a str,
+    checksum: &'a str,
+    version_id: &'a str,
+    content_type: &'a str,
+    content_encoding: &'a str,
+    content_disposition: &'a str,
+    cache_control: &'a str,
+    storage_class: &'a str,
+    server_side_encryption: &'a str,
+    sse_kms_key_id: &'a str,
+    s3_uri: &'a str,
+    arn: &'a str,
+    object_url: &'a str,
+    public: Option<bool>,
+}
+
+impl<'a> From<&'a FileDetail> for FileDetailJson<'a> {
+    fn from(detail: &'a FileDetail) -> Self {
+        FileDetailJson {
+            name: &detail.name,
+            key: &detail.key,
+            bucket: &detail.bucket,
+            size: detail.size_byte,
+            last_modified: detail.last_modified.to_rfc3339(),
+            e_tag: &detail.e_tag,
+            checksum_algorithm: &detail.checksum_algorithm,
+            checksum: &detail.checksum,
+            version_id: &detail.version_id,
+            content_type: &detail.content_type,
+            content_encoding: &detail.content_encoding,
+            content_disposition: &detail.content_disposition,
+            cache_control: &detail.cache_control,
+            storage_class: &detail.storage_class,
+            server_side_encryption: &detail.server_side_encryption,
+            sse_kms_key_id: &detail.sse_kms_key_id,
+            s3_uri: &detail.s3_uri,
+            arn: &detail.arn,
+            object_url: &detail.object_url,
+            public: detail.public,
+        }
+    }
+}
+
+pub fn export_file_detail(detail: &FileDetail) -> Result<String> {
+    serde_json::to_string_pretty(&FileDetailJson::from(detail))
+        .map_err(|e| AppError::new("Failed to serialize object detail", e))
+}
+
+fn to_csv(rows: &[ListingRow]) -> String {
+    let mut lines = vec!["name,size,last_modified,type".to_string()];
+    for row in rows {
+        let size = row.size.map(|s| s.to_string()).unwrap_or_default();
+        let last_modified = row.last_modified.clone().unwrap_or_default();
+        let line = [row.name, &size, &last_modified, row.item_type]
+            .iter()
+            .map(|v| escape_csv_field(v))
+            .collect::<Vec<String>>()
+            .join(",");
+        lines.push(line);
+    }
+    lines.join("\n") + "\n"
+}
+
+fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset, Local};
+
+    use super::*;
+
+    #[test]
+    fn test_export_object_items_csv() {
+        let items = items();
+        let actual = export_object_items(&items, ExportFormat::Csv).unwrap();
+        let expected = format!(
+            "name,size,last_modified,type\ndir1,,,dir\nfile1,1034,{0},file\n\"a,\"\"b\"\"\",0,{0},file\n",
+            datetime().to_rfc3339()
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_export_object_items_json() {
+        let items = items();
+        let actual = export_object_items(&items, ExportFormat::Json).unwrap();
+        let actual: serde_json::Value = serde_json::from_str(&actual).unwrap();
+        let expected = serde_json::json!([
+            { "name": "dir1", "size": null, "last_modified": null, "type": "dir" },
+            { "name": "file1", "size": 1034, "last_modified": datetime().to_rfc3339(), "type": "file" },
+            { "name": "a,\"b\"", "size": 0, "last_modified": datetime().to_rfc3339(), "type": "file" },
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_export_file_detail() {
+        let detail = FileDetail {
+            name: "file1".to_string(),
+            size_byte: 1024 + 10,
+            last_modified: datetime(),
+            e_tag: "etag-1".to_string(),
+            checksum_algorithm: "CRC32C".to_string(),
+            checksum: "4waSgw==".to_string(),
+            version_id: "version-1".to_string(),
+            content_type: "text/plain".to_string(),
+            content_encoding: "gzip".to_string(),
+            content_disposition: "inline".to_string(),
+            cache_control: "no-cache".to_string(),
+            storage_class: "STANDARD".to_string(),
+            server_side_encryption: "aws:kms".to_string(),
+            sse_kms_key_id: "key-1".to_string(),
+            bucket: "bucket-1".to_string(),
+            key: "dir1/file1".to_string(),
+            s3_uri: "s3://bucket-1/dir1/file1".to_string(),
+            arn: "arn:aws:s3:::bucket-1/dir1/file1".to_string(),
+            object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/dir1/file1".to_string(),
+            public: Some(true),
+        };
+        let actual = export_file_detail(&detail).unwrap();
+
+        let expected = serde_json::json!({
+            "name": "file1",
+            "key": "dir1/file1",
+            "bucket": "bucket-1",
+            "size": 1034,
+            "last_modified": datetime().to_rfc3339(),
+            "e_tag": "etag-1",
+            "checksum_algorithm": "CRC32C",
+            "checksum": "4waSgw==",
+            "version_id": "version-1",
+            "content_type": "text/plain",
+            "content_encoding": "gzip",
+            "content_disposition": "inline",
+            "cache_control": "no-cache",
+            "storage_class": "STANDARD",
+            "server_side_encryption": "aws:kms",
+            "sse_kms_key_id": "key-1",
+            "s3_uri": "s3://bucket-1/dir1/file1",
+            "arn": "arn:aws:s3:::bucket-1/dir1/file1",
+            "object_url": "https://bucket-1.s3.ap-northeast-1.amazonaws.com/dir1/file1",
+            "public": true,
+        });
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&actual).unwrap(),
+            expected
+        );
+        // pretty-printed in the declared order
+        assert!(actual.starts_with("{\n  \"name\": \"file1\",\n  \"key\": \"dir1/file1\","));
+
+        // unknown if the public access is not checked
+        let detail = FileDetail {
+            public: None,
+            ..detail
+        };
+        let actual = export_file_detail(&detail).unwrap();
+        let actual = serde_json::from_str::<serde_json::Value>(&actual).unwrap();
+        assert_eq!(actual["public"], serde_json::Value::Null);
+    }
+
+    fn items() -> Vec<ObjectItem> {
+        vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: datetime(),
+            },
+            ObjectItem::File {
+                name: "a,\"b\"".to_string(),
+                size_byte: 0,
+                last_modified: datetime(),
+            },
+        ]
+    }
+
+    fn datetime() -> DateTime<Local> {
+        DateTime::<FixedOffset>::parse_from_rfc3339("2024-01-02T13:01:02+09:00")
+            .unwrap()
+            .with_timezone(&Local)
+    }
+}
@@ -1,12 +1,21 @@
 use arboard::Clipboard;
+use base64::Engine;
 use chrono::Local;
+use once_cell::sync::OnceCell;
 use std::{
+    env,
     fs::{File, OpenOptions},
-    io::{BufWriter, Write},
-    path::Path,
+    io::{BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
 };
 
-use crate::error::{AppError, Result};
+use crate::{
+    config::ClipboardBackend,
+    error::{AppError, Result},
+    util::{add_number_suffix_to_file_name, verify_checksum},
+};
+
+static TEMP_DIR: OnceCell<PathBuf> = OnceCell::new();
 
 pub fn save_binary(path: &str, bytes: &[u8]) -> Result<()> {
     create_dirs(path)?;
@@ -20,6 +29,95 @@ pub fn save_binary(path: &str, bytes: &[u8]) -> Result<()> {
     Ok(())
 }
 
+pub fn create_binary_file(path: &str) -> Result<File> {
+    create_dirs(path)?;
+    File::create(path).map_err(|e| AppError::new("Failed to create file", e))
+}
+
+pub fn move_file(from: &str, to: &str) -> Result<()> {
+    create_dirs(to)?;
+    std::fs::rename(from, to).map_err(|e| AppError::new("Failed to move file", e))
+}
+
+pub fn remove_file(path: &str) -> Result<()> {
+    std::fs::remove_file(path).map_err(|e| AppError::new("Failed to remove file", e))
+}
+
+pub fn verify_file_checksum(path: &str, algorithm: &str, expected: &str) -> Result<Option<bool>> {
+    let f = File::open(path).map_err(|e| AppError::new("Failed to open file", e))?;
+    verify_checksum(algorithm, expected, BufReader::new(f))
+        .map_err(|e| AppError::new("Failed to read file", e))
+}
+
+pub fn partial_file_path(path: &str) -> String {
+    // saved next to the destination so that it can be moved without copying
+    format!("{}.part", path)
+}
+
+pub fn exists_file(path: &str) -> bool {
+    Path::new(path).exists()
+}
+
+pub fn available_file_path(path: &str) -> String {
+    (1..)
+        .map(|n| add_number_suffix_to_file_name(path, n))
+        .find(|p| !exists_file(p))
+        .unwrap()
+}
+
+pub fn expand_home_dir(path: &str) -> String {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(std::path::is_separator) => rest,
+        _ => return path.to_string(),
+    };
+    match dirs::home_dir() {
+        Some(home) => format!("{}{}", home.to_string_lossy(), rest),
+        None => path.to_string(),
+    }
+}
+
+// the name may be relative to the download directory, or an absolute path (or under `~`)
+pub fn download_file_path_in(dir: &str, name: &str) -> String {
+    let path = PathBuf::from(dir).join(expand_home_dir(name));
+    String::from(path.to_string_lossy())
+}
+
+pub fn path_completion_candidates(input: &str, base_dir: &str) -> Vec<String> {
+    let (dir_part, name_part) = match input.rfind(std::path::is_separator) {
+        Some(i) => input.split_at(i + 1),
+        None => ("", input),
+    };
+    let dir = PathBuf::from(base_dir).join(expand_home_dir(dir_part));
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            if !name.starts_with(name_part) {
+                return None;
+            }
+            if name.starts_with('.') && !name_part.starts_with('.') {
+                return None;
+            }
+            if entry.path().is_dir() {
+                Some(format!("{}/", name))
+            } else {
+                Some(name)
+            }
+        })
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| format!("{}{}", dir_part, name))
+        .collect()
+}
+
 pub fn save_error_log(path: &str, e: &AppError) -> Result<()> {
     create_dirs(path)?;
 
@@ -28,12 +126,14 @@ pub fn save_error_log(path: &str, e: &AppError) -> Result<()> {
 
     let now = Local::now();
 
+    let msg = e.full_msg();
+
     match &e.cause {
         Some(cause) => {
-            writeln!(f, "{} {}: {:?}", now, e.msg, cause)
+            writeln!(f, "{} {}: {:?}", now, msg, cause)
         }
         None => {
-            writeln!(f, "{} {}", now, e.msg)
+            writeln!(f, "{} {}", now, msg)
         }
     }
     .map_err(|e| AppError::new("Failed to write file", e))
@@ -52,8 +152,161 @@ fn create_dirs(path: &str) -> Result<()> {
     }
 }
 
-pub fn copy_to_clipboard(value: String) -> Result<()> {
+// the directory has a random name and is only accessible by the user, so other users cannot replace or read the files.
+// it is created once per process, and kept after exit as the files may still be opened in the external apps.
+fn temp_dir() -> Result<&'static PathBuf> {
+    TEMP_DIR.get_or_try_init(|| {
+        tempfile::Builder::new()
+            .prefix("stu-")
+            .tempdir()
+            .map(|dir| dir.into_path())
+            .and_then(|path| restrict_to_user(&path).map(|_| path))
+            .map_err(|e| AppError::new("Failed to create temporary directory", e))
+    })
+}
+
+#[cfg(unix)]
+fn restrict_to_user(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))
+}
+
+// the temporary directory of the user is not shared on Windows
+#[cfg(not(unix))]
+fn restrict_to_user(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+pub fn temp_file_path(name: &str) -> Result<String> {
+    // the same path is reused for the same object name in a process, so files do not pile up
+    let path = temp_dir()?.join(name);
+    Ok(String::from(path.to_string_lossy()))
+}
+
+pub fn open_file(path: &str, command: Option<&str>) -> Result<()> {
+    let result = match command {
+        Some(command) => open::with_detached(path, command),
+        None => open::that_detached(path),
+    };
+    result.map_err(|e| AppError::new(format!("Failed to open file: {}", path), e))
+}
+
+pub fn copy_to_clipboard(value: String, backend: ClipboardBackend) -> Result<()> {
+    match resolve_clipboard_backend(backend, env::var_os("SSH_CONNECTION").is_some()) {
+        ClipboardBackend::Osc52 => copy_to_clipboard_osc52(&value),
+        _ => copy_to_clipboard_native(value),
+    }
+}
+
+fn resolve_clipboard_backend(backend: ClipboardBackend, over_ssh: bool) -> ClipboardBackend {
+    match backend {
+        // the native clipboard is the one of the remote host when connected via SSH
+        ClipboardBackend::Auto if over_ssh => ClipboardBackend::Osc52,
+        ClipboardBackend::Auto => ClipboardBackend::Native,
+        backend => backend,
+    }
+}
+
+fn copy_to_clipboard_native(value: String) -> Result<()> {
     Clipboard::new()
         .and_then(|mut c| c.set_text(value))
+        .map_err(|e| {
+            AppError::new(
+                "Failed to copy to clipboard (if the terminal supports OSC 52, try `clipboard.backend = \"osc52\"`)",
+                e,
+            )
+        })
+}
+
+fn copy_to_clipboard_osc52(value: &str) -> Result<()> {
+    // the terminal sets its clipboard, so this works even on a remote host
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(osc52_sequence(value).as_bytes())
+        .and_then(|_| stdout.flush())
         .map_err(|e| AppError::new("Failed to copy to clipboard", e))
 }
+
+fn osc52_sequence(value: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(value);
+    format!("\x1b]52;c;{}\x07", encoded)
+}
+
+pub fn data_uri(content_type: &str, bytes: &[u8]) -> String {
+    let content_type = if content_type.is_empty() {
+        "application/octet-stream"
+    } else {
+        content_type
+    };
+    let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+    format!("data:{};base64,{}", content_type, encoded)
+}
+
+pub fn read_from_clipboard() -> Result<String> {
+    Clipboard::new()
+        .and_then(|mut c| c.get_text())
+        .map_err(|e| AppError::new("Failed to read from clipboard", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("/tmp/download", "a.txt", "/tmp/download/a.txt")]
+    #[case("/tmp/download", "dir/a.txt", "/tmp/download/dir/a.txt")]
+    #[case("/tmp/download", "/var/a.txt", "/var/a.txt")]
+    #[trace]
+    fn test_download_file_path_in(#[case] dir: &str, #[case] name: &str, #[case] expected: &str) {
+        assert_eq!(download_file_path_in(dir, name), expected);
+    }
+
+    #[rstest]
+    #[case(ClipboardBackend::Auto, false, ClipboardBackend::Native)]
+    #[case(ClipboardBackend::Auto, true, ClipboardBackend::Osc52)]
+    #[case(ClipboardBackend::Native, true, ClipboardBackend::Native)]
+    #[case(ClipboardBackend::Osc52, false, ClipboardBackend::Osc52)]
+    fn test_resolve_clipboard_backend(
+        #[case] backend: ClipboardBackend,
+        #[case] over_ssh: bool,
+        #[case] expected: ClipboardBackend,
+    ) {
+        assert_eq!(resolve_clipboard_backend(backend, over_ssh), expected);
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        let actual = osc52_sequence("s3://bucket-1/file.txt");
+        assert_eq!(actual, "\x1b]52;c;czM6Ly9idWNrZXQtMS9maWxlLnR4dA==\x07");
+    }
+
+    #[rstest]
+    #[case("text/plain", b"hello", "data:text/plain;base64,aGVsbG8=")]
+    #[case("image/png", b"", "data:image/png;base64,")]
+    #[case("", b"hello", "data:application/octet-stream;base64,aGVsbG8=")]
+    fn test_data_uri(#[case] content_type: &str, #[case] bytes: &[u8], #[case] expected: &str) {
+        assert_eq!(data_uri(content_type, bytes), expected);
+    }
+
+    #[test]
+    fn test_temp_file_path() {
+        let path1 = temp_file_path("file1.txt").unwrap();
+        let path2 = temp_file_path("file2.txt").unwrap();
+        let dir = Path::new(&path1).parent().unwrap();
+        assert_eq!(Path::new(&path2).parent(), Some(dir));
+        assert!(dir
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("stu-"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(dir).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+    }
+}
@@ -4,6 +4,7 @@ mod config;
 mod constant;
 mod error;
 mod event;
+mod export;
 mod file;
 mod macros;
 mod object;
@@ -13,7 +14,7 @@ mod ui;
 mod util;
 mod widget;
 
-use clap::{arg, Parser};
+use clap::Parser;
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -25,6 +26,7 @@ use ratatui::{
     Terminal,
 };
 use std::{
+    env,
     io::{stdout, Stdout},
     panic,
     sync::Mutex,
@@ -56,6 +58,22 @@ struct Args {
     #[arg(short, long, value_name = "NAME")]
     bucket: Option<String>,
 
+    /// Disable all actions that modify objects
+    #[arg(long)]
+    read_only: bool,
+
+    /// Send requests to requester pays buckets
+    #[arg(long)]
+    requester_pays: bool,
+
+    /// Render without colors (same as setting NO_COLOR)
+    #[arg(long)]
+    no_color: bool,
+
+    /// Disable the keys to open the management console
+    #[arg(long)]
+    no_management_console: bool,
+
     /// Output debug logs
     #[arg(long)]
     debug: bool,
@@ -64,7 +82,27 @@ struct Args {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    let config = Config::load()?;
+    let mut config = Config::load()?;
+    if args.read_only {
+        config.read_only = true;
+    }
+    if args.requester_pays {
+        config.requester_pays = true;
+    }
+    // https://no-color.org/
+    if args.no_color || env::var("NO_COLOR").is_ok_and(|v| !v.is_empty()) {
+        config.no_color = true;
+    }
+    if let Some(prefix) = env::var("STU_DEFAULT_PREFIX")
+        .ok()
+        .filter(|v| !v.is_empty())
+    {
+        config.single_bucket.default_prefix = Some(prefix);
+    }
+    // the management console does not exist for S3-compatible endpoints
+    if args.no_management_console || args.endpoint_url.is_some() {
+        config.no_management_console = true;
+    }
 
     initialize_debug_log(&args, &config)?;
     initialize_panic_handler();
@@ -94,15 +132,28 @@ async fn run<B: Backend>(
 ) -> anyhow::Result<()> {
     let (tx, rx) = event::new();
     let (width, height) = get_frame_size(terminal);
+    let timeout_secs = config.request.timeout_secs;
+    let requester_pays = config.requester_pays;
+    let assume_role = config.assume_role.clone();
     let mut app = App::new(config, tx.clone(), width, height);
 
     spawn(async move {
-        let client = Client::new(args.region, args.endpoint_url, args.profile).await;
+        let client = Client::new(
+            args.region,
+            args.endpoint_url,
+            args.profile,
+            timeout_secs,
+            requester_pays,
+            assume_role,
+        )
+        .await;
         tx.send(AppEventType::Initialize(client, args.bucket));
     });
 
     run::run(&mut app, terminal, rx).await?;
 
+    app.save_session();
+
     Ok(())
 }
 
@@ -5,9 +5,17 @@ use std::{
 
 use chrono::{DateTime, Local};
 
+use crate::widget::ScrollListState;
+
 #[derive(Clone, Debug)]
 pub struct BucketItem {
     pub name: String,
+    pub creation_date: Option<DateTime<Local>>,
+    pub region: Option<String>,
+    pub versioning: Option<String>,
+    pub encryption: Option<String>,
+    // None if not checked yet, or the check is not permitted
+    pub public: Option<bool>,
 }
 
 #[derive(Clone, Debug)]
@@ -31,18 +39,30 @@ impl ObjectItem {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct FileDetail {
     pub name: String,
     pub size_byte: usize,
     pub last_modified: DateTime<Local>,
     pub e_tag: String,
+    pub checksum_algorithm: String,
+    pub checksum: String,
+    // empty if the bucket is not versioned
+    pub version_id: String,
     pub content_type: String,
+    pub content_encoding: String,
+    pub content_disposition: String,
+    pub cache_control: String,
     pub storage_class: String,
+    pub server_side_encryption: String,
+    pub sse_kms_key_id: String,
+    pub bucket: String,
     pub key: String,
     pub s3_uri: String,
     pub arn: String,
     pub object_url: String,
+    // None if not checked, or the check is not permitted
+    pub public: Option<bool>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,14 +71,74 @@ pub struct FileVersion {
     pub size_byte: usize,
     pub last_modified: DateTime<Local>,
     pub is_latest: bool,
+    pub is_delete_marker: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BucketPolicy {
+    // pretty-printed policy JSON
+    Document(String),
+    NotSet,
+    AccessDenied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Acl {
+    Grants {
+        owner: String,
+        grants: Vec<AclGrant>,
+    },
+    AccessDenied,
+}
+
+// the predefined groups for anyone, and for anyone with an AWS account
+const PUBLIC_GRANTEES: [&str; 2] = ["AllUsers (group)", "AuthenticatedUsers (group)"];
+
+impl Acl {
+    pub fn is_public(&self) -> Option<bool> {
+        match self {
+            Acl::Grants { grants, .. } => Some(
+                grants
+                    .iter()
+                    .any(|g| PUBLIC_GRANTEES.contains(&g.grantee.as_str())),
+            ),
+            Acl::AccessDenied => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AclGrant {
+    pub grantee: String,
+    pub permission: String,
+}
+
+// a version of any object under a prefix, unlike FileVersion which is of a single object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixVersion {
+    pub key: String,
+    pub version_id: String,
+    pub size_byte: usize,
+    pub last_modified: DateTime<Local>,
+    pub is_delete_marker: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: Option<DateTime<Local>>,
 }
 
 #[derive(Debug, Default)]
 pub struct AppObjects {
     bucket_items: Vec<BucketItem>,
     object_items_map: HashMap<ObjectKey, Vec<ObjectItem>>,
+    next_token_map: HashMap<ObjectKey, String>,
     detail_map: HashMap<ObjectKey, FileDetail>,
     versions_map: HashMap<ObjectKey, Vec<FileVersion>>,
+    list_state_map: HashMap<ObjectKey, ScrollListState>,
+    filter_map: HashMap<String, String>,
 }
 
 impl AppObjects {
@@ -74,10 +154,46 @@ impl AppObjects {
         self.bucket_items = items;
     }
 
+    pub fn set_bucket_region(&mut self, name: &str, region: String) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.region = Some(region);
+        }
+    }
+
+    pub fn set_bucket_versioning(&mut self, name: &str, versioning: String) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.versioning = Some(versioning);
+        }
+    }
+
+    pub fn set_bucket_public(&mut self, name: &str, public: Option<bool>) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.public = public;
+        }
+    }
+
+    pub fn set_bucket_encryption(&mut self, name: &str, encryption: String) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.encryption = Some(encryption);
+        }
+    }
+
     pub fn set_object_items(&mut self, key: ObjectKey, items: Vec<ObjectItem>) {
         self.object_items_map.insert(key, items);
     }
 
+    // only the lists loaded partially have the token to load the rest
+    pub fn get_next_token(&self, key: &ObjectKey) -> Option<&str> {
+        self.next_token_map.get(key).map(String::as_str)
+    }
+
+    pub fn set_next_token(&mut self, key: ObjectKey, token: Option<String>) {
+        match token {
+            Some(token) => self.next_token_map.insert(key, token),
+            None => self.next_token_map.remove(&key),
+        };
+    }
+
     pub fn get_object_detail(&self, key: &ObjectKey) -> Option<&FileDetail> {
         self.detail_map.get(key)
     }
@@ -95,6 +211,32 @@ impl AppObjects {
         self.detail_map.insert(key.to_owned(), detail);
         self.versions_map.insert(key.to_owned(), versions);
     }
+
+    // the object is loaded again the next time it is opened
+    pub fn invalidate_object_detail(&mut self, key: &ObjectKey) {
+        self.detail_map.remove(key);
+        self.versions_map.remove(key);
+    }
+
+    pub fn get_list_state(&self, key: &ObjectKey) -> Option<ScrollListState> {
+        self.list_state_map.get(key).copied()
+    }
+
+    pub fn set_list_state(&mut self, key: ObjectKey, state: ScrollListState) {
+        self.list_state_map.insert(key, state);
+    }
+
+    pub fn get_filter(&self, bucket: &str) -> Option<&str> {
+        self.filter_map.get(bucket).map(String::as_str)
+    }
+
+    pub fn set_filter(&mut self, bucket: String, filter: &str) {
+        if filter.is_empty() {
+            self.filter_map.remove(&bucket);
+        } else {
+            self.filter_map.insert(bucket, filter.to_string());
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -103,6 +245,12 @@ pub struct ObjectKey {
     pub object_path: Vec<String>,
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct ObjectHead {
+    pub content_type: String,
+    pub obj: RawObject,
+}
+
 #[derive(Default, Clone)]
 pub struct RawObject {
     pub bytes: Vec<u8>,
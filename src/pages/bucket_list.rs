@@ -1,24 +1,45 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::Rect,
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::ListItem,
     Frame,
 };
 
 use crate::{
+    config::ListConfig,
     event::{AppEventType, Sender},
     key_code, key_code_char,
-    object::BucketItem,
-    pages::util::{build_helps, build_short_helps},
-    util::split_str,
-    widget::{InputDialog, InputDialogState, ScrollList, ScrollListState},
+    object::{Acl, BucketItem, BucketPolicy, MultipartUpload},
+    pages::util::{
+        build_helps, build_list_number, build_short_helps, filter_management_console_helps,
+        push_number_prefix,
+    },
+    ui::common::{
+        datetime_width, format_datetime, highlight_style, is_management_console_enabled,
+        toggle_relative_datetime,
+    },
+    util::{digits, split_str, truncate_with_ellipsis},
+    widget::{
+        BucketPolicyDialog, BucketPolicyDialogState, BucketPropertiesDialog,
+        BucketPropertiesDialogState, InputDialog, InputDialogState, MultipartUploadsDialog,
+        MultipartUploadsDialogState, ScrollList, ScrollListState,
+    },
 };
 
 const SELECTED_COLOR: Color = Color::Cyan;
 const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
-const HIGHLIGHTED_ITEM_TEXT_COLOR: Color = Color::Red;
+const PUBLIC_COLOR: Color = Color::Red;
+
+const ITEM_MIN_NAME_WIDTH: usize = 10;
+const ITEM_REGION_WIDTH: usize = 14;
+const ITEM_VERSIONING_WIDTH: usize = 9;
+const ITEM_COLUMN_GAP: usize = 2;
+
+const TYPE_AHEAD_RESET_TIMEOUT: Duration = Duration::from_millis(1000);
 
 #[derive(Debug)]
 pub struct BucketListPage {
@@ -29,6 +50,8 @@ pub struct BucketListPage {
 
     list_state: ScrollListState,
     filter_input_state: InputDialogState,
+    number_prefix: Option<usize>,
+    list_config: ListConfig,
     tx: Sender,
 }
 
@@ -36,23 +59,52 @@ pub struct BucketListPage {
 enum ViewState {
     Default,
     FilterDialog,
+    TypeAhead(TypeAheadState),
+    PolicyDialog(Box<BucketPolicyDialogState>),
+    PropertiesDialog(Box<BucketPropertiesDialogState>),
+    MultipartUploadsDialog(Box<MultipartUploadsDialogState>),
+}
+
+#[derive(Debug, Default)]
+struct TypeAheadState {
+    query: String,
+    last_input: Option<Instant>,
+}
+
+impl TypeAheadState {
+    fn push(&mut self, c: char, now: Instant) -> &str {
+        // start over if the user paused typing
+        let expired = self
+            .last_input
+            .is_some_and(|t| now.duration_since(t) > TYPE_AHEAD_RESET_TIMEOUT);
+        if expired {
+            self.query.clear();
+        }
+        self.query.push(c);
+        self.last_input = Some(now);
+        &self.query
+    }
 }
 
 impl BucketListPage {
-    pub fn new(bucket_items: Vec<BucketItem>, tx: Sender) -> Self {
+    pub fn new(bucket_items: Vec<BucketItem>, list_config: ListConfig, tx: Sender) -> Self {
         let items_len = bucket_items.len();
         let filtered_indices = (0..items_len).collect();
         Self {
             bucket_items,
             filtered_indices,
             view_state: ViewState::Default,
-            list_state: ScrollListState::new(items_len),
+            list_state: ScrollListState::new(items_len).wrap(list_config.wrap),
             filter_input_state: InputDialogState::default(),
+            number_prefix: None,
+            list_config,
             tx,
         }
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) {
+        // the number prefix is only for the next key
+        let number_prefix = self.number_prefix.take();
         match self.view_state {
             ViewState::Default => match key {
                 key_code!(KeyCode::Esc) => {
@@ -75,8 +127,16 @@ impl BucketListPage {
                 key_code_char!('g') if self.non_empty() => {
                     self.select_first();
                 }
-                key_code_char!('G') if self.non_empty() => {
-                    self.select_last();
+                key_code_char!('G') if self.non_empty() => match number_prefix {
+                    Some(n) => self.select_index(n.saturating_sub(1)),
+                    None => self.select_last(),
+                },
+                KeyEvent {
+                    code: KeyCode::Char(c @ '0'..='9'),
+                    modifiers: KeyModifiers::NONE,
+                    ..
+                } if self.non_empty() => {
+                    self.number_prefix = Some(push_number_prefix(number_prefix, c));
                 }
                 key_code_char!('f') if self.non_empty() => {
                     self.select_next_page();
@@ -84,17 +144,147 @@ impl BucketListPage {
                 key_code_char!('b') if self.non_empty() => {
                     self.select_prev_page();
                 }
-                key_code_char!('x') if self.non_empty() => {
+                key_code_char!('d', Ctrl) if self.non_empty() => {
+                    self.select_next_half_page();
+                }
+                key_code_char!('u', Ctrl) if self.non_empty() => {
+                    self.select_prev_half_page();
+                }
+                key_code_char!('x') if self.non_empty() && is_management_console_enabled() => {
                     self.tx.send(AppEventType::BucketListOpenManagementConsole);
                 }
+                key_code_char!('X') if self.non_empty() && is_management_console_enabled() => {
+                    self.tx
+                        .send(AppEventType::BucketListCopyManagementConsoleUrl);
+                }
+                key_code_char!('y') if self.non_empty() => {
+                    self.tx.send(AppEventType::BucketListCopyS3Uri);
+                }
+                key_code_char!('T') => {
+                    toggle_relative_datetime();
+                }
                 key_code_char!('/') => {
                     self.open_filter_dialog();
                 }
+                key_code_char!('s') if self.non_empty() => {
+                    self.start_type_ahead();
+                }
+                key_code_char!('i') if self.non_empty() => {
+                    self.open_policy_dialog();
+                }
+                key_code_char!('p') if self.non_empty() => {
+                    self.open_properties_dialog();
+                }
+                key_code_char!('M') if self.non_empty() => {
+                    self.open_multipart_uploads_dialog();
+                }
+                key_code_char!('r', Ctrl) => {
+                    self.tx.send(AppEventType::ReloadBuckets);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
                 _ => {}
             },
+            ViewState::PropertiesDialog(_) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('p') => {
+                    self.close_properties_dialog();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::MultipartUploadsDialog(ref mut state) if state.is_confirming() => {
+                match key {
+                    key_code_char!('y') | key_code!(KeyCode::Enter) => {
+                        state.close_confirm();
+                        if let Some(upload) = state.selected_upload() {
+                            let (key, upload_id) = (upload.key.clone(), upload.upload_id.clone());
+                            self.tx
+                                .send(AppEventType::AbortMultipartUpload(key, upload_id));
+                        }
+                    }
+                    key_code_char!('n') | key_code!(KeyCode::Esc) => {
+                        state.close_confirm();
+                    }
+                    _ => {}
+                }
+            }
+            ViewState::MultipartUploadsDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('M') => {
+                    self.close_multipart_uploads_dialog();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('g') => {
+                    state.select_first();
+                }
+                key_code_char!('G') => {
+                    state.select_last();
+                }
+                key_code_char!('d') => {
+                    state.open_confirm();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::PolicyDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('i') => {
+                    self.close_policy_dialog();
+                }
+                key_code_char!('j') => {
+                    state.scroll_forward();
+                }
+                key_code_char!('k') => {
+                    state.scroll_backward();
+                }
+                key_code_char!('f') => {
+                    state.scroll_page_forward();
+                }
+                key_code_char!('b') => {
+                    state.scroll_page_backward();
+                }
+                key_code_char!('g') => {
+                    state.scroll_to_top();
+                }
+                key_code_char!('G') => {
+                    state.scroll_to_end();
+                }
+                key_code_char!('l') => {
+                    state.scroll_right();
+                }
+                key_code_char!('h') => {
+                    state.scroll_left();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::TypeAhead(_) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.end_type_ahead();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.end_type_ahead();
+                    self.tx.send(AppEventType::BucketListMoveDown);
+                }
+                KeyEvent {
+                    code: KeyCode::Char(c),
+                    modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+                    ..
+                } => {
+                    self.type_ahead(c, Instant::now());
+                }
+                _ => {}
+            },
             ViewState::FilterDialog => match key {
                 key_code!(KeyCode::Esc) => {
                     self.close_filter_dialog();
@@ -124,9 +314,16 @@ impl BucketListPage {
             offset,
             selected,
             area,
+            &self.list_config,
         );
 
-        let list = ScrollList::new(list_items);
+        let mut list = ScrollList::new(list_items);
+        if self.bucket_items.is_empty() {
+            list = list.empty_message("No buckets");
+        }
+        if let ViewState::TypeAhead(state) = &self.view_state {
+            list = list.title(format!("Jump: {}", state.query));
+        }
         f.render_stateful_widget(list, area, &mut self.list_state);
 
         if let ViewState::FilterDialog = self.view_state {
@@ -136,6 +333,21 @@ impl BucketListPage {
             let (cursor_x, cursor_y) = self.filter_input_state.cursor();
             f.set_cursor(cursor_x, cursor_y);
         }
+
+        if let ViewState::PolicyDialog(state) = &mut self.view_state {
+            let policy_dialog = BucketPolicyDialog::default();
+            f.render_stateful_widget(policy_dialog, area, state);
+        }
+
+        if let ViewState::PropertiesDialog(state) = &mut self.view_state {
+            let properties_dialog = BucketPropertiesDialog::default();
+            f.render_stateful_widget(properties_dialog, area, state);
+        }
+
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            let multipart_uploads_dialog = MultipartUploadsDialog::default();
+            f.render_stateful_widget(multipart_uploads_dialog, area, state);
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -146,11 +358,25 @@ impl BucketListPage {
                         (&["Esc", "Ctrl-c"], "Quit app"),
                         (&["j/k"], "Select item"),
                         (&["g/G"], "Go to top/bottom"),
+                        (&["{n}G"], "Go to n-th item"),
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
+                        (&["Ctrl-d/Ctrl-u"], "Scroll half page forward/backward"),
                         (&["Enter"], "Open bucket"),
                         (&["/"], "Filter bucket list"),
+                        (&["s"], "Jump to bucket by typing its name"),
+                        (&["i"], "Show bucket policy and ACL"),
+                        (&["p"], "Show bucket properties"),
+                        (&["M"], "Show incomplete multipart uploads"),
+                        (&["Ctrl-r"], "Reload list"),
+                        (&["y"], "Copy S3 URI to clipboard"),
+                        (&["T"], "Toggle relative/absolute time"),
                         (&["x"], "Open management console in browser"),
+                        (&["X"], "Copy management console URL to clipboard"),
+                        (&["Ctrl-n"], "Open new tab"),
+                        (&["Ctrl-t"], "Switch to next tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["Ctrl-y"], "Copy notification to clipboard"),
                     ]
                 } else {
                     &[
@@ -158,11 +384,25 @@ impl BucketListPage {
                         (&["Esc"], "Clear filter"),
                         (&["j/k"], "Select item"),
                         (&["g/G"], "Go to top/bottom"),
+                        (&["{n}G"], "Go to n-th item"),
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
+                        (&["Ctrl-d/Ctrl-u"], "Scroll half page forward/backward"),
                         (&["Enter"], "Open bucket"),
                         (&["/"], "Filter bucket list"),
+                        (&["s"], "Jump to bucket by typing its name"),
+                        (&["i"], "Show bucket policy and ACL"),
+                        (&["p"], "Show bucket properties"),
+                        (&["M"], "Show incomplete multipart uploads"),
+                        (&["Ctrl-r"], "Reload list"),
+                        (&["y"], "Copy S3 URI to clipboard"),
+                        (&["T"], "Toggle relative/absolute time"),
                         (&["x"], "Open management console in browser"),
+                        (&["X"], "Copy management console URL to clipboard"),
+                        (&["Ctrl-n"], "Open new tab"),
+                        (&["Ctrl-t"], "Switch to next tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["Ctrl-y"], "Copy notification to clipboard"),
                     ]
                 }
             }
@@ -171,8 +411,35 @@ impl BucketListPage {
                 (&["Esc"], "Close filter dialog"),
                 (&["Enter"], "Apply filter"),
             ],
+            ViewState::TypeAhead(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Stop jumping"),
+                (&["Enter"], "Open bucket"),
+            ],
+            ViewState::PolicyDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "i"], "Close policy dialog"),
+                (&["j/k"], "Scroll forward/backward"),
+                (&["f/b"], "Scroll page forward/backward"),
+                (&["g/G"], "Scroll to top/bottom"),
+                (&["h/l"], "Scroll left/right"),
+            ],
+            ViewState::PropertiesDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "p"], "Close properties dialog"),
+            ],
+            ViewState::MultipartUploadsDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "M"], "Close multipart uploads dialog"),
+                (&["j/k"], "Select upload"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["d"], "Abort selected upload"),
+            ],
         };
-        build_helps(helps)
+        build_helps(&filter_management_console_helps(
+            helps,
+            is_management_console_enabled(),
+        ))
     }
 
     pub fn short_helps(&self) -> Vec<(String, usize)> {
@@ -203,6 +470,19 @@ impl BucketListPage {
                 (&["Enter"], "Filter", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::TypeAhead(_) => &[(&["Esc"], "Stop", 2), (&["Enter"], "Open", 1)],
+            ViewState::PolicyDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Scroll", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::PropertiesDialog(_) => &[(&["Esc"], "Close", 1), (&["?"], "Help", 0)],
+            ViewState::MultipartUploadsDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["d"], "Abort", 1),
+                (&["?"], "Help", 0),
+            ],
         };
         build_short_helps(helps)
     }
@@ -225,12 +505,141 @@ impl BucketListPage {
         self.list_state.select_last();
     }
 
+    fn select_index(&mut self, index: usize) {
+        self.list_state.select_index(index);
+    }
+
     fn select_next_page(&mut self) {
-        self.list_state.select_next_page();
+        match self.list_config.page_size {
+            Some(n) => self.list_state.select_next_by(n.max(1)),
+            None => self.list_state.select_next_page(),
+        }
     }
 
     fn select_prev_page(&mut self) {
-        self.list_state.select_prev_page();
+        match self.list_config.page_size {
+            Some(n) => self.list_state.select_prev_by(n.max(1)),
+            None => self.list_state.select_prev_page(),
+        }
+    }
+
+    fn select_next_half_page(&mut self) {
+        self.list_state.select_next_half_page();
+    }
+
+    fn select_prev_half_page(&mut self) {
+        self.list_state.select_prev_half_page();
+    }
+
+    fn start_type_ahead(&mut self) {
+        self.view_state = ViewState::TypeAhead(TypeAheadState::default());
+    }
+
+    fn end_type_ahead(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn type_ahead(&mut self, c: char, now: Instant) {
+        let ViewState::TypeAhead(state) = &mut self.view_state else {
+            return;
+        };
+        let query = state.push(c, now).to_lowercase();
+
+        // prefer a bucket starting with the query, and only move when something matches
+        let names: Vec<String> = self
+            .filtered_indices
+            .iter()
+            .map(|&i| self.bucket_items[i].name.to_lowercase())
+            .collect();
+        let found = names
+            .iter()
+            .position(|name| name.starts_with(&query))
+            .or_else(|| names.iter().position(|name| name.contains(&query)));
+        if let Some(i) = found {
+            self.list_state.select(i);
+        }
+    }
+
+    fn open_policy_dialog(&mut self) {
+        let name = self.current_selected_item().name.clone();
+        let state = BucketPolicyDialogState::new(name);
+        self.view_state = ViewState::PolicyDialog(Box::new(state));
+        self.tx.send(AppEventType::BucketListLoadPolicy);
+    }
+
+    pub fn close_policy_dialog(&mut self) {
+        if let ViewState::PolicyDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_policy(&mut self, name: &str, policy: &BucketPolicy, acl: &Acl) {
+        if let ViewState::PolicyDialog(state) = &mut self.view_state {
+            // ignore the result for the dialog already closed
+            if state.name() == name {
+                state.set_policy(policy, acl);
+            }
+        }
+    }
+
+    fn open_properties_dialog(&mut self) {
+        let name = &self.current_selected_item().name;
+        let state = BucketPropertiesDialogState::new(name);
+        self.view_state = ViewState::PropertiesDialog(Box::new(state));
+        self.tx.send(AppEventType::BucketListLoadProperties);
+    }
+
+    pub fn close_properties_dialog(&mut self) {
+        if let ViewState::PropertiesDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    fn open_multipart_uploads_dialog(&mut self) {
+        let state = MultipartUploadsDialogState::default();
+        self.view_state = ViewState::MultipartUploadsDialog(Box::new(state));
+        self.tx.send(AppEventType::LoadMultipartUploads);
+    }
+
+    pub fn close_multipart_uploads_dialog(&mut self) {
+        if let ViewState::MultipartUploadsDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_multipart_uploads(&mut self, bucket: &str, uploads: Vec<MultipartUpload>) {
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            state.set_uploads(bucket, uploads);
+        }
+    }
+
+    pub fn remove_multipart_upload(&mut self, upload_id: &str) {
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            state.remove_upload(upload_id);
+        }
+    }
+
+    // the properties failed to load are None, and they are loaded again next time
+    pub fn set_bucket_properties(
+        &mut self,
+        name: &str,
+        region: Option<String>,
+        versioning: Option<String>,
+        encryption: Option<String>,
+    ) {
+        let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) else {
+            return;
+        };
+        item.region = region.or(item.region.take());
+        item.versioning = versioning.or(item.versioning.take());
+        item.encryption = encryption.or(item.encryption.take());
+
+        if let ViewState::PropertiesDialog(state) = &mut self.view_state {
+            // ignore the result for the dialog already closed
+            if state.name() == name {
+                state.set_properties(item);
+            }
+        }
     }
 
     fn open_filter_dialog(&mut self) {
@@ -264,7 +673,8 @@ impl BucketListPage {
             .map(|(idx, _)| idx)
             .collect();
         // reset list state
-        self.list_state = ScrollListState::new(self.filtered_indices.len());
+        self.list_state =
+            ScrollListState::new(self.filtered_indices.len()).wrap(self.list_config.wrap);
     }
 
     pub fn current_selected_item(&self) -> &BucketItem {
@@ -287,6 +697,52 @@ impl BucketListPage {
         })
     }
 
+    // the number of buckets shown, after filtering
+    pub fn item_count(&self) -> usize {
+        self.filtered_indices.len()
+    }
+
+    pub fn set_bucket_region(&mut self, name: &str, region: String) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.region = Some(region);
+        }
+    }
+
+    pub fn set_bucket_versioning(&mut self, name: &str, versioning: String) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.versioning = Some(versioning);
+        }
+    }
+
+    pub fn set_bucket_public(&mut self, name: &str, public: Option<bool>) {
+        if let Some(item) = self.bucket_items.iter_mut().find(|b| b.name == name) {
+            item.public = public;
+        }
+    }
+
+    // the items are replaced with the reloaded ones, keeping the filter and the selected item if it still exists
+    pub fn replace_items(&mut self, items: Vec<BucketItem>) {
+        let selected = self
+            .non_empty()
+            .then(|| self.current_selected_item().name.clone());
+        self.bucket_items = items;
+        self.update_filtered_indices();
+        if let Some(name) = selected {
+            self.select_item_by_name(&name);
+        }
+    }
+
+    pub fn select_item_by_name(&mut self, name: &str) -> bool {
+        let position = self
+            .filtered_indices
+            .iter()
+            .position(|&i| self.bucket_items[i].name == name);
+        if let Some(index) = position {
+            self.list_state.select(index);
+        }
+        position.is_some()
+    }
+
     fn non_empty(&self) -> bool {
         !self.filtered_indices.is_empty()
     }
@@ -299,8 +755,16 @@ fn build_list_items<'a>(
     offset: usize,
     selected: usize,
     area: Rect,
+    list_config: &ListConfig,
 ) -> Vec<ListItem<'a>> {
-    let show_item_count = (area.height as usize) - 2 /* border */;
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+    let show_versioning = list_config.bucket_versioning;
+    let show_numbers = list_config.numbers;
+    let number_w = if show_numbers {
+        digits(filter_indices.len())
+    } else {
+        0
+    };
     current_items
         .iter()
         .enumerate()
@@ -310,24 +774,54 @@ fn build_list_items<'a>(
         .enumerate()
         .map(|(idx, (_, item))| {
             let selected = idx + offset == selected;
-            build_list_item(&item.name, selected, filter)
+            let number = show_numbers.then_some((idx + offset + 1, number_w));
+            build_list_item(item, selected, filter, area.width, show_versioning, number)
         })
         .collect()
 }
 
-fn build_list_item<'a>(name: &'a str, selected: bool, filter: &'a str) -> ListItem<'a> {
-    let line = if filter.is_empty() {
-        Line::from(vec![" ".into(), name.into(), " ".into()])
+fn build_list_item<'a>(
+    item: &'a BucketItem,
+    selected: bool,
+    filter: &'a str,
+    width: u16,
+    show_versioning: bool,
+    number: Option<(usize, usize)>,
+) -> ListItem<'a> {
+    let name = item.name.as_str();
+    let number_w = number.map_or(0, |(_, w)| w + 1 /* space */);
+    let content_w: usize =
+        (width as usize).saturating_sub(2 /* spaces */ + 4 /* border */ + number_w);
+    let (name_w, columns) = build_item_columns(item, content_w, show_versioning);
+    let name_pad = " ".repeat(name_w.saturating_sub(name.chars().count()));
+
+    let mut line = if filter.is_empty() {
+        Line::from(vec![
+            " ".into(),
+            name.into(),
+            name_pad.into(),
+            columns.into(),
+            " ".into(),
+        ])
     } else {
         let (before, highlighted, after) = split_str(name, filter).unwrap();
         Line::from(vec![
             " ".into(),
             before.into(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR),
+            Span::styled(highlighted, highlight_style()),
             after.into(),
+            name_pad.into(),
+            columns.into(),
             " ".into(),
         ])
     };
+    if item.public == Some(true) {
+        // shown in place of the leading space
+        line.spans[0] = "!".fg(PUBLIC_COLOR).bold();
+    }
+    if let Some((n, w)) = number {
+        line.spans.insert(1, build_list_number(n, w));
+    }
 
     let style = if selected {
         Style::default()
@@ -339,12 +833,45 @@ fn build_list_item<'a>(name: &'a str, selected: bool, filter: &'a str) -> ListIt
     ListItem::new(line).style(style)
 }
 
+// show the region, creation date and versioning columns only if the name column can keep enough width
+fn build_item_columns(item: &BucketItem, width: usize, show_versioning: bool) -> (usize, String) {
+    let region_cols_w = ITEM_COLUMN_GAP + ITEM_REGION_WIDTH;
+    let date_cols_w = ITEM_COLUMN_GAP + datetime_width();
+    let versioning_cols_w = ITEM_COLUMN_GAP + ITEM_VERSIONING_WIDTH;
+
+    let mut columns = String::new();
+    let mut name_w = width;
+    if name_w >= ITEM_MIN_NAME_WIDTH + region_cols_w {
+        name_w -= region_cols_w;
+        // the regions of S3-compatible storages can be longer than the AWS ones
+        let region = item.region.as_deref().unwrap_or_default();
+        let region = truncate_with_ellipsis(region, ITEM_REGION_WIDTH);
+        columns = format!("{:>w$}", region, w = region_cols_w);
+        if name_w >= ITEM_MIN_NAME_WIDTH + date_cols_w {
+            name_w -= date_cols_w;
+            let date = item
+                .creation_date
+                .as_ref()
+                .map(format_datetime)
+                .unwrap_or_default();
+            columns = format!("{:>w$}{}", date, columns, w = date_cols_w);
+            if show_versioning && name_w >= ITEM_MIN_NAME_WIDTH + versioning_cols_w {
+                name_w -= versioning_cols_w;
+                let versioning = item.versioning.as_deref().unwrap_or_default();
+                columns = format!("{:>w$}{}", versioning, columns, w = versioning_cols_w);
+            }
+        }
+    }
+    (name_w, columns)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{event, set_cells};
+    use crate::{event, set_cells, ui::common::apply_monochrome};
 
     use super::*;
-    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+    use chrono::{DateTime, Local, NaiveDateTime};
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Modifier, Terminal};
 
     #[test]
     fn test_render_without_scroll() -> std::io::Result<()> {
@@ -356,9 +883,14 @@ mod tests {
                 .iter()
                 .map(|name| BucketItem {
                     name: name.to_string(),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: None,
                 })
                 .collect();
-            let mut page = BucketListPage::new(items, tx);
+            let mut page = BucketListPage::new(items, ListConfig::default(), tx);
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
@@ -385,6 +917,303 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_numbers() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = (1..=12)
+                .map(|i| BucketItem {
+                    name: format!("bucket{}", i),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                })
+                .collect();
+            let list_config = ListConfig {
+                numbers: true,
+                ..Default::default()
+            };
+            let mut page = BucketListPage::new(items, list_config, tx);
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────  1 / 12 ┐",
+            "│   1 bucket1               ││",
+            "│   2 bucket2               ││",
+            "│   3 bucket3               ││",
+            "│   4 bucket4               ││",
+            "│   5 bucket5               ││",
+            "│   6 bucket6                │",
+            "│   7 bucket7                │",
+            "│   8 bucket8                │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // row numbers
+            (3..6, 1..9) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_public_bucket() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = [Some(true), Some(false), None]
+                .iter()
+                .enumerate()
+                .map(|(i, public)| BucketItem {
+                    name: format!("bucket{}", i + 1),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: *public,
+                })
+                .collect();
+            let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 3 ┐",
+            "│ !bucket1                   │",
+            "│  bucket2                   │",
+            "│  bucket3                   │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // public mark
+            ([2], [1]) => fg: Color::Red, modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_monochrome() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = ["bucket1", "bucket2"]
+                .iter()
+                .map(|name| BucketItem {
+                    name: name.to_string(),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: Some(*name == "bucket2"),
+                })
+                .collect();
+            let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+            apply_monochrome(f.buffer_mut());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 2 ┐",
+            "│  bucket1                   │",
+            "│ !bucket2                   │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item is shown in reverse video instead of colors
+            (2..28, [1]) => modifier: Modifier::REVERSED,
+            // the public mark has only a foreground color, so it is underlined
+            ([2], [2]) => modifier: Modifier::BOLD, modifier: Modifier::UNDERLINED,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_policy_dialog() {
+        let (tx, _rx) = event::new();
+
+        let items = ["foo", "bar"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: None,
+                versioning: None,
+                encryption: None,
+                public: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('i')));
+        assert!(
+            matches!(&page.view_state, ViewState::PolicyDialog(state) if state.name() == "bar")
+        );
+
+        // keys scroll the dialog instead of the list
+        page.handle_key(KeyEvent::from(KeyCode::Char('g')));
+        assert_eq!(page.list_state.selected, 1);
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(page.view_state, ViewState::Default));
+        page.handle_key(KeyEvent::from(KeyCode::Char('g')));
+        assert_eq!(page.list_state.selected, 0);
+    }
+
+    #[test]
+    fn test_properties_dialog() {
+        let (tx, _rx) = event::new();
+
+        let items = ["foo", "bar"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: Some("us-east-1".to_string()),
+                versioning: None,
+                encryption: None,
+                public: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        assert!(
+            matches!(&page.view_state, ViewState::PropertiesDialog(state) if state.name() == "bar")
+        );
+
+        // the properties loaded are cached, and the failed ones are left unknown
+        page.set_bucket_properties("bar", None, Some("enabled".to_string()), None);
+        let item = page.current_selected_item();
+        assert_eq!(item.region.as_deref(), Some("us-east-1"));
+        assert_eq!(item.versioning.as_deref(), Some("enabled"));
+        assert_eq!(item.encryption, None);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        assert!(matches!(page.view_state, ViewState::Default));
+    }
+
+    #[test]
+    fn test_multipart_uploads_dialog_abort() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+
+        let items = vec![BucketItem {
+            name: "foo".to_string(),
+            creation_date: None,
+            region: None,
+            versioning: None,
+            encryption: None,
+            public: None,
+        }];
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('M')));
+        rx.recv_find(|event| matches!(event, AppEventType::LoadMultipartUploads));
+
+        let uploads = ["upload-1", "upload-2"]
+            .iter()
+            .map(|id| MultipartUpload {
+                key: format!("{}.bin", id),
+                upload_id: id.to_string(),
+                initiated: None,
+            })
+            .collect();
+        page.set_multipart_uploads("foo", uploads);
+        let mut terminal = setup_terminal()?;
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        // nothing is sent until confirmed
+        page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('y')));
+
+        let event = rx.recv_find(|event| matches!(event, AppEventType::AbortMultipartUpload(_, _)));
+        match event {
+            AppEventType::AbortMultipartUpload(key, upload_id) => {
+                assert_eq!(key, "upload-2.bin");
+                assert_eq!(upload_id, "upload-2");
+            }
+            _ => panic!("abort is not requested"),
+        }
+
+        page.remove_multipart_upload("upload-2");
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(page.view_state, ViewState::Default));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_empty() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let mut page = BucketListPage::new(Vec::new(), ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 30, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌────────────────────────────┐",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "│         No buckets         │",
+            "│                            │",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (10..20, [5]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -394,9 +1223,14 @@ mod tests {
             let items = (0..16)
                 .map(|i| BucketItem {
                     name: format!("bucket{}", i + 1),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: None,
                 })
                 .collect();
-            let mut page = BucketListPage::new(items, tx);
+            let mut page = BucketListPage::new(items, ListConfig::default(), tx);
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
@@ -424,6 +1258,187 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_columns() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(60, 6))?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                BucketItem {
+                    name: "bucket1".to_string(),
+                    creation_date: Some(parse_datetime("2024-01-02 13:01:02")),
+                    region: Some("ap-northeast-1".to_string()),
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                },
+                BucketItem {
+                    name: "bucket2".to_string(),
+                    creation_date: Some(parse_datetime("2023-12-31 09:00:00")),
+                    region: Some("us-east-1".to_string()),
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                },
+                BucketItem {
+                    name: "bucket3".to_string(),
+                    creation_date: Some(parse_datetime("2024-01-03 12:59:59")),
+                    region: Some("my-storage-region-1".to_string()),
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                },
+                BucketItem {
+                    name: "bucket4".to_string(),
+                    creation_date: Some(parse_datetime("2024-01-03 12:59:59")),
+                    region: None, // not loaded yet
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                },
+            ];
+            let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 60, 6);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 4 ┐",
+            "│  bucket1            2024-01-02 13:01:02  ap-northeast-1  │",
+            "│  bucket2            2023-12-31 09:00:00       us-east-1  │",
+            "│  bucket3            2024-01-03 12:59:59  my-storage-...  │",
+            "│  bucket4            2024-01-03 12:59:59                  │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_versioning_column() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(80, 5))?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                BucketItem {
+                    name: "bucket1".to_string(),
+                    creation_date: Some(parse_datetime("2024-01-02 13:01:02")),
+                    region: Some("ap-northeast-1".to_string()),
+                    versioning: Some("enabled".to_string()),
+                    encryption: None,
+                    public: None,
+                },
+                BucketItem {
+                    name: "bucket2".to_string(),
+                    creation_date: Some(parse_datetime("2023-12-31 09:00:00")),
+                    region: Some("us-east-1".to_string()),
+                    versioning: None, // not loaded yet
+                    encryption: None,
+                    public: None,
+                },
+            ];
+            let list_config = ListConfig {
+                bucket_versioning: true,
+                ..Default::default()
+            };
+            let mut page = BucketListPage::new(items, list_config, tx);
+            let area = Rect::new(0, 0, 80, 5);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  bucket1                       enabled  2024-01-02 13:01:02  ap-northeast-1  │",
+            "│  bucket2                                2023-12-31 09:00:00       us-east-1  │",
+            "│                                                                              │",
+            "└──────────────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..78, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_late_loaded_fields() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(80, 5))?;
+
+        // only names and creation dates are available when the list is shown
+        let items = ["bucket1", "bucket2"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: Some(parse_datetime("2024-01-02 13:01:02")),
+                region: None,
+                versioning: None,
+                encryption: None,
+                public: None,
+            })
+            .collect();
+        let list_config = ListConfig {
+            bucket_versioning: true,
+            ..Default::default()
+        };
+        let mut page = BucketListPage::new(items, list_config, tx);
+        let area = Rect::new(0, 0, 80, 5);
+
+        let mut expected_rows = [
+            "│  bucket1                                2024-01-02 13:01:02                  │",
+            "│  bucket2                                2024-01-02 13:01:02                  │",
+        ];
+        let assert_rows = |terminal: &mut Terminal<TestBackend>,
+                           page: &mut BucketListPage,
+                           rows: &[&str; 2]|
+         -> std::io::Result<()> {
+            terminal.draw(|f| page.render(f, area))?;
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "┌─────────────────────────────────────────────────────────────────────── 1 / 2 ┐",
+                rows[0],
+                rows[1],
+                "│                                                                              │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+            ]);
+            set_cells! { expected =>
+                (2..78, [1]) => bg: Color::Cyan, fg: Color::Black,
+            }
+            terminal.backend().assert_buffer(&expected);
+            Ok(())
+        };
+        assert_rows(&mut terminal, &mut page, &expected_rows)?;
+
+        // the fields arrive in any order, and each one is shown as soon as it is loaded
+        page.set_bucket_region("bucket2", "us-east-1".to_string());
+        expected_rows[1] =
+            "│  bucket2                                2024-01-02 13:01:02       us-east-1  │";
+        assert_rows(&mut terminal, &mut page, &expected_rows)?;
+
+        page.set_bucket_versioning("bucket1", "enabled".to_string());
+        expected_rows[0] =
+            "│  bucket1                       enabled  2024-01-02 13:01:02                  │";
+        assert_rows(&mut terminal, &mut page, &expected_rows)?;
+
+        page.set_bucket_region("bucket1", "ap-northeast-1".to_string());
+        expected_rows[0] =
+            "│  bucket1                       enabled  2024-01-02 13:01:02  ap-northeast-1  │";
+        assert_rows(&mut terminal, &mut page, &expected_rows)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_filter_items() {
         let (tx, _) = event::new();
@@ -432,9 +1447,14 @@ mod tests {
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
+                versioning: None,
+                encryption: None,
+                public: None,
             })
             .collect();
-        let mut page = BucketListPage::new(items, tx);
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
 
         page.handle_key(KeyEvent::from(KeyCode::Char('/')));
         page.handle_key(KeyEvent::from(KeyCode::Char('b')));
@@ -460,6 +1480,84 @@ mod tests {
         assert_eq!(page.filtered_indices, vec![0, 1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_type_ahead() {
+        let (tx, _) = event::new();
+
+        let items = ["foo", "bar", "baz", "qux", "foobar"]
+            .iter()
+            .map(|name| BucketItem {
+                name: name.to_string(),
+                creation_date: None,
+                region: None,
+                versioning: None,
+                encryption: None,
+                public: None,
+            })
+            .collect();
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('s')));
+        let now = Instant::now();
+
+        page.type_ahead('b', now);
+        assert_eq!(page.list_state.selected, 1);
+
+        page.type_ahead('a', now);
+        page.type_ahead('z', now);
+        assert_eq!(page.list_state.selected, 2);
+
+        // no match keeps the selection
+        page.type_ahead('x', now);
+        assert_eq!(page.list_state.selected, 2);
+
+        // the query is reset after a pause
+        page.type_ahead('q', now + Duration::from_secs(2));
+        assert_eq!(page.list_state.selected, 3);
+
+        // the other items are not hidden
+        assert_eq!(page.filtered_indices, vec![0, 1, 2, 3, 4]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        page.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert_eq!(page.list_state.selected, 2);
+    }
+
+    #[test]
+    fn test_select_page() -> std::io::Result<()> {
+        let cases = [(None, 8, 0), (Some(3), 3, 0)];
+        for (page_size, after_next, after_prev) in cases {
+            let (tx, _) = event::new();
+            let mut terminal = setup_terminal()?;
+
+            let items = (0..20)
+                .map(|i| BucketItem {
+                    name: format!("bucket{}", i),
+                    creation_date: None,
+                    region: None,
+                    versioning: None,
+                    encryption: None,
+                    public: None,
+                })
+                .collect();
+            let list_config = ListConfig {
+                page_size,
+                ..Default::default()
+            };
+            let mut page = BucketListPage::new(items, list_config, tx);
+            // the viewport height is known after rendering (8 items)
+            terminal.draw(|f| page.render(f, Rect::new(0, 0, 30, 10)))?;
+
+            page.handle_key(KeyEvent::from(KeyCode::Char('f')));
+            assert_eq!(page.list_state.selected, after_next);
+
+            page.handle_key(KeyEvent::from(KeyCode::Char('b')));
+            assert_eq!(page.list_state.selected, after_prev);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_filter_items() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -469,9 +1567,14 @@ mod tests {
             .iter()
             .map(|name| BucketItem {
                 name: name.to_string(),
+                creation_date: None,
+                region: None,
+                versioning: None,
+                encryption: None,
+                public: None,
             })
             .collect();
-        let mut page = BucketListPage::new(items, tx);
+        let mut page = BucketListPage::new(items, ListConfig::default(), tx);
         let area = Rect::new(0, 0, 30, 10);
 
         page.handle_key(KeyEvent::from(KeyCode::Char('/')));
@@ -544,4 +1647,11 @@ mod tests {
         terminal.clear()?;
         Ok(terminal)
     }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
 }
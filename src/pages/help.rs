@@ -14,7 +14,7 @@ use crate::{
     key_code, key_code_char,
     pages::util::build_short_helps,
     util::group_strings_to_fit_width,
-    widget::Divider,
+    widget::{Divider, InputDialog, InputDialogState},
 };
 
 const LINK_TEXT_COLOR: Color = Color::Blue;
@@ -22,24 +22,62 @@ const LINK_TEXT_COLOR: Color = Color::Blue;
 #[derive(Debug)]
 pub struct HelpPage {
     helps: Vec<String>,
+    filtered_indices: Vec<usize>,
 
+    view_state: ViewState,
+    filter_input_state: InputDialogState,
     tx: Sender,
 }
 
+#[derive(Debug, Default)]
+enum ViewState {
+    #[default]
+    Default,
+    FilterDialog,
+}
+
 impl HelpPage {
     pub fn new(helps: Vec<String>, tx: Sender) -> Self {
-        Self { helps, tx }
+        let filtered_indices = (0..helps.len()).collect();
+        Self {
+            helps,
+            filtered_indices,
+            view_state: ViewState::default(),
+            filter_input_state: InputDialogState::default(),
+            tx,
+        }
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) {
-        match key {
-            key_code!(KeyCode::Esc) => {
-                self.tx.send(AppEventType::Quit);
-            }
-            key_code!(KeyCode::Backspace) | key_code_char!('?') => {
-                self.tx.send(AppEventType::CloseCurrentPage);
-            }
-            _ => {}
+        match self.view_state {
+            ViewState::Default => match key {
+                key_code!(KeyCode::Esc) => {
+                    if self.filter_input_state.input().is_empty() {
+                        self.tx.send(AppEventType::Quit);
+                    } else {
+                        self.reset_filter();
+                    }
+                }
+                key_code!(KeyCode::Backspace) | key_code_char!('?') => {
+                    self.tx.send(AppEventType::CloseCurrentPage);
+                }
+                key_code_char!('/') => {
+                    self.open_filter_dialog();
+                }
+                _ => {}
+            },
+            ViewState::FilterDialog => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_filter_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.apply_filter();
+                }
+                _ => {
+                    self.filter_input_state.handle_key_event(key);
+                    self.update_filtered_indices();
+                }
+            },
         }
     }
 
@@ -59,20 +97,88 @@ impl HelpPage {
 
         let about = About::new(APP_NAME, APP_DESCRIPTION, APP_VERSION, APP_HOMEPAGE);
         let divider = Divider::default();
-        let help = Help::new(&self.helps);
+        let helps: Vec<String> = self
+            .filtered_indices
+            .iter()
+            .map(|&i| self.helps[i].clone())
+            .collect();
+        let help = Help::new(&helps);
 
         f.render_widget(block, area);
         f.render_widget(about, chunks[0]);
         f.render_widget(divider, chunks[1]);
         f.render_widget(help, chunks[2]);
+
+        if let ViewState::FilterDialog = self.view_state {
+            let filter_dialog = InputDialog::default().title("Filter").max_width(30);
+            f.render_stateful_widget(filter_dialog, area, &mut self.filter_input_state);
+
+            let (cursor_x, cursor_y) = self.filter_input_state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
     }
 
     pub fn short_helps(&self) -> Vec<(String, usize)> {
-        let helps: &[(&[&str], &str, usize)] = &[(&["Esc"], "Quit", 0), (&["?"], "Close help", 0)];
+        let helps: &[(&[&str], &str, usize)] = match self.view_state {
+            ViewState::Default => {
+                if self.filter_input_state.input().is_empty() {
+                    &[
+                        (&["Esc"], "Quit", 0),
+                        (&["/"], "Filter", 1),
+                        (&["?"], "Close help", 0),
+                    ]
+                } else {
+                    &[
+                        (&["Esc"], "Clear filter", 0),
+                        (&["/"], "Filter", 1),
+                        (&["?"], "Close help", 0),
+                    ]
+                }
+            }
+            ViewState::FilterDialog => &[(&["Esc"], "Close", 1), (&["Enter"], "Filter", 0)],
+        };
         build_short_helps(helps)
     }
 }
 
+impl HelpPage {
+    fn open_filter_dialog(&mut self) {
+        self.view_state = ViewState::FilterDialog;
+    }
+
+    fn close_filter_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+        self.reset_filter();
+    }
+
+    fn apply_filter(&mut self) {
+        self.view_state = ViewState::Default;
+
+        self.update_filtered_indices();
+    }
+
+    fn reset_filter(&mut self) {
+        self.filter_input_state.clear_input();
+
+        self.update_filtered_indices();
+    }
+
+    // only the descriptions are matched, ignoring case, as the keys would match almost anything
+    fn update_filtered_indices(&mut self) {
+        let filter = self.filter_input_state.input().to_lowercase();
+        self.filtered_indices = self
+            .helps
+            .iter()
+            .enumerate()
+            .filter(|(_, help)| {
+                let desc = help.split_once(": ").map_or(help.as_str(), |(_, d)| d);
+                desc.to_lowercase().contains(&filter)
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+    }
+}
+
 #[derive(Debug)]
 struct About<'a> {
     name: &'a str,
@@ -136,7 +242,7 @@ impl Widget for Help<'_> {
     }
 }
 
-fn build_help_lines(helps: &[String], max_width: usize) -> Vec<Line> {
+fn build_help_lines(helps: &[String], max_width: usize) -> Vec<Line<'_>> {
     let delimiter = ",  ";
     let word_groups = group_strings_to_fit_width(helps, max_width, delimiter);
     let lines: Vec<Line> = word_groups
@@ -221,6 +327,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_filtered() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let helps = [
+                "<s>: Download object",
+                "<p>: Preview object",
+                "<S>: Download object as",
+                "<d>: Abort selected upload",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+            let mut page = HelpPage::new(helps, tx);
+            page.handle_key(KeyEvent::from(KeyCode::Char('/')));
+            for c in "download".chars() {
+                page.handle_key(KeyEvent::from(KeyCode::Char(c)));
+            }
+            page.handle_key(KeyEvent::from(KeyCode::Enter));
+            let area = Rect::new(0, 0, 70, 20);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌STU─────────────────────────────────────────────────────────────────┐",
+            "│                                                                    │",
+            "│  STU - TUI application for AWS S3 written in Rust using ratatui    │",
+            "│                                                                    │",
+            "│  Version: 0.4.2                                                    │",
+            "│                                                                    │",
+            "│  https://github.com/lusingander/stu                                │",
+            "│                                                                    │",
+            "│ ────────────────────────────────────────────────────────────────── │",
+            "│                                                                    │",
+            "│  <s>: Download object,  <S>: Download object as                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "└────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // link
+            (3..37, [6]) => fg: Color::Blue,
+            // divider
+            (2..68, [8]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(70, 20);
         let mut terminal = Terminal::new(backend)?;
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local};
 use crossterm::event::{KeyCode, KeyEvent};
 use itsuki::zero_indexed_enum;
 use ratatui::{
@@ -10,20 +11,41 @@ use ratatui::{
 };
 
 use crate::{
+    config::{ClipboardConfig, DetailConfig, DetailDefaultTab, ListConfig},
     event::{AppEventType, Sender},
+    export::export_file_detail,
     key_code, key_code_char,
-    object::{FileDetail, FileVersion, ObjectItem},
-    pages::util::{build_helps, build_short_helps},
-    ui::common::{format_datetime, format_size_byte, format_version},
+    object::{Acl, FileDetail, FileVersion, ObjectItem},
+    pages::util::{build_helps, build_short_helps, filter_management_console_helps},
+    ui::common::{
+        datetime_width, format_datetime, format_size_byte, format_version,
+        is_management_console_enabled, toggle_relative_datetime,
+    },
+    util::{extension_from_file_name, truncate_head_with_ellipsis, truncate_with_ellipsis},
     widget::{
         Bar, CopyDetailDialog, CopyDetailDialogState, Divider, InputDialog, InputDialogState,
-        ScrollLines, ScrollLinesOptions, ScrollLinesState, ScrollList, ScrollListState,
+        ObjectAclDialog, ObjectAclDialogState, SaveDialog, SaveDialogState, ScrollLines,
+        ScrollLinesOptions, ScrollLinesState, ScrollList, ScrollListState,
     },
 };
 
 const SELECTED_COLOR: Color = Color::Cyan;
 const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
 const SELECTED_DISABLED_COLOR: Color = Color::DarkGray;
+const DELETE_MARKER_COLOR: Color = Color::DarkGray;
+const LATEST_VERSION_COLOR: Color = Color::Green;
+const PUBLIC_COLOR: Color = Color::Red;
+
+const ITEM_MIN_NAME_WIDTH: usize = 10;
+const ITEM_SIZE_WIDTH: usize = 10;
+const ITEM_COLUMN_GAP: usize = 2;
+
+// Nerd Fonts glyphs
+const DIR_ICON: &str = "\u{f07b}";
+const IMAGE_FILE_ICON: &str = "\u{f1c5}";
+const ARCHIVE_FILE_ICON: &str = "\u{f1c6}";
+const TEXT_FILE_ICON: &str = "\u{f15c}";
+const DEFAULT_FILE_ICON: &str = "\u{f15b}";
 
 #[derive(Debug)]
 pub struct ObjectDetailPage {
@@ -35,8 +57,13 @@ pub struct ObjectDetailPage {
 
     object_items: Vec<ObjectItem>,
     list_state: ScrollListState,
+    // the prefix of the items if the full keys are shown in the object list
+    key_prefix: Option<String>,
     detail_tab_state: DetailTabState,
     version_tab_state: VersionTabState,
+    download_dir: String,
+    list_config: ListConfig,
+    clipboard_config: ClipboardConfig,
     tx: Sender,
 }
 
@@ -52,29 +79,45 @@ enum Tab {
 enum ViewState {
     #[default]
     Default,
-    SaveDialog(InputDialogState),
+    SaveDialog(SaveDialogState),
     CopyDetailDialog(CopyDetailDialogState),
+    GotoLineDialog(InputDialogState),
+    AclDialog(Box<ObjectAclDialogState>),
 }
 
 impl ObjectDetailPage {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         file_detail: FileDetail,
         file_versions: Vec<FileVersion>,
         object_items: Vec<ObjectItem>,
         list_state: ScrollListState,
+        download_dir: String,
+        list_config: ListConfig,
+        detail_config: DetailConfig,
+        clipboard_config: ClipboardConfig,
         tx: Sender,
     ) -> Self {
-        let detail_tab_state = DetailTabState::new(&file_detail);
+        let detail_tab_state = DetailTabState::new(&file_detail, list_config.public_access);
         let version_tab_state = VersionTabState::new(&file_versions);
+        let tab = match detail_config.default_tab {
+            // fall back to the detail tab as there is nothing to show in the version tab
+            DetailDefaultTab::Version if !file_versions.is_empty() => Tab::Version,
+            _ => Tab::Detail,
+        };
         Self {
             file_detail,
             file_versions,
-            tab: Tab::Detail,
+            tab,
             view_state: ViewState::Default,
             object_items,
             list_state,
+            key_prefix: None,
             detail_tab_state,
             version_tab_state,
+            download_dir,
+            list_config,
+            clipboard_config,
             tx,
         }
     }
@@ -96,7 +139,7 @@ impl ObjectDetailPage {
                 }
                 key_code_char!('j') => match self.tab {
                     Tab::Detail => {
-                        self.detail_tab_state.scroll_lines_state.scroll_forward();
+                        self.detail_tab_state.select_next();
                     }
                     Tab::Version => {
                         self.version_tab_state.select_next();
@@ -104,7 +147,7 @@ impl ObjectDetailPage {
                 },
                 key_code_char!('k') => match self.tab {
                     Tab::Detail => {
-                        self.detail_tab_state.scroll_lines_state.scroll_backward();
+                        self.detail_tab_state.select_prev();
                     }
                     Tab::Version => {
                         self.version_tab_state.select_prev();
@@ -120,21 +163,62 @@ impl ObjectDetailPage {
                         self.version_tab_state.select_last();
                     }
                 }
+                key_code_char!('o') => {
+                    if self.tab == Tab::Version {
+                        self.version_tab_state.toggle_sort(&self.file_versions);
+                    }
+                }
+                key_code_char!(':') => {
+                    if self.tab == Tab::Detail {
+                        self.open_goto_line_dialog();
+                    }
+                }
                 key_code_char!('s') => {
                     self.download();
                 }
                 key_code_char!('S') => {
                     self.open_save_dialog();
                 }
+                key_code_char!('O') => {
+                    self.download_and_open();
+                }
                 key_code_char!('p') | key_code_char!('l') => {
                     self.preview();
                 }
+                key_code_char!('e') => {
+                    self.open_in_external_app();
+                }
                 key_code_char!('r') => {
                     self.open_copy_detail_dialog();
                 }
-                key_code_char!('x') => {
+                key_code_char!('y') => {
+                    if self.tab == Tab::Detail {
+                        self.copy_selected_detail();
+                    }
+                }
+                key_code_char!('J') => {
+                    if self.tab == Tab::Detail {
+                        self.detail_tab_state.toggle_json_view();
+                    }
+                }
+                key_code_char!('Y') => {
+                    self.copy_detail_json();
+                }
+                key_code_char!('A') => {
+                    self.open_acl_dialog();
+                }
+                key_code_char!('x') if is_management_console_enabled() => {
                     self.open_management_console();
                 }
+                key_code_char!('X') if is_management_console_enabled() => {
+                    self.copy_management_console_url();
+                }
+                key_code_char!('U') if is_management_console_enabled() => {
+                    self.open_parent_management_console();
+                }
+                key_code_char!('T') => {
+                    self.toggle_relative_datetime();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -160,8 +244,13 @@ impl ObjectDetailPage {
                     self.close_copy_detail_dialog();
                 }
                 key_code!(KeyCode::Enter) => {
-                    let (name, value) = state.selected_name_and_value(&self.file_detail);
-                    self.tx.send(AppEventType::CopyToClipboard(name, value));
+                    if state.is_data_uri_selected() {
+                        let file_detail = self.file_detail.clone();
+                        self.tx.send(AppEventType::CopyAsDataUri(file_detail));
+                    } else {
+                        let (name, value) = state.selected_name_and_value(&self.file_detail);
+                        self.tx.send(AppEventType::CopyToClipboard(name, value));
+                    }
                 }
                 key_code_char!('j') => {
                     state.select_next();
@@ -174,6 +263,52 @@ impl ObjectDetailPage {
                 }
                 _ => {}
             },
+            ViewState::AclDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('A') => {
+                    self.close_acl_dialog();
+                }
+                key_code_char!('j') => {
+                    state.scroll_forward();
+                }
+                key_code_char!('k') => {
+                    state.scroll_backward();
+                }
+                key_code_char!('g') => {
+                    state.scroll_to_top();
+                }
+                key_code_char!('G') => {
+                    state.scroll_to_end();
+                }
+                key_code_char!('l') => {
+                    state.scroll_right();
+                }
+                key_code_char!('h') => {
+                    state.scroll_left();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::GotoLineDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_goto_line_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    if let Ok(line) = state.input().trim().parse() {
+                        self.detail_tab_state
+                            .scroll_lines_state
+                            .scroll_to_line(line);
+                    }
+                    self.close_goto_line_dialog();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
         }
     }
 
@@ -183,13 +318,22 @@ impl ObjectDetailPage {
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
 
-        let list_items =
-            build_list_items_from_object_items(&self.object_items, offset, selected, chunks[0]);
+        let list_items = build_list_items_from_object_items(
+            &self.object_items,
+            offset,
+            selected,
+            chunks[0],
+            self.list_config.icons,
+            self.key_prefix.as_deref(),
+        );
 
         let list = ScrollList::new(list_items);
         f.render_stateful_widget(list, chunks[0], &mut self.list_state);
 
-        let block = Block::bordered();
+        let mut block = Block::bordered();
+        if self.file_detail.public == Some(true) {
+            block = block.title(" ! Public ".fg(PUBLIC_COLOR).bold());
+        }
         f.render_widget(block, chunks[1]);
 
         let chunks = Layout::vertical([Constraint::Length(2), Constraint::Min(0)])
@@ -211,7 +355,7 @@ impl ObjectDetailPage {
         }
 
         if let ViewState::SaveDialog(state) = &mut self.view_state {
-            let save_dialog = InputDialog::default().title("Save As").max_width(40);
+            let save_dialog = SaveDialog::default();
             f.render_stateful_widget(save_dialog, area, state);
 
             let (cursor_x, cursor_y) = state.cursor();
@@ -219,9 +363,40 @@ impl ObjectDetailPage {
         }
 
         if let ViewState::CopyDetailDialog(state) = &self.view_state {
-            let copy_detail_dialog = CopyDetailDialog::new(*state, &self.file_detail);
+            let copy_detail_dialog = CopyDetailDialog::new(state, &self.file_detail);
             f.render_widget(copy_detail_dialog, area);
         }
+
+        if let ViewState::AclDialog(state) = &mut self.view_state {
+            let acl_dialog = ObjectAclDialog::default();
+            f.render_stateful_widget(acl_dialog, area, state);
+        }
+
+        if let ViewState::GotoLineDialog(state) = &mut self.view_state {
+            let goto_line_dialog = InputDialog::default().title("Go to Line").max_width(20);
+            f.render_stateful_widget(goto_line_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+    }
+
+    pub fn set_key_prefix(&mut self, key_prefix: Option<String>) {
+        self.key_prefix = key_prefix;
+    }
+
+    // shows the version in the version tab, e.g. the one chosen from the versions under a prefix
+    pub fn select_version(&mut self, version_id: &str) -> bool {
+        let Some(index) = self
+            .file_versions
+            .iter()
+            .position(|v| v.version_id == version_id)
+        else {
+            return false;
+        };
+        self.tab = Tab::Version;
+        self.version_tab_state.select_index(index);
+        true
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -229,31 +404,50 @@ impl ObjectDetailPage {
             ViewState::Default => match self.tab {
                 Tab::Detail => &[
                     (&["Esc", "Ctrl-c"], "Quit app"),
-                    (&["h/l"], "Select tabs"),
+                    (&["Tab"], "Select tabs"),
                     (&["Backspace"], "Close detail panel"),
-                    (&["j/k"], "Scroll forward/backward"),
+                    (&["j/k"], "Select item"),
+                    (&[":"], "Go to line"),
+                    (&["y"], "Copy selected value to clipboard"),
+                    (&["J"], "Toggle JSON view"),
+                    (&["Y"], "Copy detail as JSON to clipboard"),
                     (&["r"], "Open copy dialog"),
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
+                    (&["O"], "Download object and open it"),
                     (&["p"], "Preview object"),
+                    (&["e"], "Open object in external app"),
+                    (&["A"], "Show object ACL"),
                     (&["x"], "Open management console in browser"),
+                    (&["X"], "Copy management console URL to clipboard"),
+                    (&["U"], "Open parent folder in management console"),
+                    (&["T"], "Toggle relative/absolute time"),
                 ],
                 Tab::Version => &[
                     (&["Esc", "Ctrl-c"], "Quit app"),
-                    (&["h/l"], "Select tabs"),
+                    (&["Tab"], "Select tabs"),
                     (&["j/k"], "Select version"),
                     (&["g/G"], "Go to top/bottom"),
+                    (&["o"], "Toggle sort by last modified/size"),
                     (&["Backspace"], "Close detail panel"),
                     (&["r"], "Open copy dialog"),
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
+                    (&["O"], "Download object and open it"),
                     (&["p"], "Preview object"),
+                    (&["e"], "Open object in external app"),
+                    (&["A"], "Show object ACL"),
                     (&["x"], "Open management console in browser"),
+                    (&["X"], "Copy management console URL to clipboard"),
+                    (&["U"], "Open parent folder in management console"),
+                    (&["Y"], "Copy detail as JSON to clipboard"),
+                    (&["T"], "Toggle relative/absolute time"),
                 ],
             },
             ViewState::SaveDialog(_) => &[
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close save dialog"),
+                (&["Tab"], "Complete path"),
                 (&["Enter"], "Download object"),
             ],
             ViewState::CopyDetailDialog(_) => &[
@@ -262,8 +456,23 @@ impl ObjectDetailPage {
                 (&["j/k"], "Select item"),
                 (&["Enter"], "Copy selected value to clipboard"),
             ],
+            ViewState::GotoLineDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close dialog"),
+                (&["Enter"], "Go to line"),
+            ],
+            ViewState::AclDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "A"], "Close ACL dialog"),
+                (&["j/k"], "Scroll forward/backward"),
+                (&["g/G"], "Scroll to top/bottom"),
+                (&["h/l"], "Scroll left/right"),
+            ],
         };
-        build_helps(helps)
+        build_helps(&filter_management_console_helps(
+            helps,
+            is_management_console_enabled(),
+        ))
     }
 
     pub fn short_helps(&self) -> Vec<(String, usize)> {
@@ -271,8 +480,8 @@ impl ObjectDetailPage {
             ViewState::Default => match self.tab {
                 Tab::Detail => &[
                     (&["Esc"], "Quit", 0),
-                    (&["h/l"], "Select tabs", 3),
-                    (&["j/k"], "Scroll", 5),
+                    (&["Tab"], "Select tabs", 3),
+                    (&["j/k"], "Select", 5),
                     (&["s/S"], "Download", 1),
                     (&["p"], "Preview", 4),
                     (&["Backspace"], "Close", 2),
@@ -280,7 +489,7 @@ impl ObjectDetailPage {
                 ],
                 Tab::Version => &[
                     (&["Esc"], "Quit", 0),
-                    (&["h/l"], "Select tabs", 3),
+                    (&["Tab"], "Select tabs", 3),
                     (&["j/k"], "Select", 5),
                     (&["s/S"], "Download", 1),
                     (&["p"], "Preview", 4),
@@ -290,6 +499,7 @@ impl ObjectDetailPage {
             },
             ViewState::SaveDialog(_) => &[
                 (&["Esc"], "Close", 2),
+                (&["Tab"], "Complete", 3),
                 (&["Enter"], "Download", 1),
                 (&["?"], "Help", 0),
             ],
@@ -299,6 +509,16 @@ impl ObjectDetailPage {
                 (&["Enter"], "Copy", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::GotoLineDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Go", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::AclDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Scroll", 1),
+                (&["?"], "Help", 0),
+            ],
         };
 
         build_short_helps(helps)
@@ -311,7 +531,11 @@ impl ObjectDetailPage {
     }
 
     fn open_save_dialog(&mut self) {
-        self.view_state = ViewState::SaveDialog(InputDialogState::default());
+        if self.is_delete_marker_selected() {
+            return;
+        }
+        let state = SaveDialogState::new(self.download_dir.clone());
+        self.view_state = ViewState::SaveDialog(state);
     }
 
     pub fn close_save_dialog(&mut self) {
@@ -319,20 +543,101 @@ impl ObjectDetailPage {
     }
 
     fn open_copy_detail_dialog(&mut self) {
-        self.view_state = ViewState::CopyDetailDialog(CopyDetailDialogState::default());
+        // large objects are not offered as a data URI, it would be too long to paste anywhere
+        let data_uri_enabled =
+            self.file_detail.size_byte <= self.clipboard_config.data_uri_max_size;
+        // only a concrete version can be pinned, objects in unversioned buckets have the "null" version
+        let version_id = match self.tab {
+            Tab::Detail => None,
+            Tab::Version => self
+                .selected_version()
+                .filter(|v| !v.is_delete_marker && v.version_id != "null")
+                .map(|v| v.version_id.clone()),
+        };
+        let mut state = CopyDetailDialogState::new(
+            &self.file_detail,
+            data_uri_enabled,
+            version_id,
+            &self.clipboard_config.templates,
+        );
+        state.set_value_width(self.clipboard_config.value_width);
+        self.view_state = ViewState::CopyDetailDialog(state);
+    }
+
+    // the ACL of the version selected in the version tab, or the latest one
+    fn open_acl_dialog(&mut self) {
+        let key = self.file_detail.key.clone();
+        let version_id = self.current_selected_version_id();
+        let state = ObjectAclDialogState::new(&key);
+        self.view_state = ViewState::AclDialog(Box::new(state));
+        self.tx
+            .send(AppEventType::ObjectDetailLoadAcl(key, version_id));
+    }
+
+    pub fn close_acl_dialog(&mut self) {
+        if let ViewState::AclDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_acl(&mut self, key: &str, acl: &Acl) {
+        if let ViewState::AclDialog(state) = &mut self.view_state {
+            // ignore the result for the dialog already closed
+            if state.key() == key {
+                state.set_acl(acl);
+            }
+        }
+    }
+
+    fn copy_selected_detail(&self) {
+        if let Some((label, value)) = self.detail_tab_state.selected_entry() {
+            let (name, value) = (label.to_string(), value.to_string());
+            self.tx.send(AppEventType::CopyToClipboard(name, value));
+        }
+    }
+
+    fn copy_detail_json(&self) {
+        let (name, value) = (
+            "Detail JSON".to_string(),
+            self.detail_tab_state.json.clone(),
+        );
+        self.tx.send(AppEventType::CopyToClipboard(name, value));
     }
 
     fn close_copy_detail_dialog(&mut self) {
         self.view_state = ViewState::Default;
     }
 
+    fn open_goto_line_dialog(&mut self) {
+        self.view_state = ViewState::GotoLineDialog(InputDialogState::default());
+    }
+
+    fn close_goto_line_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
     fn download(&self) {
+        if self.is_delete_marker_selected() {
+            return;
+        }
         let file_detail = self.file_detail.clone();
         let version_id = self.current_selected_version_id();
         self.tx
             .send(AppEventType::DetailDownloadObject(file_detail, version_id));
     }
 
+    fn download_and_open(&self) {
+        if self.is_delete_marker_selected() {
+            return;
+        }
+        let file_detail = self.file_detail.clone();
+        let version_id = self.current_selected_version_id();
+        self.tx.send(AppEventType::DetailDownloadAndOpenObject(
+            file_detail,
+            version_id,
+        ));
+    }
+
     fn download_as(&self, input: String) {
         let input: String = input.trim().into();
         if input.is_empty() {
@@ -349,60 +654,140 @@ impl ObjectDetailPage {
     }
 
     fn preview(&self) {
+        if self.is_delete_marker_selected() {
+            return;
+        }
         let file_detail = self.file_detail.clone();
         let version_id = self.current_selected_version_id();
         self.tx
             .send(AppEventType::OpenPreview(file_detail, version_id));
     }
 
+    fn open_in_external_app(&self) {
+        if self.is_delete_marker_selected() {
+            return;
+        }
+        let file_detail = self.file_detail.clone();
+        let version_id = self.current_selected_version_id();
+        self.tx
+            .send(AppEventType::OpenInExternalApp(file_detail, version_id));
+    }
+
     fn open_management_console(&self) {
         let file_name = self.file_detail.name.clone();
         self.tx
             .send(AppEventType::ObjectDetailOpenManagementConsole(file_name));
     }
 
+    fn open_parent_management_console(&self) {
+        self.tx
+            .send(AppEventType::ObjectDetailOpenParentManagementConsole);
+    }
+
+    fn copy_management_console_url(&self) {
+        let file_name = self.file_detail.name.clone();
+        self.tx
+            .send(AppEventType::ObjectDetailCopyManagementConsoleUrl(
+                file_name,
+            ));
+    }
+
+    fn toggle_relative_datetime(&mut self) {
+        toggle_relative_datetime();
+
+        // the lines of the tabs are cached, so rebuild them to reflect the change
+        self.detail_tab_state.refresh();
+        self.version_tab_state.refresh(&self.file_versions);
+    }
+
+    // delete markers have no content to download, so notify instead of failing the request
+    fn is_delete_marker_selected(&self) -> bool {
+        let selected = match self.tab {
+            Tab::Detail => false,
+            Tab::Version => self.selected_version().is_some_and(|v| v.is_delete_marker),
+        };
+        if selected {
+            let msg = "Delete marker cannot be downloaded or previewed".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        }
+        selected
+    }
+
     fn current_selected_version_id(&self) -> Option<String> {
         match self.tab {
             Tab::Detail => None,
-            Tab::Version => self
-                .file_versions
-                .get(self.version_tab_state.selected)
-                .map(|v| v.version_id.clone()),
+            Tab::Version => self.selected_version().map(|v| v.version_id.clone()),
         }
     }
+
+    fn selected_version(&self) -> Option<&FileVersion> {
+        self.version_tab_state
+            .selected_index()
+            .and_then(|i| self.file_versions.get(i))
+    }
 }
 
-fn build_list_items_from_object_items(
-    current_items: &[ObjectItem],
+fn build_list_items_from_object_items<'a>(
+    current_items: &'a [ObjectItem],
     offset: usize,
     selected: usize,
     area: Rect,
-) -> Vec<ListItem> {
-    let show_item_count = (area.height as usize) - 2 /* border */;
+    icons: bool,
+    key_prefix: Option<&str>,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
     current_items
         .iter()
         .skip(offset)
         .take(show_item_count)
         .enumerate()
-        .map(|(idx, item)| build_list_item_from_object_item(idx, item, offset, selected, area))
+        .map(|(idx, item)| {
+            build_list_item_from_object_item(idx, item, offset, selected, area, icons, key_prefix)
+        })
         .collect()
 }
 
-fn build_list_item_from_object_item(
+fn build_list_item_from_object_item<'a>(
     idx: usize,
-    item: &ObjectItem,
+    item: &'a ObjectItem,
     offset: usize,
     selected: usize,
     area: Rect,
-) -> ListItem {
+    icons: bool,
+    key_prefix: Option<&str>,
+) -> ListItem<'a> {
+    let full_key = key_prefix.is_some();
     let content = match item {
         ObjectItem::Dir { name, .. } => {
-            let content = format_dir_item(name, area.width);
+            let icon = if icons {
+                format!("{} ", DIR_ICON)
+            } else {
+                String::new()
+            };
+            let name = format!("{}{}", key_prefix.unwrap_or_default(), name);
+            let content = format_dir_item(&icon, &name, area.width, full_key);
             let style = Style::default().add_modifier(Modifier::BOLD);
             Span::styled(content, style)
         }
-        ObjectItem::File { name, .. } => {
-            let content = format_file_item(name, area.width);
+        ObjectItem::File {
+            name,
+            size_byte,
+            last_modified,
+        } => {
+            let icon = if icons {
+                format!("{} ", file_icon(name))
+            } else {
+                String::new()
+            };
+            let name = format!("{}{}", key_prefix.unwrap_or_default(), name);
+            let content = format_file_item(
+                &icon,
+                &name,
+                *size_byte,
+                last_modified,
+                area.width,
+                full_key,
+            );
             let style = Style::default();
             Span::styled(content, style)
         }
@@ -418,15 +803,73 @@ fn build_list_item_from_object_item(
     }
 }
 
-fn format_dir_item(name: &str, width: u16) -> String {
-    let name_w: usize = (width as usize) - 2 /* spaces */ - 2 /* border */;
+fn file_icon(name: &str) -> &'static str {
+    let extension = extension_from_file_name(name).to_lowercase();
+    match extension.as_str() {
+        "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "svg" | "ico" => IMAGE_FILE_ICON,
+        "zip" | "tar" | "gz" | "tgz" | "bz2" | "xz" | "7z" | "rar" => ARCHIVE_FILE_ICON,
+        "txt" | "md" | "csv" | "log" | "json" | "yaml" | "yml" | "toml" | "xml" => TEXT_FILE_ICON,
+        _ => DEFAULT_FILE_ICON,
+    }
+}
+
+fn format_dir_item(icon: &str, name: &str, width: u16, full_key: bool) -> String {
+    let content_w: usize = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border */);
     let name = format!("{}/", name);
-    format!(" {:<name_w$} ", name, name_w = name_w)
+    format!(
+        " {} ",
+        format_item_columns(icon, &name, "", "-", content_w, full_key)
+    )
 }
 
-fn format_file_item(name: &str, width: u16) -> String {
-    let name_w: usize = (width as usize) - 2 /* spaces */ - 4 /* border */;
-    format!(" {:<name_w$} ", name, name_w = name_w)
+fn format_file_item(
+    icon: &str,
+    name: &str,
+    size_byte: usize,
+    last_modified: &DateTime<Local>,
+    width: u16,
+    full_key: bool,
+) -> String {
+    let content_w: usize = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border */);
+    let date = format_datetime(last_modified);
+    let size = format_size_byte(size_byte);
+    format!(
+        " {} ",
+        format_item_columns(icon, name, &date, &size, content_w, full_key)
+    )
+}
+
+// show the size and date columns only if the name column can keep enough width.
+// the full keys are cut off from the head, leaving the icon.
+fn format_item_columns(
+    icon: &str,
+    name: &str,
+    date: &str,
+    size: &str,
+    width: usize,
+    full_key: bool,
+) -> String {
+    let size_cols_w = ITEM_COLUMN_GAP + ITEM_SIZE_WIDTH;
+    let date_cols_w = ITEM_COLUMN_GAP + datetime_width();
+
+    let mut columns = String::new();
+    let mut name_w = width;
+    if name_w >= ITEM_MIN_NAME_WIDTH + size_cols_w {
+        name_w -= size_cols_w;
+        columns = format!("{:>w$}", size, w = size_cols_w);
+        if name_w >= ITEM_MIN_NAME_WIDTH + date_cols_w {
+            name_w -= date_cols_w;
+            columns = format!("{:>w$}{}", date, columns, w = date_cols_w);
+        }
+    }
+
+    let name_w = name_w.saturating_sub(icon.chars().count());
+    let name = if full_key {
+        truncate_head_with_ellipsis(name, name_w)
+    } else {
+        truncate_with_ellipsis(name, name_w)
+    };
+    format!("{}{:<name_w$}{}", icon, name, columns, name_w = name_w)
 }
 
 fn build_tabs(tab: Tab) -> Tabs<'static> {
@@ -441,43 +884,227 @@ fn build_tabs(tab: Tab) -> Tabs<'static> {
         .block(Block::default().borders(Borders::BOTTOM))
 }
 
-fn build_detail_content_lines(detail: &FileDetail) -> Vec<Line<'static>> {
-    let details = [
-        ("Name:", &detail.name),
-        ("Size:", &format_size_byte(detail.size_byte)),
-        ("Last Modified:", &format_datetime(&detail.last_modified)),
-        ("ETag:", &detail.e_tag),
-        ("Content-Type:", &detail.content_type),
-        ("Storage class:", &detail.storage_class),
+// the public status is shown only if it is checked, to tell the unknown status from the unchecked one
+fn build_detail_entries(detail: &FileDetail, public_access: bool) -> Vec<(&'static str, String)> {
+    let public = if public_access {
+        format_public(detail.public).to_string()
+    } else {
+        String::new()
+    };
+    [
+        ("Name", detail.name.clone()),
+        ("Size", format_size_byte(detail.size_byte)),
+        ("Last Modified", format_datetime(&detail.last_modified)),
+        ("ETag", format_e_tag(detail)),
+        ("Checksum", format_checksum(detail)),
+        ("Content-Type", detail.content_type.clone()),
+        ("Content-Encoding", detail.content_encoding.clone()),
+        ("Content-Disposition", detail.content_disposition.clone()),
+        ("Cache-Control", detail.cache_control.clone()),
+        ("Storage class", detail.storage_class.clone()),
+        ("Encryption", format_encryption(detail)),
+        ("Public", public),
     ]
-    .iter()
-    .filter_map(|(label, value)| {
-        if value.is_empty() {
-            None
-        } else {
-            let lines = vec![
-                Line::from(label.add_modifier(Modifier::BOLD)),
-                Line::from(format!(" {}", value)),
-            ];
-            Some(lines)
+    .into_iter()
+    .filter(|(_, value)| !value.is_empty())
+    .collect()
+}
+
+fn build_detail_line_groups(
+    entries: &[(&'static str, String)],
+    selected: usize,
+    width: usize,
+) -> Vec<Vec<Line<'static>>> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, (label, value))| {
+            let mut label = format!("{}:", label).add_modifier(Modifier::BOLD);
+            if i == selected {
+                label = label.fg(SELECTED_COLOR);
+            }
+            let value_lines = wrap_detail_value(value, width).into_iter().map(Line::from);
+            std::iter::once(Line::from(label))
+                .chain(value_lines)
+                .collect()
+        })
+        .collect()
+}
+
+fn format_checksum(detail: &FileDetail) -> String {
+    if detail.checksum.is_empty() {
+        String::new()
+    } else {
+        format!("{} ({})", detail.checksum, detail.checksum_algorithm)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ETagType {
+    Md5,
+    Multipart(usize),
+}
+
+// the ETag of a multipart upload is the MD5 of the part MD5s followed by the number of parts.
+// other forms (e.g. by S3-compatible storages) are not classified.
+fn classify_e_tag(e_tag: &str) -> Option<ETagType> {
+    let is_md5 = |s: &str| s.len() == 32 && s.chars().all(|c| c.is_ascii_hexdigit());
+    match e_tag.split_once('-') {
+        Some((hash, parts)) if is_md5(hash) => parts.parse().ok().map(ETagType::Multipart),
+        None if is_md5(e_tag) => Some(ETagType::Md5),
+        _ => None,
+    }
+}
+
+fn format_e_tag(detail: &FileDetail) -> String {
+    let e_tag = &detail.e_tag;
+    match classify_e_tag(e_tag) {
+        // the ETag of an object encrypted with SSE-KMS looks like an MD5, but is not
+        Some(ETagType::Md5) if detail.server_side_encryption.starts_with("aws:kms") => {
+            e_tag.to_string()
         }
-    })
-    .collect();
+        Some(ETagType::Md5) => format!("{} (MD5)", e_tag),
+        Some(ETagType::Multipart(1)) => format!("{} (multipart, 1 part)", e_tag),
+        Some(ETagType::Multipart(n)) => format!("{} (multipart, {} parts)", e_tag, n),
+        None => e_tag.to_string(),
+    }
+}
+
+fn format_encryption(detail: &FileDetail) -> String {
+    if detail.sse_kms_key_id.is_empty() {
+        detail.server_side_encryption.clone()
+    } else {
+        format!(
+            "{} (key {})",
+            detail.server_side_encryption, detail.sse_kms_key_id
+        )
+    }
+}
+
+fn format_public(public: Option<bool>) -> &'static str {
+    match public {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "unknown",
+    }
+}
 
-    flatten_with_empty_lines(details)
+fn wrap_detail_value(value: &str, width: usize) -> Vec<String> {
+    const INDENT: &str = " ";
+    // values such as ETag and URL have no spaces, so break them at the width instead of hyphens
+    let options = textwrap::Options::new(width.max(INDENT.len() + 1))
+        .initial_indent(INDENT)
+        .subsequent_indent(INDENT)
+        .word_splitter(textwrap::WordSplitter::NoHyphenation)
+        .break_words(true);
+    textwrap::wrap(value, options)
+        .into_iter()
+        .map(|line| line.into_owned())
+        .collect()
 }
 
 #[derive(Debug)]
 struct DetailTabState {
+    entries: Vec<(&'static str, String)>,
+    json: String,
+    json_view: bool,
+    selected: usize,
+    // the first and last line of each entry, to keep the selected entry in view
+    entry_lines: Vec<(usize, usize)>,
+    width: usize,
     scroll_lines_state: ScrollLinesState,
 }
 
 impl DetailTabState {
-    fn new(file_detail: &FileDetail) -> Self {
-        let scroll_lines = build_detail_content_lines(file_detail);
+    fn new(file_detail: &FileDetail, public_access: bool) -> Self {
+        // the lines are built on the first render, when the width is known
         let scroll_lines_state =
-            ScrollLinesState::new(scroll_lines, ScrollLinesOptions::new(false, true));
-        Self { scroll_lines_state }
+            ScrollLinesState::new(Vec::new(), ScrollLinesOptions::new(false, true));
+        let json = export_file_detail(file_detail).unwrap_or_else(|e| e.msg);
+        Self {
+            entries: build_detail_entries(file_detail, public_access),
+            json,
+            json_view: false,
+            selected: 0,
+            entry_lines: Vec::new(),
+            width: 0,
+            scroll_lines_state,
+        }
+    }
+
+    fn select_next(&mut self) {
+        if self.json_view {
+            self.scroll_lines_state.scroll_forward();
+        } else if self.selected + 1 < self.entries.len() {
+            self.select(self.selected + 1);
+        }
+    }
+
+    fn select_prev(&mut self) {
+        if self.json_view {
+            self.scroll_lines_state.scroll_backward();
+        } else if self.selected > 0 {
+            self.select(self.selected - 1);
+        }
+    }
+
+    fn toggle_json_view(&mut self) {
+        self.json_view = !self.json_view;
+        self.refresh();
+        if self.json_view {
+            self.scroll_lines_state.scroll_to_top();
+        } else {
+            self.select(self.selected);
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.refresh();
+        if let Some(&(first, last)) = self.entry_lines.get(index) {
+            self.scroll_lines_state.scroll_into_view(first, last);
+        }
+    }
+
+    fn selected_entry(&self) -> Option<(&str, &str)> {
+        if self.json_view {
+            return None;
+        }
+        self.entries
+            .get(self.selected)
+            .map(|(label, value)| (*label, value.as_str()))
+    }
+
+    fn update_width(&mut self, width: usize) {
+        if self.width == width {
+            return;
+        }
+        self.width = width;
+        self.refresh();
+    }
+
+    fn refresh(&mut self) {
+        if self.json_view {
+            let lines = self
+                .json
+                .lines()
+                .map(|l| Line::raw(l.to_string()))
+                .collect();
+            self.scroll_lines_state.set_lines(lines);
+            return;
+        }
+        let groups = build_detail_line_groups(&self.entries, self.selected, self.width);
+        let mut first = 0;
+        self.entry_lines = groups
+            .iter()
+            .map(|lines| {
+                let range = (first, first + lines.len() - 1);
+                first += lines.len() + 1; // empty line between entries
+                range
+            })
+            .collect();
+        self.scroll_lines_state
+            .set_lines(flatten_with_empty_lines(groups));
     }
 }
 
@@ -488,17 +1115,40 @@ impl StatefulWidget for DetailTab {
     type State = DetailTabState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.update_width(area.width.saturating_sub(2) as usize /* padding */);
+
         let scroll_lines = ScrollLines::default();
         StatefulWidget::render(scroll_lines, area, buf, &mut state.scroll_lines_state);
     }
 }
 
-fn build_help_lines(versions: &[FileVersion]) -> Vec<Vec<Line<'static>>> {
-    versions
+fn build_help_lines(versions: &[FileVersion], indices: &[usize]) -> Vec<Vec<Line<'static>>> {
+    indices
         .iter()
-        .map(|v| {
+        .map(|&i| {
+            let v = &versions[i];
             let version_id = format_version(&v.version_id).to_owned();
             let last_modified = format_datetime(&v.last_modified);
+            if v.is_delete_marker {
+                // a delete marker has no content, so the size is meaningless
+                return vec![
+                    Line::from(vec![
+                        "   Version ID: ".add_modifier(Modifier::BOLD),
+                        Span::raw(version_id),
+                    ])
+                    .fg(DELETE_MARKER_COLOR),
+                    Line::from(vec![
+                        "Last Modified: ".add_modifier(Modifier::BOLD),
+                        Span::raw(last_modified),
+                    ])
+                    .fg(DELETE_MARKER_COLOR),
+                    Line::from(vec![
+                        "Delete Marker".add_modifier(Modifier::BOLD),
+                        latest_version_mark(v),
+                    ])
+                    .fg(DELETE_MARKER_COLOR),
+                ];
+            }
             let size_byte = format_size_byte(v.size_byte);
             vec![
                 Line::from(vec![
@@ -512,15 +1162,49 @@ fn build_help_lines(versions: &[FileVersion]) -> Vec<Vec<Line<'static>>> {
                 Line::from(vec![
                     "         Size: ".add_modifier(Modifier::BOLD),
                     Span::raw(size_byte),
+                    latest_version_mark(v),
                 ]),
             ]
         })
         .collect()
 }
 
+// the latest version is not always at the top once sorted by size
+fn latest_version_mark(v: &FileVersion) -> Span<'static> {
+    if v.is_latest {
+        " (latest)".fg(LATEST_VERSION_COLOR)
+    } else {
+        Span::raw("")
+    }
+}
+
+#[derive(Default)]
+#[zero_indexed_enum]
+enum VersionSort {
+    #[default]
+    LastModified,
+    Size,
+}
+
+// the indices of the versions in the order to show, newest or largest first
+fn sort_version_indices(versions: &[FileVersion], sort: VersionSort) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..versions.len()).collect();
+    match sort {
+        VersionSort::LastModified => {
+            indices.sort_by(|&a, &b| versions[b].last_modified.cmp(&versions[a].last_modified))
+        }
+        VersionSort::Size => {
+            indices.sort_by(|&a, &b| versions[b].size_byte.cmp(&versions[a].size_byte))
+        }
+    }
+    indices
+}
+
 #[derive(Debug, Default)]
 struct VersionTabState {
     help_lines: Vec<Vec<Line<'static>>>,
+    sort: VersionSort,
+    indices: Vec<usize>,
     selected: usize,
     offset: usize,
     height: usize,
@@ -528,15 +1212,52 @@ struct VersionTabState {
 
 impl VersionTabState {
     fn new(versions: &[FileVersion]) -> Self {
-        let help_lines = build_help_lines(versions);
+        let sort = VersionSort::default();
+        let indices = sort_version_indices(versions, sort);
+        let help_lines = build_help_lines(versions, &indices);
         Self {
             help_lines,
+            sort,
+            indices,
             ..Default::default()
         }
     }
 
+    // the index of the selected version in the versions, not in the sorted order
+    fn selected_index(&self) -> Option<usize> {
+        self.indices.get(self.selected).copied()
+    }
+
+    fn refresh(&mut self, versions: &[FileVersion]) {
+        self.help_lines = build_help_lines(versions, &self.indices);
+    }
+
+    fn toggle_sort(&mut self, versions: &[FileVersion]) {
+        let selected_index = self.selected_index();
+
+        self.sort = self.sort.next();
+        self.indices = sort_version_indices(versions, self.sort);
+        self.refresh(versions);
+
+        // keep the same version selected
+        self.select_index(selected_index.unwrap_or_default());
+    }
+
+    // selects the version at the index in the versions, scrolling from the top so that it is visible
+    fn select_index(&mut self, index: usize) {
+        let position = self
+            .indices
+            .iter()
+            .position(|&i| i == index)
+            .unwrap_or_default();
+        self.select_first();
+        for _ in 0..position {
+            self.select_next();
+        }
+    }
+
     fn select_next(&mut self) {
-        if self.selected >= self.help_lines.len() - 1 {
+        if self.selected + 1 >= self.help_lines.len() {
             return;
         }
 
@@ -574,6 +1295,9 @@ impl VersionTabState {
     }
 
     fn select_last(&mut self) {
+        if self.help_lines.is_empty() {
+            return;
+        }
         self.selected = self.help_lines.len() - 1;
 
         let mut total_height = 0;
@@ -587,7 +1311,8 @@ impl VersionTabState {
                 self.offset = i;
                 break;
             } else if total_height > self.height {
-                self.offset = i + 1;
+                // the height is 0 before the first render, and the selected one is still shown
+                self.offset = (i + 1).min(self.selected);
                 break;
             }
         }
@@ -661,16 +1386,18 @@ fn flatten_with_empty_lines(line_groups: Vec<Vec<Line>>) -> Vec<Line> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{event, set_cells};
+    use crate::{event, set_cells, ui::common::apply_monochrome};
 
     use super::*;
     use chrono::{DateTime, Local, NaiveDateTime};
     use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+    use rstest::rstest;
 
     #[test]
     fn test_render_detail_tab() -> std::io::Result<()> {
         let (tx, _) = event::new();
-        let mut terminal = setup_terminal()?;
+        // taller than the other tests to show the content metadata
+        let mut terminal = Terminal::new(TestBackend::new(60, 30))?;
 
         terminal.draw(|f| {
             let (items, file_detail, file_versions) = fixtures();
@@ -680,18 +1407,22 @@ mod tests {
                 file_versions,
                 items,
                 ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig::default(),
+                ClipboardConfig::default(),
                 tx,
             );
-            let area = Rect::new(0, 0, 60, 20);
+            let area = Rect::new(0, 0, 60, 30);
             page.render(f, area);
         })?;
 
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
-            "│  file2                     ││────────────────────────────│",
-            "│  file3                     ││ Name:                      │",
+            "│  file1           1.01 KiB  ││ Detail │ Version           │",
+            "│  file2            999 KiB  ││────────────────────────────│",
+            "│  file3              1 KiB  ││ Name:                      │",
             "│                            ││  file1                     │",
             "│                            ││                            │",
             "│                            ││ Size:                      │",
@@ -702,11 +1433,21 @@ mod tests {
             "│                            ││                            │",
             "│                            ││ ETag:                      │",
             "│                            ││  bef684de-a260-48a4-8178-8 │",
-            "│                            ││ a535ecccadb                │",
+            "│                            ││  a535ecccadb               │",
             "│                            ││                            │",
             "│                            ││ Content-Type:              │",
             "│                            ││  text/plain                │",
             "│                            ││                            │",
+            "│                            ││ Content-Encoding:          │",
+            "│                            ││  gzip                      │",
+            "│                            ││                            │",
+            "│                            ││ Cache-Control:             │",
+            "│                            ││  max-age=3600              │",
+            "│                            ││                            │",
+            "│                            ││ Storage class:             │",
+            "│                            ││  STANDARD                  │",
+            "│                            ││                            │",
+            "│                            ││ Encryption:                │",
             "└────────────────────────────┘└────────────────────────────┘",
         ]);
         set_cells! { expected =>
@@ -714,8 +1455,8 @@ mod tests {
             (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
             // "Detail" is selected
             (32..38, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
-            // "Name" label
-            (32..37, [3]) => modifier: Modifier::BOLD,
+            // "Name" label is selected
+            (32..37, [3]) => fg: Color::Cyan, modifier: Modifier::BOLD,
             // "Size" label
             (32..37, [6]) => modifier: Modifier::BOLD,
             // "Last Modified" label
@@ -724,6 +1465,14 @@ mod tests {
             (32..37, [12]) => modifier: Modifier::BOLD,
             // "Content-Type" label
             (32..45, [16]) => modifier: Modifier::BOLD,
+            // "Content-Encoding" label
+            (32..49, [19]) => modifier: Modifier::BOLD,
+            // "Cache-Control" label
+            (32..46, [22]) => modifier: Modifier::BOLD,
+            // "Storage class" label
+            (32..46, [25]) => modifier: Modifier::BOLD,
+            // "Encryption" label
+            (32..43, [28]) => modifier: Modifier::BOLD,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -731,6 +1480,132 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_public_warning() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(60, 8))?;
+
+        terminal.draw(|f| {
+            let (items, mut file_detail, file_versions) = fixtures();
+            file_detail.public = Some(true);
+            let items_len = items.len();
+            let list_config = ListConfig {
+                public_access: true,
+                ..Default::default()
+            };
+            let mut page = ObjectDetailPage::new(
+                file_detail,
+                file_versions,
+                items,
+                ScrollListState::new(items_len),
+                "download".to_string(),
+                list_config,
+                DetailConfig::default(),
+                ClipboardConfig::default(),
+                tx,
+            );
+            let area = Rect::new(0, 0, 60, 8);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 3 ┐┌ ! Public ──────────────────┐",
+            "│  file1           1.01 KiB  ││ Detail │ Version           │",
+            "│  file2            999 KiB  ││────────────────────────────│",
+            "│  file3              1 KiB  ││ Name:                      │",
+            "│                            ││  file1                     │",
+            "│                            ││                            │",
+            "│                            ││ Size:                      │",
+            "└────────────────────────────┘└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // public warning
+            (31..41, [0]) => fg: Color::Red, modifier: Modifier::BOLD,
+            // selected item
+            (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
+            // "Detail" is selected
+            (32..38, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+            // "Name" label is selected
+            (32..37, [3]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+            // "Size" label
+            (32..37, [6]) => modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_detail_entries_public() {
+        let (_, mut file_detail, _) = fixtures();
+
+        let public = |entries: Vec<(&str, String)>| {
+            entries
+                .into_iter()
+                .find(|(label, _)| *label == "Public")
+                .map(|(_, value)| value)
+        };
+        assert_eq!(public(build_detail_entries(&file_detail, false)), None);
+        assert_eq!(
+            public(build_detail_entries(&file_detail, true)),
+            Some("unknown".to_string())
+        );
+
+        file_detail.public = Some(false);
+        assert_eq!(
+            public(build_detail_entries(&file_detail, true)),
+            Some("no".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case("d41d8cd98f00b204e9800998ecf8427e", Some(ETagType::Md5))]
+    #[case("D41D8CD98F00B204E9800998ECF8427E", Some(ETagType::Md5))]
+    #[case("9b2cf535f27731c974343645a3985328-3", Some(ETagType::Multipart(3)))]
+    #[case(
+        "9b2cf535f27731c974343645a3985328-10000",
+        Some(ETagType::Multipart(10000))
+    )]
+    #[case("9b2cf535f27731c974343645a3985328-", None)]
+    #[case("9b2cf535f27731c974343645a3985328-x", None)]
+    #[case("bef684de-a260-48a4-8178-8a535ecccadb", None)]
+    #[case("d41d8cd98f00b204e9800998ecf8427", None)]
+    #[case("", None)]
+    fn test_classify_e_tag(#[case] e_tag: &str, #[case] expected: Option<ETagType>) {
+        assert_eq!(classify_e_tag(e_tag), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "d41d8cd98f00b204e9800998ecf8427e",
+        "d41d8cd98f00b204e9800998ecf8427e (MD5)"
+    )]
+    #[case(
+        "9b2cf535f27731c974343645a3985328-1",
+        "9b2cf535f27731c974343645a3985328-1 (multipart, 1 part)"
+    )]
+    #[case(
+        "9b2cf535f27731c974343645a3985328-3",
+        "9b2cf535f27731c974343645a3985328-3 (multipart, 3 parts)"
+    )]
+    #[case("etag-1", "etag-1")]
+    fn test_format_e_tag(#[case] e_tag: &str, #[case] expected: &str) {
+        let detail = FileDetail {
+            e_tag: e_tag.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(format_e_tag(&detail), expected);
+
+        let kms_detail = FileDetail {
+            server_side_encryption: "aws:kms".to_string(),
+            ..detail
+        };
+        let expected = expected.strip_suffix(" (MD5)").unwrap_or(expected);
+        assert_eq!(format_e_tag(&kms_detail), expected);
+    }
+
     #[test]
     fn test_render_version_tab() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -744,6 +1619,10 @@ mod tests {
                 file_versions,
                 items,
                 ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig::default(),
+                ClipboardConfig::default(),
                 tx,
             );
             page.toggle_tab();
@@ -754,20 +1633,20 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
-            "│  file2                     ││────────────────────────────│",
-            "│  file3                     ││┃    Version ID: 60f36bc2-0f│",
+            "│  file1           1.01 KiB  ││ Detail │ Version           │",
+            "│  file2            999 KiB  ││────────────────────────────│",
+            "│  file3              1 KiB  ││┃    Version ID: 60f36bc2-0f│",
             "│                            ││┃ Last Modified: 2024-01-02 │",
-            "│                            ││┃          Size: 1.01 KiB   │",
+            "│                            ││┃          Size: 1.01 KiB (l│",
             "│                            ││────────────────────────────│",
             "│                            ││     Version ID: 1c5d3bcc-2b│",
             "│                            ││  Last Modified: 2024-01-01 │",
             "│                            ││           Size: 1 KiB      │",
             "│                            ││────────────────────────────│",
-            "│                            ││                            │",
-            "│                            ││                            │",
-            "│                            ││                            │",
-            "│                            ││                            │",
+            "│                            ││     Version ID: 0b6b7d4e-3f│",
+            "│                            ││  Last Modified: 2023-12-31 │",
+            "│                            ││  Delete Marker             │",
+            "│                            ││────────────────────────────│",
             "│                            ││                            │",
             "│                            ││                            │",
             "│                            ││                            │",
@@ -787,8 +1666,15 @@ mod tests {
             (33..48, [5, 9]) => modifier: Modifier::BOLD,
             // selected bar
             ([31], [3, 4, 5]) => fg: Color::Cyan,
+            // latest version mark
+            (56..59, [5]) => fg: Color::Green,
             // divider
-            (31..59, [6, 10]) => fg: Color::DarkGray,
+            (31..59, [6, 10, 14]) => fg: Color::DarkGray,
+            // delete marker is dimmed
+            (33..59, [11, 12]) => fg: Color::DarkGray,
+            (33..46, [13]) => fg: Color::DarkGray,
+            (33..48, [11, 12]) => modifier: Modifier::BOLD,
+            (33..46, [13]) => modifier: Modifier::BOLD,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -809,6 +1695,10 @@ mod tests {
                 file_versions,
                 items,
                 ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig::default(),
+                ClipboardConfig::default(),
                 tx,
             );
             page.open_save_dialog();
@@ -819,20 +1709,20 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
-            "│  file2                     ││────────────────────────────│",
-            "│  file3                     ││ Name:                      │",
+            "│  file1           1.01 KiB  ││ Detail │ Version           │",
+            "│  file2            999 KiB  ││────────────────────────────│",
+            "│  file3              1 KiB  ││ Name:                      │",
             "│                            ││  file1                     │",
             "│                            ││                            │",
             "│                            ││ Size:                      │",
             "│                            ││  1.01 KiB                  │",
             "│         ╭Save As───────────────────────────────╮         │",
             "│         │                                      │         │",
-            "│         ╰──────────────────────────────────────╯ 2       │",
+            "│         ╰download/─────────────────────────────╯ 2       │",
             "│                            ││                            │",
             "│                            ││ ETag:                      │",
             "│                            ││  bef684de-a260-48a4-8178-8 │",
-            "│                            ││ a535ecccadb                │",
+            "│                            ││  a535ecccadb               │",
             "│                            ││                            │",
             "│                            ││ Content-Type:              │",
             "│                            ││  text/plain                │",
@@ -844,14 +1734,16 @@ mod tests {
             (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
             // "Detail" is selected
             (32..38, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
-            // "Name" label
-            (32..37, [3]) => modifier: Modifier::BOLD,
+            // "Name" label is selected
+            (32..37, [3]) => fg: Color::Cyan, modifier: Modifier::BOLD,
             // "Size" label
             (32..37, [6]) => modifier: Modifier::BOLD,
             // "ETag" label
             (32..37, [12]) => modifier: Modifier::BOLD,
             // "Content-Type" label
             (32..45, [16]) => modifier: Modifier::BOLD,
+            // hint
+            (11..20, [10]) => fg: Color::DarkGray,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -872,6 +1764,10 @@ mod tests {
                 file_versions,
                 items,
                 ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig::default(),
+                ClipboardConfig::default(),
                 tx,
             );
             page.open_copy_detail_dialog();
@@ -882,9 +1778,6 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
-            "│  file2                     ││────────────────────────────│",
-            "│  file3                     ││ Name:                      │",
             "│ ╭Copy──────────────────────────────────────────────────╮ │",
             "│ │ Key:                                                 │ │",
             "│ │   file1                                              │ │",
@@ -893,36 +1786,37 @@ mod tests {
             "│ │ ARN:                                                 │ │",
             "│ │   arn:aws:s3:::bucket-1/file1                        │ │",
             "│ │ Object URL:                                          │ │",
-            "│ │   https://bucket-1.s3.ap-northeast-1.amazonaws.com/f │ │",
+            "│ │   https://bucket-1.s3.ap-northeast-1.amazonaws.co... │ │",
             "│ │ ETag:                                                │ │",
             "│ │   bef684de-a260-48a4-8178-8a535ecccadb               │ │",
+            "│ │ Content-Encoding:                                    │ │",
+            "│ │   gzip                                               │ │",
+            "│ │ Cache-Control:                                       │ │",
+            "│ │   max-age=3600                                       │ │",
+            "│ │ Data URI:                                            │ │",
+            "│ │   data:text/plain;base64,...                         │ │",
             "│ ╰──────────────────────────────────────────────────────╯ │",
-            "│                            ││ Content-Type:              │",
-            "│                            ││  text/plain                │",
-            "│                            ││                            │",
             "└────────────────────────────┘└────────────────────────────┘",
         ]);
         set_cells! { expected =>
-            // selected item
-            (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
-            // "Detail" is selected
-            (32..38, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
-            // "Name" label
-            (32..37, [3]) => modifier: Modifier::BOLD,
-            // "Content-Type" label
-            (32..45, [16]) => modifier: Modifier::BOLD,
             // "Key" label
-            (4..8, [5]) => modifier: Modifier::BOLD,
+            (4..8, [2]) => modifier: Modifier::BOLD,
             // "S3 URI" label
-            (4..11, [7]) => modifier: Modifier::BOLD,
+            (4..11, [4]) => modifier: Modifier::BOLD,
             // "ARN" label
-            (4..8, [9]) => modifier: Modifier::BOLD,
+            (4..8, [6]) => modifier: Modifier::BOLD,
             // "Object URL" label
-            (4..15, [11]) => modifier: Modifier::BOLD,
+            (4..15, [8]) => modifier: Modifier::BOLD,
             // "ETag" label
-            (4..9, [13]) => modifier: Modifier::BOLD,
+            (4..9, [10]) => modifier: Modifier::BOLD,
+            // "Content-Encoding" label
+            (4..21, [12]) => modifier: Modifier::BOLD,
+            // "Cache-Control" label
+            (4..18, [14]) => modifier: Modifier::BOLD,
+            // "Data URI" label
+            (4..13, [16]) => modifier: Modifier::BOLD,
             // "Key" is selected
-            (4..56, [5, 6]) => fg: Color::Cyan,
+            (4..56, [2, 3]) => fg: Color::Cyan,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -930,6 +1824,485 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_copy_detail_dialog_monochrome() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let (items, file_detail, file_versions) = fixtures();
+            let items_len = items.len();
+            let mut page = ObjectDetailPage::new(
+                file_detail,
+                file_versions,
+                items,
+                ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig::default(),
+                ClipboardConfig::default(),
+                tx,
+            );
+            page.open_copy_detail_dialog();
+            let area = Rect::new(0, 0, 60, 20);
+            page.render(f, area);
+            apply_monochrome(f.buffer_mut());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
+            "│ ╭Copy──────────────────────────────────────────────────╮ │",
+            "│ │ Key:                                                 │ │",
+            "│ │   file1                                              │ │",
+            "│ │ S3 URI:                                              │ │",
+            "│ │   s3://bucket-1/file1                                │ │",
+            "│ │ ARN:                                                 │ │",
+            "│ │   arn:aws:s3:::bucket-1/file1                        │ │",
+            "│ │ Object URL:                                          │ │",
+            "│ │   https://bucket-1.s3.ap-northeast-1.amazonaws.co... │ │",
+            "│ │ ETag:                                                │ │",
+            "│ │   bef684de-a260-48a4-8178-8a535ecccadb               │ │",
+            "│ │ Content-Encoding:                                    │ │",
+            "│ │   gzip                                               │ │",
+            "│ │ Cache-Control:                                       │ │",
+            "│ │   max-age=3600                                       │ │",
+            "│ │ Data URI:                                            │ │",
+            "│ │   data:text/plain;base64,...                         │ │",
+            "│ ╰──────────────────────────────────────────────────────╯ │",
+            "└────────────────────────────┘└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // "Key" label
+            (4..8, [2]) => modifier: Modifier::BOLD,
+            // "S3 URI" label
+            (4..11, [4]) => modifier: Modifier::BOLD,
+            // "ARN" label
+            (4..8, [6]) => modifier: Modifier::BOLD,
+            // "Object URL" label
+            (4..15, [8]) => modifier: Modifier::BOLD,
+            // "ETag" label
+            (4..9, [10]) => modifier: Modifier::BOLD,
+            // "Content-Encoding" label
+            (4..21, [12]) => modifier: Modifier::BOLD,
+            // "Cache-Control" label
+            (4..18, [14]) => modifier: Modifier::BOLD,
+            // "Data URI" label
+            (4..13, [16]) => modifier: Modifier::BOLD,
+            // the selected item has no background, but is shown in reverse video too
+            (4..56, [2, 3]) => modifier: Modifier::REVERSED,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_selected_detail() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(60, 20))?;
+
+        let (items, file_detail, file_versions) = fixtures();
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        terminal.draw(|f| page.render(f, f.size()))?;
+
+        // Name -> Size -> Last Modified -> ETag
+        for _ in 0..3 {
+            page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        }
+        page.handle_key(KeyEvent::from(KeyCode::Char('y')));
+
+        // skip the events from the input thread, which cannot read the terminal in tests
+        let event = rx.recv_find(|event| matches!(event, AppEventType::CopyToClipboard(_, _)));
+        match event {
+            AppEventType::CopyToClipboard(name, value) => {
+                assert_eq!(name, "ETag");
+                assert_eq!(value, "bef684de-a260-48a4-8178-8a535ecccadb");
+            }
+            _ => panic!("value is not copied"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_toggle_version_sort() {
+        let (tx, _) = event::new();
+        let (items, file_detail, _) = fixtures();
+        let version = |id: &str, size_byte, last_modified, is_latest| FileVersion {
+            version_id: id.to_string(),
+            size_byte,
+            last_modified: parse_datetime(last_modified),
+            is_latest,
+            is_delete_marker: false,
+        };
+        let file_versions = vec![
+            version("v3", 10, "2024-01-03 00:00:00", true),
+            version("v2", 2000, "2024-01-02 00:00:00", false),
+            version("v1", 500, "2024-01-01 00:00:00", false),
+        ];
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.toggle_tab();
+        page.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(page.current_selected_version_id().as_deref(), Some("v2"));
+
+        // the selected version is kept, and moves to the top as the largest one
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert_eq!(page.version_tab_state.selected, 0);
+        assert_eq!(page.current_selected_version_id().as_deref(), Some("v2"));
+
+        // the latest version is the smallest, and is still marked as latest
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(page.current_selected_version_id().as_deref(), Some("v3"));
+        let size_line = &page.version_tab_state.help_lines[2][2];
+        assert_eq!(size_line.spans[2].content, " (latest)");
+
+        // back to the newest first
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert_eq!(page.version_tab_state.selected, 0);
+        assert_eq!(page.current_selected_version_id().as_deref(), Some("v3"));
+    }
+
+    #[test]
+    fn test_default_tab() {
+        let new_page = |default_tab, file_versions| {
+            let (tx, _) = event::new();
+            let (items, file_detail, _) = fixtures();
+            let items_len = items.len();
+            ObjectDetailPage::new(
+                file_detail,
+                file_versions,
+                items,
+                ScrollListState::new(items_len),
+                "download".to_string(),
+                ListConfig::default(),
+                DetailConfig { default_tab },
+                ClipboardConfig::default(),
+                tx,
+            )
+        };
+        let (_, _, file_versions) = fixtures();
+
+        let page = new_page(DetailDefaultTab::Detail, file_versions.clone());
+        assert_eq!(page.tab, Tab::Detail);
+
+        let page = new_page(DetailDefaultTab::Version, file_versions);
+        assert_eq!(page.tab, Tab::Version);
+        assert!(page.helps().iter().any(|h| h.contains("Select version")));
+
+        // no versions to show
+        let page = new_page(DetailDefaultTab::Version, vec![]);
+        assert_eq!(page.tab, Tab::Detail);
+    }
+
+    #[test]
+    fn test_download_and_open() {
+        let (tx, rx) = event::new();
+
+        let (items, file_detail, file_versions) = fixtures();
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('O')));
+
+        let event =
+            rx.recv_find(|event| matches!(event, AppEventType::DetailDownloadAndOpenObject(_, _)));
+        match event {
+            AppEventType::DetailDownloadAndOpenObject(file_detail, version_id) => {
+                assert_eq!(file_detail.name, "file1");
+                assert_eq!(version_id, None);
+            }
+            _ => panic!("download and open is not requested"),
+        }
+    }
+
+    #[test]
+    fn test_open_parent_management_console() {
+        let (tx, rx) = event::new();
+
+        let (items, file_detail, file_versions) = fixtures();
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('U')));
+
+        let event = rx.recv_find(|event| {
+            matches!(
+                event,
+                AppEventType::ObjectDetailOpenParentManagementConsole
+                    | AppEventType::ObjectDetailOpenManagementConsole(_)
+            )
+        });
+        assert!(matches!(
+            event,
+            AppEventType::ObjectDetailOpenParentManagementConsole
+        ));
+    }
+
+    #[test]
+    fn test_acl_dialog() {
+        let (tx, rx) = event::new();
+
+        let (items, file_detail, file_versions) = fixtures();
+        let items_len = items.len();
+        let key = file_detail.key.clone();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('A')));
+        assert!(matches!(&page.view_state, ViewState::AclDialog(state) if state.key() == key));
+
+        let event = rx.recv_find(|event| matches!(event, AppEventType::ObjectDetailLoadAcl(_, _)));
+        match event {
+            AppEventType::ObjectDetailLoadAcl(k, version_id) => {
+                assert_eq!(k, key);
+                assert_eq!(version_id, None);
+            }
+            _ => panic!("object ACL is not requested"),
+        }
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(page.view_state, ViewState::Default));
+    }
+
+    #[test]
+    fn test_wrap_detail_value() {
+        let value = "bef684de-a260-48a4-8178-8a535ecccadb";
+        let actual = wrap_detail_value(value, 20);
+        assert_eq!(actual, [" bef684de-a260-48a4-", " 8178-8a535ecccadb"]);
+
+        let value = "あいうえおかきくけこ";
+        let actual = wrap_detail_value(value, 10);
+        assert_eq!(actual, [" あいうえ", " おかきく", " けこ"]);
+
+        let value = "short";
+        let actual = wrap_detail_value(value, 20);
+        assert_eq!(actual, [" short"]);
+    }
+
+    #[test]
+    fn test_build_detail_line_groups_encryption() {
+        let (_, mut file_detail, _) = fixtures();
+
+        let entries = build_detail_entries(&file_detail, false);
+        let groups = build_detail_line_groups(&entries, 0, 60);
+        let lines: Vec<String> = groups
+            .last()
+            .unwrap()
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Encryption:",
+                " aws:kms (key",
+                " arn:aws:kms:ap-northeast-1:123456789012:key/2f9e0c34-4a3d-4",
+                " b8e-9f3c-6d2a1b7e5c10)",
+            ]
+        );
+
+        file_detail.server_side_encryption = "".to_string();
+        file_detail.sse_kms_key_id = "".to_string();
+        let entries = build_detail_entries(&file_detail, false);
+        assert!(!entries.iter().any(|(label, _)| *label == "Encryption"));
+    }
+
+    #[test]
+    fn test_format_list_items() {
+        let last_modified = parse_datetime("2024-01-02 13:01:02");
+
+        #[rustfmt::skip]
+        let cases = [
+            (format_file_item("", "file1", 1024 + 10, &last_modified, 60, false), " file1                  2024-01-02 13:01:02    1.01 KiB "),
+            (format_dir_item("", "dir1", 60, false),                              " dir1/                                                - "),
+            (format_file_item("", "file1", 1024 + 10, &last_modified, 30, false), " file1           1.01 KiB "),
+            (format_file_item("", "file_name_too_long", 1024, &last_modified, 30, false), " file_name...       1 KiB "),
+            (format_file_item("", "file1", 1024 + 10, &last_modified, 20, false), " file1          "),
+            (format_file_item("", "dir/file_name_too_long", 1024, &last_modified, 30, true), " ..._too_long       1 KiB "),
+            (format_dir_item("", "dir1/dir2/dir3/dir4", 20, true),                " .../dir3/dir4/ "),
+            (format_file_item("* ", "dir/sub/file1", 1024, &last_modified, 20, true), " * ...sub/file1 "),
+        ];
+        for (actual, expected) in cases {
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_format_list_items_narrow() {
+        let last_modified = parse_datetime("2024-01-02 13:01:02");
+
+        // no room for the name, but it does not panic
+        #[rustfmt::skip]
+        let cases = [
+            (format_file_item("", "file1", 1024, &last_modified, 3, false), " ... "),
+            (format_dir_item("", "dir1", 3, false),                         " ... "),
+            (format_file_item("", "file1", 1024, &last_modified, 0, true),  " ... "),
+        ];
+        for (actual, expected) in cases {
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 0)]
+    #[case(2, 0)]
+    #[case(3, 1)]
+    #[case(4, 2)]
+    #[case(10, 3)]
+    fn test_build_list_items_short(#[case] height: u16, #[case] expected: usize) {
+        let (items, _, _) = fixtures();
+        let area = Rect::new(0, 0, 3, height);
+        let list_items = build_list_items_from_object_items(&items, 0, 0, area, false, None);
+        assert_eq!(list_items.len(), expected);
+    }
+
+    #[test]
+    fn test_version_tab_state_empty() {
+        let mut state = VersionTabState::new(&[]);
+
+        state.select_next();
+        state.select_last();
+        state.select_prev();
+        state.select_first();
+        assert_eq!((state.selected, state.offset), (0, 0));
+        assert_eq!(state.selected_index(), None);
+    }
+
+    #[test]
+    fn test_version_tab_state_not_rendered() {
+        let (_, _, file_versions) = fixtures();
+        let mut state = VersionTabState::new(&file_versions);
+
+        // the height is not known yet
+        state.select_last();
+        assert_eq!(state.selected, file_versions.len() - 1);
+        assert!(state.offset <= state.selected);
+        state.select_next();
+        assert_eq!(state.selected, file_versions.len() - 1);
+        state.select_first();
+        state.select_next();
+        assert_eq!((state.selected, state.offset), (1, 1));
+    }
+
+    #[test]
+    fn test_select_version() {
+        let (tx, _) = event::new();
+        let (items, file_detail, file_versions) = fixtures();
+        let items_len = items.len();
+        let mut page = ObjectDetailPage::new(
+            file_detail,
+            file_versions,
+            items,
+            ScrollListState::new(items_len),
+            "download".to_string(),
+            ListConfig::default(),
+            DetailConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+
+        assert!(!page.select_version("unknown"));
+        assert_eq!(page.tab, Tab::Detail);
+
+        assert!(page.select_version("1c5d3bcc-2bb3-4cd5-875f-a95a6ae53f65"));
+        assert_eq!(page.tab, Tab::Version);
+        assert_eq!(
+            page.current_selected_version_id().as_deref(),
+            Some("1c5d3bcc-2bb3-4cd5-875f-a95a6ae53f65")
+        );
+    }
+
+    #[test]
+    fn test_file_icon() {
+        assert_eq!(file_icon("image.png"), IMAGE_FILE_ICON);
+        assert_eq!(file_icon("IMAGE.JPG"), IMAGE_FILE_ICON);
+        assert_eq!(file_icon("archive.tar.gz"), ARCHIVE_FILE_ICON);
+        assert_eq!(file_icon("README.md"), TEXT_FILE_ICON);
+        assert_eq!(file_icon("binary"), DEFAULT_FILE_ICON);
+    }
+
+    #[test]
+    fn test_build_list_item_with_icons() {
+        let last_modified = parse_datetime("2024-01-02 13:01:02");
+        let items = [
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "a.png".to_string(),
+                size_byte: 1024,
+                last_modified,
+            },
+        ];
+        let area = Rect::new(0, 0, 30, 10);
+        let list_items = build_list_items_from_object_items(&items, 0, 1, area, true, None);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 26, 2));
+        let list = ratatui::widgets::List::new(list_items);
+        Widget::render(list, buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " \u{f07b} dir1/                - ",
+            " \u{f1c5} a.png            1 KiB ",
+        ]);
+        set_cells! { expected =>
+            (0..26, [0]) => modifier: Modifier::BOLD,
+            (0..26, [1]) => bg: SELECTED_DISABLED_COLOR, fg: SELECTED_ITEM_TEXT_COLOR,
+        }
+        assert_eq!(buf, expected);
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 20);
         let mut terminal = Terminal::new(backend)?;
@@ -967,12 +2340,24 @@ mod tests {
             size_byte: 1024 + 10,
             last_modified: parse_datetime("2024-01-02 13:01:02"),
             e_tag: "bef684de-a260-48a4-8178-8a535ecccadb".to_string(),
+            checksum_algorithm: "".to_string(),
+            checksum: "".to_string(),
+            version_id: "".to_string(),
             content_type: "text/plain".to_string(),
+            content_encoding: "gzip".to_string(),
+            content_disposition: "".to_string(),
+            cache_control: "max-age=3600".to_string(),
             storage_class: "STANDARD".to_string(),
+            server_side_encryption: "aws:kms".to_string(),
+            sse_kms_key_id:
+                "arn:aws:kms:ap-northeast-1:123456789012:key/2f9e0c34-4a3d-4b8e-9f3c-6d2a1b7e5c10"
+                    .to_string(),
+            bucket: "bucket-1".to_string(),
             key: "file1".to_string(),
             s3_uri: "s3://bucket-1/file1".to_string(),
             arn: "arn:aws:s3:::bucket-1/file1".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file1".to_string(),
+            public: None,
         };
         let file_versions = vec![
             FileVersion {
@@ -980,12 +2365,21 @@ mod tests {
                 size_byte: 1024 + 10,
                 last_modified: parse_datetime("2024-01-02 13:01:02"),
                 is_latest: true,
+                is_delete_marker: false,
             },
             FileVersion {
                 version_id: "1c5d3bcc-2bb3-4cd5-875f-a95a6ae53f65".to_string(),
                 size_byte: 1024,
                 last_modified: parse_datetime("2024-01-01 23:59:59"),
                 is_latest: false,
+                is_delete_marker: false,
+            },
+            FileVersion {
+                version_id: "0b6b7d4e-3f1a-4c2e-8d5b-9a7e6f1c2d3b".to_string(),
+                size_byte: 0,
+                last_modified: parse_datetime("2023-12-31 09:00:00"),
+                is_latest: false,
+                is_delete_marker: true,
             },
         ];
         (items, file_detail, file_versions)
@@ -1,36 +1,65 @@
+use std::{collections::HashSet, time::Instant};
+
 use chrono::{DateTime, Local};
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use itsuki::zero_indexed_enum;
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::ListItem,
     Frame,
 };
 
 use crate::{
+    config::ListConfig,
     event::{AppEventType, Sender},
     key_code, key_code_char,
-    object::ObjectItem,
-    pages::util::{build_helps, build_short_helps},
-    ui::common::{format_datetime, format_size_byte},
-    util::split_str,
-    widget::{InputDialog, InputDialogState, ScrollList, ScrollListState},
+    object::{FileDetail, MultipartUpload, ObjectHead, ObjectItem, PrefixVersion},
+    pages::util::{
+        build_helps, build_list_number, build_short_helps, filter_management_console_helps,
+        push_number_prefix,
+    },
+    ui::common::{
+        datetime_width, format_datetime, format_size_byte, highlight_style,
+        is_management_console_enabled, toggle_relative_datetime,
+    },
+    util::{digits, rsplit_str, split_str, truncate_head_with_ellipsis, TimeRange},
+    widget::{
+        ExportDialog, ExportDialogState, HeadPreview, HeadPreviewState, InputDialog,
+        InputDialogState, MultipartUploadsDialog, MultipartUploadsDialogState, PeekDialog,
+        PeekDialogState, PrefixVersionsDialog, PrefixVersionsDialogState, ScrollList,
+        ScrollListState,
+    },
 };
 
 const SELECTED_COLOR: Color = Color::Cyan;
 const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
-const HIGHLIGHTED_ITEM_TEXT_COLOR: Color = Color::Red;
+const MARKED_COLOR: Color = Color::Yellow;
+const NEWEST_COLOR: Color = Color::Green;
+
+const ITEM_MIN_NAME_WIDTH: usize = 10;
 
 #[derive(Debug)]
 pub struct ObjectListPage {
     object_items: Vec<ObjectItem>,
     filtered_indices: Vec<usize>,
+    type_filter: TypeFilter,
 
     view_state: ViewState,
 
     list_state: ScrollListState,
     filter_input_state: InputDialogState,
+    number_prefix: Option<usize>,
+    head_preview: Option<HeadPreviewState>,
+    // indices of object_items, so that the marks are kept while filtering
+    marked_indices: HashSet<usize>,
+    flat: bool,
+    // the prefix the items are listed under, to show the full keys
+    prefix: String,
+    full_key: bool,
+    next_token: Option<String>,
+    list_config: ListConfig,
     tx: Sender,
 }
 
@@ -38,27 +67,72 @@ pub struct ObjectListPage {
 enum ViewState {
     Default,
     FilterDialog,
+    ExportDialog(ExportDialogState),
+    PeekDialog(PeekDialogState),
+    MultipartUploadsDialog(Box<MultipartUploadsDialogState>),
+    VersionsRangeDialog(InputDialogState),
+    PrefixVersionsDialog(Box<PrefixVersionsDialogState>),
+}
+
+#[derive(Default)]
+#[zero_indexed_enum]
+enum TypeFilter {
+    #[default]
+    All,
+    File,
+    Dir,
+}
+
+impl TypeFilter {
+    fn matches(&self, item: &ObjectItem) -> bool {
+        match self {
+            Self::All => true,
+            Self::File => matches!(item, ObjectItem::File { .. }),
+            Self::Dir => matches!(item, ObjectItem::Dir { .. }),
+        }
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::File => Some("Files only"),
+            Self::Dir => Some("Dirs only"),
+        }
+    }
 }
 
 impl ObjectListPage {
-    pub fn new(object_items: Vec<ObjectItem>, tx: Sender) -> Self {
+    pub fn new(object_items: Vec<ObjectItem>, list_config: ListConfig, tx: Sender) -> Self {
         let items_len = object_items.len();
         let filtered_indices = (0..items_len).collect();
         Self {
             object_items,
             filtered_indices,
+            type_filter: TypeFilter::default(),
             view_state: ViewState::Default,
-            list_state: ScrollListState::new(items_len),
+            list_state: ScrollListState::new(items_len).wrap(list_config.wrap),
             filter_input_state: InputDialogState::default(),
+            number_prefix: None,
+            head_preview: None,
+            marked_indices: HashSet::new(),
+            flat: false,
+            prefix: String::new(),
+            full_key: list_config.full_key,
+            next_token: None,
+            list_config,
             tx,
         }
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) {
+        // the number prefix is only for the next key
+        let number_prefix = self.number_prefix.take();
         match self.view_state {
             ViewState::Default => match key {
                 key_code!(KeyCode::Esc) => {
-                    if !self.filter_input_state.input().is_empty() {
+                    if !self.marked_indices.is_empty() {
+                        self.clear_marks();
+                    } else if !self.filter_input_state.input().is_empty() {
                         self.reset_filter();
                     }
                 }
@@ -80,8 +154,16 @@ impl ObjectListPage {
                 key_code_char!('g') if self.non_empty() => {
                     self.select_first();
                 }
-                key_code_char!('G') if self.non_empty() => {
-                    self.select_last();
+                key_code_char!('G') if self.non_empty() => match number_prefix {
+                    Some(n) => self.select_index(n.saturating_sub(1)),
+                    None => self.select_last(),
+                },
+                KeyEvent {
+                    code: KeyCode::Char(c @ '0'..='9'),
+                    modifiers: KeyModifiers::NONE,
+                    ..
+                } if self.non_empty() => {
+                    self.number_prefix = Some(push_number_prefix(number_prefix, c));
                 }
                 key_code_char!('f') if self.non_empty() => {
                     self.select_next_page();
@@ -89,15 +171,78 @@ impl ObjectListPage {
                 key_code_char!('b') if self.non_empty() => {
                     self.select_prev_page();
                 }
+                key_code_char!('d', Ctrl) if self.non_empty() => {
+                    self.select_next_half_page();
+                }
+                key_code_char!('u', Ctrl) if self.non_empty() => {
+                    self.select_prev_half_page();
+                }
                 key_code_char!('~') => {
                     self.tx.send(AppEventType::BackToBucketList);
                 }
-                key_code_char!('x') if self.non_empty() => {
+                key_code_char!('^') => {
+                    self.tx.send(AppEventType::BackToBucketRoot);
+                }
+                key_code_char!('x') if self.non_empty() && is_management_console_enabled() => {
                     self.tx.send(AppEventType::ObjectListOpenManagementConsole);
                 }
+                key_code_char!('X') if self.non_empty() && is_management_console_enabled() => {
+                    self.tx
+                        .send(AppEventType::ObjectListCopyManagementConsoleUrl);
+                }
+                key_code_char!('y') if self.non_empty() => {
+                    self.tx.send(AppEventType::ObjectListCopyS3Uri);
+                }
+                key_code_char!('Y') if self.non_empty() => {
+                    self.tx.send(AppEventType::ObjectListCopyKey);
+                }
+                key_code_char!('T') => {
+                    toggle_relative_datetime();
+                }
                 key_code_char!('/') => {
                     self.open_filter_dialog();
                 }
+                key_code_char!(' ') if self.non_empty() => {
+                    self.toggle_mark();
+                    self.select_next();
+                }
+                key_code_char!('s') if !self.marked_indices.is_empty() => {
+                    self.tx
+                        .send(AppEventType::DownloadObjects(self.marked_items()));
+                }
+                key_code_char!('i') if self.non_empty() => {
+                    self.open_peek_dialog();
+                }
+                key_code_char!('t') => {
+                    self.toggle_type_filter();
+                }
+                key_code_char!('K') => {
+                    self.full_key = !self.full_key;
+                }
+                key_code_char!('E') => {
+                    self.open_export_dialog();
+                }
+                key_code_char!('P') => {
+                    self.toggle_head_preview();
+                }
+                key_code_char!('.') => {
+                    self.tx.send(AppEventType::ObjectListToggleFlat);
+                }
+                key_code_char!('r', Ctrl) => {
+                    self.tx.send(AppEventType::ObjectListReload);
+                }
+                key_code_char!('m') if self.next_token.is_some() => {
+                    self.tx.send(AppEventType::ObjectListLoadMore);
+                }
+                key_code_char!('M') => {
+                    self.open_multipart_uploads_dialog();
+                }
+                key_code_char!('N') if self.non_empty() => {
+                    self.select_newest();
+                }
+                key_code_char!('V') => {
+                    self.open_versions_range_dialog();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -118,12 +263,147 @@ impl ObjectListPage {
                     self.update_filtered_indices();
                 }
             },
+            ViewState::ExportDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_export_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let format = state.selected_format();
+                    self.tx.send(AppEventType::ExportListing(format));
+                    self.close_export_dialog();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::MultipartUploadsDialog(ref mut state) if state.is_confirming() => {
+                match key {
+                    key_code_char!('y') | key_code!(KeyCode::Enter) => {
+                        state.close_confirm();
+                        if let Some(upload) = state.selected_upload() {
+                            let (key, upload_id) = (upload.key.clone(), upload.upload_id.clone());
+                            self.tx
+                                .send(AppEventType::AbortMultipartUpload(key, upload_id));
+                        }
+                    }
+                    key_code_char!('n') | key_code!(KeyCode::Esc) => {
+                        state.close_confirm();
+                    }
+                    _ => {}
+                }
+            }
+            ViewState::MultipartUploadsDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('M') => {
+                    self.close_multipart_uploads_dialog();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('g') => {
+                    state.select_first();
+                }
+                key_code_char!('G') => {
+                    state.select_last();
+                }
+                key_code_char!('d') => {
+                    state.open_confirm();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::PeekDialog(_) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('i') => {
+                    self.close_peek_dialog();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::VersionsRangeDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_versions_range_dialog();
+                }
+                key_code!(KeyCode::Enter) => match TimeRange::parse(state.input()) {
+                    Ok(range) => {
+                        self.open_prefix_versions_dialog(range);
+                    }
+                    Err(msg) => {
+                        self.tx.send(AppEventType::NotifyWarn(msg));
+                    }
+                },
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::PrefixVersionsDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code_char!('V') => {
+                    self.close_prefix_versions_dialog();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('g') => {
+                    state.select_first();
+                }
+                key_code_char!('G') => {
+                    state.select_last();
+                }
+                key_code!(KeyCode::Enter) => {
+                    if let (Some(key), Some(version)) =
+                        (state.selected_relative_key(), state.selected_version())
+                    {
+                        let key = key.to_string();
+                        let version_id = version.version_id.clone();
+                        self.tx
+                            .send(AppEventType::ObjectListOpenPrefixVersion(key, version_id));
+                    }
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
         }
+
+        // the selection or the filter may have been changed by the key
+        self.update_head_preview();
     }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let (list_area, preview_area) = match &self.head_preview {
+            Some(_) => {
+                let chunks = Layout::horizontal(Constraint::from_percentages([50, 50])).split(area);
+                (chunks[0], Some(chunks[1]))
+            }
+            None => (area, None),
+        };
+
         let offset = self.list_state.offset;
         let selected = self.list_state.selected;
+        let newest = if self.list_config.mark_newest {
+            newest_index(&self.object_items, &self.filtered_indices)
+        } else {
+            None
+        };
 
         let list_items = build_list_items(
             &self.object_items,
@@ -131,11 +411,41 @@ impl ObjectListPage {
             self.filter_input_state.input(),
             offset,
             selected,
-            area,
+            &self.marked_indices,
+            newest,
+            list_area,
+            self.list_config.numbers,
+            self.key_prefix(),
         );
 
-        let list = ScrollList::new(list_items);
-        f.render_stateful_widget(list, area, &mut self.list_state);
+        let mut list = ScrollList::new(list_items);
+        if self.object_items.is_empty() {
+            list = list.empty_message("No objects");
+        }
+        // the list is incomplete if only the first pages have been loaded
+        let truncated = self.next_token.as_ref().map(|_| {
+            format!(
+                "Showing first {} of many, m: Load more",
+                self.object_items.len()
+            )
+        });
+        let titles: Vec<&str> = [
+            self.flat.then_some("Flat"),
+            self.type_filter.title(),
+            truncated.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !titles.is_empty() {
+            list = list.title(titles.join(", "));
+        }
+        f.render_stateful_widget(list, list_area, &mut self.list_state);
+
+        if let (Some(state), Some(preview_area)) = (&self.head_preview, preview_area) {
+            let head_preview = HeadPreview::new(state);
+            f.render_widget(head_preview, preview_area);
+        }
 
         if let ViewState::FilterDialog = self.view_state {
             let filter_dialog = InputDialog::default().title("Filter").max_width(30);
@@ -144,6 +454,36 @@ impl ObjectListPage {
             let (cursor_x, cursor_y) = self.filter_input_state.cursor();
             f.set_cursor(cursor_x, cursor_y);
         }
+
+        if let ViewState::ExportDialog(state) = &self.view_state {
+            let export_dialog = ExportDialog::new(*state);
+            f.render_widget(export_dialog, area);
+        }
+
+        if let ViewState::PeekDialog(state) = &self.view_state {
+            let peek_dialog = PeekDialog::new(state);
+            f.render_widget(peek_dialog, area);
+        }
+
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            let multipart_uploads_dialog = MultipartUploadsDialog::default();
+            f.render_stateful_widget(multipart_uploads_dialog, area, state);
+        }
+
+        if let ViewState::VersionsRangeDialog(state) = &mut self.view_state {
+            let range_dialog = InputDialog::default()
+                .title("Versions modified in (FROM..TO)")
+                .max_width(50);
+            f.render_stateful_widget(range_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+
+        if let ViewState::PrefixVersionsDialog(state) = &mut self.view_state {
+            let prefix_versions_dialog = PrefixVersionsDialog::default();
+            f.render_stateful_widget(prefix_versions_dialog, area, state);
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -154,13 +494,40 @@ impl ObjectListPage {
                         (&["Esc", "Ctrl-c"], "Quit app"),
                         (&["j/k"], "Select item"),
                         (&["g/G"], "Go to top/bottom"),
+                        (&["{n}G"], "Go to n-th item"),
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
+                        (&["Ctrl-d/Ctrl-u"], "Scroll half page forward/backward"),
                         (&["Enter"], "Open file or folder"),
+                        (&["i"], "Peek file metadata or count objects in folder"),
+                        (&["Space"], "Mark file to download (Esc to clear)"),
+                        (&["s"], "Download marked files"),
+                        (&["P"], "Toggle preview pane"),
                         (&["Backspace"], "Go back to prev folder"),
                         (&["~"], "Go back to bucket list"),
+                        (&["^"], "Go back to top folder of bucket"),
                         (&["/"], "Filter bucket list"),
+                        (&["t"], "Toggle type filter (all/files/dirs)"),
+                        (&["K"], "Toggle full key/name display"),
+                        (&["."], "Toggle flat/hierarchical listing"),
+                        (&["m"], "Load next page of partially loaded list"),
+                        (&["Ctrl-r"], "Reload list"),
+                        (&["E"], "Export listing"),
+                        (&["M"], "Show incomplete multipart uploads"),
+                        (&["N"], "Go to most recently modified file"),
+                        (
+                            &["V"],
+                            "Show versions under the folder modified in a time range",
+                        ),
+                        (&["y"], "Copy S3 URI to clipboard"),
+                        (&["Y"], "Copy key of selected item to clipboard"),
+                        (&["T"], "Toggle relative/absolute time"),
                         (&["x"], "Open management console in browser"),
+                        (&["X"], "Copy management console URL to clipboard"),
+                        (&["Ctrl-n"], "Open new tab"),
+                        (&["Ctrl-t"], "Switch to next tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["Ctrl-y"], "Copy notification to clipboard"),
                     ]
                 } else {
                     &[
@@ -168,13 +535,40 @@ impl ObjectListPage {
                         (&["Esc"], "Clear filter"),
                         (&["j/k"], "Select item"),
                         (&["g/G"], "Go to top/bottom"),
+                        (&["{n}G"], "Go to n-th item"),
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
+                        (&["Ctrl-d/Ctrl-u"], "Scroll half page forward/backward"),
                         (&["Enter"], "Open file or folder"),
+                        (&["i"], "Peek file metadata or count objects in folder"),
+                        (&["Space"], "Mark file to download (Esc to clear)"),
+                        (&["s"], "Download marked files"),
+                        (&["P"], "Toggle preview pane"),
                         (&["Backspace"], "Go back to prev folder"),
                         (&["~"], "Go back to bucket list"),
+                        (&["^"], "Go back to top folder of bucket"),
                         (&["/"], "Filter bucket list"),
+                        (&["t"], "Toggle type filter (all/files/dirs)"),
+                        (&["K"], "Toggle full key/name display"),
+                        (&["."], "Toggle flat/hierarchical listing"),
+                        (&["m"], "Load next page of partially loaded list"),
+                        (&["Ctrl-r"], "Reload list"),
+                        (&["E"], "Export listing"),
+                        (&["M"], "Show incomplete multipart uploads"),
+                        (&["N"], "Go to most recently modified file"),
+                        (
+                            &["V"],
+                            "Show versions under the folder modified in a time range",
+                        ),
+                        (&["y"], "Copy S3 URI to clipboard"),
+                        (&["Y"], "Copy key of selected item to clipboard"),
+                        (&["T"], "Toggle relative/absolute time"),
                         (&["x"], "Open management console in browser"),
+                        (&["X"], "Copy management console URL to clipboard"),
+                        (&["Ctrl-n"], "Open new tab"),
+                        (&["Ctrl-t"], "Switch to next tab"),
+                        (&["Ctrl-w"], "Close current tab"),
+                        (&["Ctrl-y"], "Copy notification to clipboard"),
                     ]
                 }
             }
@@ -183,8 +577,43 @@ impl ObjectListPage {
                 (&["Esc"], "Close filter dialog"),
                 (&["Enter"], "Apply filter"),
             ],
+            ViewState::ExportDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close export dialog"),
+                (&["j/k"], "Select format"),
+                (&["Enter"], "Export listing"),
+            ],
+            ViewState::PeekDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "i"], "Close peek dialog"),
+            ],
+            ViewState::MultipartUploadsDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "M"], "Close multipart uploads dialog"),
+                (&["j/k"], "Select upload"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["d"], "Abort selected upload"),
+            ],
+            ViewState::VersionsRangeDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close dialog"),
+                (
+                    &["Enter"],
+                    "Show versions (e.g. 2024-01-02..2024-01-03 12:00)",
+                ),
+            ],
+            ViewState::PrefixVersionsDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "V"], "Close versions dialog"),
+                (&["j/k"], "Select version"),
+                (&["g/G"], "Go to top/bottom"),
+                (&["Enter"], "Open version in object detail"),
+            ],
         };
-        build_helps(helps)
+        build_helps(&filter_management_console_helps(
+            helps,
+            is_management_console_enabled(),
+        ))
     }
 
     pub fn short_helps(&self) -> Vec<(String, usize)> {
@@ -217,6 +646,30 @@ impl ObjectListPage {
                 (&["Enter"], "Filter", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::ExportDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Export", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::PeekDialog(_) => &[(&["Esc"], "Close", 1), (&["?"], "Help", 0)],
+            ViewState::MultipartUploadsDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["d"], "Abort", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::VersionsRangeDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Show", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::PrefixVersionsDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Open", 1),
+                (&["?"], "Help", 0),
+            ],
         };
         build_short_helps(helps)
     }
@@ -239,12 +692,38 @@ impl ObjectListPage {
         self.list_state.select_last();
     }
 
+    fn select_index(&mut self, index: usize) {
+        self.list_state.select_index(index);
+    }
+
+    fn select_newest(&mut self) {
+        let newest = newest_index(&self.object_items, &self.filtered_indices);
+        if let Some(index) = newest.and_then(|i| self.filtered_indices.iter().position(|&j| j == i))
+        {
+            self.list_state.select(index);
+        }
+    }
+
     fn select_next_page(&mut self) {
-        self.list_state.select_next_page();
+        match self.list_config.page_size {
+            Some(n) => self.list_state.select_next_by(n.max(1)),
+            None => self.list_state.select_next_page(),
+        }
     }
 
     fn select_prev_page(&mut self) {
-        self.list_state.select_prev_page();
+        match self.list_config.page_size {
+            Some(n) => self.list_state.select_prev_by(n.max(1)),
+            None => self.list_state.select_prev_page(),
+        }
+    }
+
+    fn select_next_half_page(&mut self) {
+        self.list_state.select_next_half_page();
+    }
+
+    fn select_prev_half_page(&mut self) {
+        self.list_state.select_prev_half_page();
     }
 
     fn open_filter_dialog(&mut self) {
@@ -256,6 +735,164 @@ impl ObjectListPage {
         self.reset_filter();
     }
 
+    fn open_export_dialog(&mut self) {
+        self.view_state = ViewState::ExportDialog(ExportDialogState::default());
+    }
+
+    fn close_export_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn toggle_type_filter(&mut self) {
+        self.type_filter = self.type_filter.next();
+
+        self.update_filtered_indices();
+    }
+
+    // only files can be marked, as folders are not downloaded
+    fn toggle_mark(&mut self) {
+        let i = self.filtered_indices[self.list_state.selected];
+        if let ObjectItem::File { .. } = self.object_items[i] {
+            if !self.marked_indices.remove(&i) {
+                self.marked_indices.insert(i);
+            }
+        }
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked_indices.clear();
+    }
+
+    pub fn unmark_items(&mut self, names: &[&str]) {
+        let items = &self.object_items;
+        self.marked_indices
+            .retain(|&i| !names.contains(&items[i].name()));
+    }
+
+    fn marked_items(&self) -> Vec<ObjectItem> {
+        let mut indices: Vec<usize> = self.marked_indices.iter().copied().collect();
+        indices.sort();
+        indices
+            .into_iter()
+            .map(|i| self.object_items[i].clone())
+            .collect()
+    }
+
+    fn open_peek_dialog(&mut self) {
+        match self.current_selected_item() {
+            ObjectItem::File { name, .. } => {
+                self.view_state = ViewState::PeekDialog(PeekDialogState::new(name));
+                self.tx.send(AppEventType::PeekObject);
+            }
+            ObjectItem::Dir { .. } => {
+                // a dir has no metadata, so the objects under it are counted instead
+                self.tx.send(AppEventType::ObjectListCountObjects);
+            }
+        }
+    }
+
+    pub fn close_peek_dialog(&mut self) {
+        if let ViewState::PeekDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_peek_detail(&mut self, detail: FileDetail) {
+        if let ViewState::PeekDialog(state) = &mut self.view_state {
+            // ignore the result for the dialog already closed
+            if state.name() == detail.name {
+                state.set_detail(detail);
+            }
+        }
+    }
+
+    fn open_multipart_uploads_dialog(&mut self) {
+        let state = MultipartUploadsDialogState::default();
+        self.view_state = ViewState::MultipartUploadsDialog(Box::new(state));
+        self.tx.send(AppEventType::LoadMultipartUploads);
+    }
+
+    pub fn close_multipart_uploads_dialog(&mut self) {
+        if let ViewState::MultipartUploadsDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_multipart_uploads(&mut self, bucket: &str, uploads: Vec<MultipartUpload>) {
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            state.set_uploads(bucket, uploads);
+        }
+    }
+
+    pub fn remove_multipart_upload(&mut self, upload_id: &str) {
+        if let ViewState::MultipartUploadsDialog(state) = &mut self.view_state {
+            state.remove_upload(upload_id);
+        }
+    }
+
+    fn open_versions_range_dialog(&mut self) {
+        self.view_state = ViewState::VersionsRangeDialog(InputDialogState::default());
+    }
+
+    fn close_versions_range_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_prefix_versions_dialog(&mut self, range: TimeRange) {
+        let state = PrefixVersionsDialogState::default();
+        self.view_state = ViewState::PrefixVersionsDialog(Box::new(state));
+        self.tx
+            .send(AppEventType::ObjectListLoadPrefixVersions(range));
+    }
+
+    pub fn close_prefix_versions_dialog(&mut self) {
+        if let ViewState::PrefixVersionsDialog(_) = self.view_state {
+            self.view_state = ViewState::Default;
+        }
+    }
+
+    pub fn set_prefix_versions(&mut self, prefix: &str, versions: Vec<PrefixVersion>) {
+        if let ViewState::PrefixVersionsDialog(state) = &mut self.view_state {
+            state.set_versions(prefix, versions);
+        }
+    }
+
+    fn toggle_head_preview(&mut self) {
+        self.head_preview = match self.head_preview {
+            Some(_) => None,
+            None => Some(HeadPreviewState::default()),
+        };
+    }
+
+    fn update_head_preview(&mut self) {
+        let target = match self.non_empty().then(|| self.current_selected_item()) {
+            Some(ObjectItem::File {
+                name, size_byte, ..
+            }) => Some((name.clone(), *size_byte)),
+            _ => None,
+        };
+        if let Some(state) = &mut self.head_preview {
+            state.select(target.as_ref().map(|(name, size)| (name.as_str(), *size)));
+        }
+    }
+
+    // returns the name of the file to fetch for the preview pane
+    pub fn poll_head_preview(&mut self, now: Instant) -> Option<String> {
+        self.head_preview.as_mut().and_then(|state| state.poll(now))
+    }
+
+    pub fn set_head_preview(&mut self, name: &str, head: ObjectHead) {
+        if let Some(state) = &mut self.head_preview {
+            state.set_head(name, head);
+        }
+    }
+
+    pub fn set_head_preview_error(&mut self, msg: &str) {
+        if let Some(state) = &mut self.head_preview {
+            state.set_error(msg);
+        }
+    }
+
     fn apply_filter(&mut self) {
         self.view_state = ViewState::Default;
 
@@ -274,11 +911,13 @@ impl ObjectListPage {
             .object_items
             .iter()
             .enumerate()
+            .filter(|(_, item)| self.type_filter.matches(item))
             .filter(|(_, item)| item.name().contains(filter))
             .map(|(idx, _)| idx)
             .collect();
         // reset list state
-        self.list_state = ScrollListState::new(self.filtered_indices.len());
+        self.list_state =
+            ScrollListState::new(self.filtered_indices.len()).wrap(self.list_config.wrap);
     }
 
     pub fn current_selected_item(&self) -> &ObjectItem {
@@ -311,123 +950,325 @@ impl ObjectListPage {
             .collect()
     }
 
+    // the number of items shown, after filtering
+    pub fn item_count(&self) -> usize {
+        self.filtered_indices.len()
+    }
+
     pub fn list_state(&self) -> ScrollListState {
         self.list_state
     }
 
-    fn non_empty(&self) -> bool {
-        !self.filtered_indices.is_empty()
+    pub fn filter(&self) -> &str {
+        self.filter_input_state.input()
     }
-}
 
-fn build_list_items<'a>(
-    current_items: &'a [ObjectItem],
-    filter_indices: &'a [usize],
-    filter: &'a str,
-    offset: usize,
-    selected: usize,
-    area: Rect,
-) -> Vec<ListItem<'a>> {
-    let show_item_count = (area.height as usize) - 2 /* border */;
-    current_items
-        .iter()
-        .enumerate()
-        .filter(|(original_idx, _)| filter_indices.contains(original_idx))
-        .skip(offset)
-        .take(show_item_count)
-        .enumerate()
-        .map(|(idx, (_, item))| build_list_item(item, idx + offset == selected, filter, area))
-        .collect()
-}
+    pub fn is_flat(&self) -> bool {
+        self.flat
+    }
 
-fn build_list_item<'a>(
-    item: &'a ObjectItem,
-    selected: bool,
-    filter: &'a str,
-    area: Rect,
-) -> ListItem<'a> {
-    let line = match item {
-        ObjectItem::Dir { name, .. } => build_object_dir_line(name, filter),
-        ObjectItem::File {
-            name,
-            size_byte,
-            last_modified,
-            ..
-        } => build_object_file_line(name, *size_byte, last_modified, filter, area.width),
-    };
+    pub fn set_flat(&mut self, flat: bool) {
+        self.flat = flat;
+    }
 
-    let style = if selected {
-        Style::default()
-            .bg(SELECTED_COLOR)
-            .fg(SELECTED_ITEM_TEXT_COLOR)
-    } else {
-        Style::default()
-    };
-    ListItem::new(line).style(style)
-}
+    pub fn set_prefix(&mut self, prefix: impl Into<String>) {
+        self.prefix = prefix.into();
+    }
 
-fn build_object_dir_line<'a>(name: &'a str, filter: &'a str) -> Line<'a> {
-    if filter.is_empty() {
-        Line::from(vec![" ".into(), name.bold(), "/".bold(), " ".into()])
-    } else {
-        let (before, highlighted, after) = split_str(name, filter).unwrap();
-        Line::from(vec![
-            " ".into(),
+    // the prefix to show the full keys with, None if only the names are shown
+    pub fn key_prefix(&self) -> Option<&str> {
+        self.full_key.then_some(self.prefix.as_str())
+    }
+
+    pub fn next_token(&self) -> Option<&str> {
+        self.next_token.as_deref()
+    }
+
+    pub fn set_next_token(&mut self, next_token: Option<String>) {
+        self.next_token = next_token;
+    }
+
+    // the loaded items are added after the current ones, keeping the selection
+    pub fn append_items(&mut self, items: Vec<ObjectItem>, next_token: Option<String>) {
+        let ScrollListState {
+            selected, offset, ..
+        } = self.list_state;
+        self.object_items.extend(items);
+        self.next_token = next_token;
+        self.update_filtered_indices();
+        self.list_state.selected = selected;
+        self.list_state.offset = offset;
+    }
+
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter_input_state.clear_input();
+        self.filter_input_state.paste(filter);
+        self.update_filtered_indices();
+    }
+
+    pub fn set_list_state(&mut self, list_state: ScrollListState) {
+        // the state is only valid for the same items without filtering
+        if list_state.selected < self.filtered_indices.len() {
+            self.list_state = list_state;
+        }
+    }
+
+    // the items are replaced with the reloaded ones, keeping the filter and the selected item if it still exists
+    pub fn replace_items(&mut self, items: Vec<ObjectItem>, next_token: Option<String>) {
+        let selected = self
+            .non_empty()
+            .then(|| self.current_selected_item().clone());
+        self.object_items = items;
+        self.next_token = next_token;
+        // the indices point to the old items
+        self.marked_indices.clear();
+        self.update_filtered_indices();
+        match selected {
+            Some(ObjectItem::Dir { name }) => self.select_dir_by_name(&name),
+            Some(ObjectItem::File { name, .. }) => self.select_file_by_name(&name),
+            None => false,
+        };
+    }
+
+    // a file and a dir can have the same name (`foo` and `foo/`), so only dirs are matched
+    pub fn select_dir_by_name(&mut self, name: &str) -> bool {
+        let position = self.filtered_indices.iter().position(
+            |&i| matches!(&self.object_items[i], ObjectItem::Dir { name: n } if n == name),
+        );
+        if let Some(index) = position {
+            self.list_state.select(index);
+        }
+        position.is_some()
+    }
+
+    pub fn select_file_by_name(&mut self, name: &str) -> bool {
+        let position = self.filtered_indices.iter().position(
+            |&i| matches!(&self.object_items[i], ObjectItem::File { name: n, .. } if n == name),
+        );
+        if let Some(index) = position {
+            self.list_state.select(index);
+        }
+        position.is_some()
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.filtered_indices.is_empty()
+    }
+}
+
+// returns the index of object_items of the most recently modified file, dirs have no modified time
+fn newest_index(items: &[ObjectItem], filter_indices: &[usize]) -> Option<usize> {
+    filter_indices
+        .iter()
+        .filter_map(|&i| match &items[i] {
+            ObjectItem::File { last_modified, .. } => Some((i, last_modified)),
+            ObjectItem::Dir { .. } => None,
+        })
+        // reversed so that the first one is taken if there are ties
+        .rev()
+        .max_by_key(|(_, last_modified)| *last_modified)
+        .map(|(i, _)| i)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_list_items<'a>(
+    current_items: &'a [ObjectItem],
+    filter_indices: &'a [usize],
+    filter: &'a str,
+    offset: usize,
+    selected: usize,
+    marked_indices: &HashSet<usize>,
+    newest: Option<usize>,
+    area: Rect,
+    show_numbers: bool,
+    prefix: Option<&str>,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+    let number_w = if show_numbers {
+        digits(filter_indices.len())
+    } else {
+        0
+    };
+    current_items
+        .iter()
+        .enumerate()
+        .filter(|(original_idx, _)| filter_indices.contains(original_idx))
+        .skip(offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(idx, (original_idx, item))| {
+            let selected = idx + offset == selected;
+            let marked = marked_indices.contains(&original_idx);
+            let is_newest = newest == Some(original_idx);
+            let number = show_numbers.then_some((idx + offset + 1, number_w));
+            build_list_item(
+                item, selected, marked, is_newest, filter, area, number, prefix,
+            )
+        })
+        .collect()
+}
+
+// the full keys are shown if the prefix is given, otherwise only the names
+#[allow(clippy::too_many_arguments)]
+fn build_list_item(
+    item: &ObjectItem,
+    selected: bool,
+    marked: bool,
+    newest: bool,
+    filter: &str,
+    area: Rect,
+    number: Option<(usize, usize)>,
+    prefix: Option<&str>,
+) -> ListItem<'static> {
+    let number_w = number.map_or(0, |(_, w)| w + 1 /* space */) as u16;
+    let width = area.width.saturating_sub(number_w);
+    let highlight = highlight_style();
+    let mut line = match (item, prefix) {
+        (ObjectItem::Dir { name, .. }, None) => {
+            build_object_dir_line(name, filter, highlight, false)
+        }
+        (ObjectItem::Dir { name, .. }, Some(prefix)) => {
+            let name_w = (width as usize)
+                .saturating_sub(2 /* spaces */ + 4 /* border + pad */ + 1 /* slash */);
+            let key = truncate_head_with_ellipsis(&format!("{}{}", prefix, name), name_w);
+            build_object_dir_line(&key, filter, highlight, true)
+        }
+        (
+            ObjectItem::File {
+                name,
+                size_byte,
+                last_modified,
+                ..
+            },
+            prefix,
+        ) => {
+            let name = match prefix {
+                Some(prefix) => format!("{}{}", prefix, name),
+                None => name.clone(),
+            };
+            build_object_file_line(
+                &name,
+                *size_byte,
+                last_modified,
+                filter,
+                highlight,
+                width,
+                prefix.is_some(),
+            )
+        }
+    };
+    if marked {
+        // shown in place of the leading space
+        line.spans[0] = "*".fg(MARKED_COLOR).bold();
+    } else if newest {
+        line.spans[0] = "+".fg(NEWEST_COLOR);
+    }
+    if let Some((n, w)) = number {
+        line.spans.insert(1, build_list_number(n, w));
+    }
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+// the name matches the filter, so the last match in a full key is the one in the name.
+// the match can be missing if the full key is truncated.
+fn split_filter_match(
+    name: &str,
+    filter: &str,
+    full_key: bool,
+) -> Option<(String, String, String)> {
+    if filter.is_empty() {
+        None
+    } else if full_key {
+        rsplit_str(name, filter)
+    } else {
+        split_str(name, filter)
+    }
+}
+
+fn build_object_dir_line(
+    name: &str,
+    filter: &str,
+    highlight: Style,
+    full_key: bool,
+) -> Line<'static> {
+    match split_filter_match(name, filter, full_key) {
+        None => Line::from(vec![
+            " ".into(),
+            name.to_string().bold(),
+            "/".bold(),
+            " ".into(),
+        ]),
+        Some((before, highlighted, after)) => Line::from(vec![
+            " ".into(),
             before.bold(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR).bold(),
+            Span::styled(highlighted, highlight).bold(),
             after.bold(),
             "/".bold(),
             " ".into(),
-        ])
+        ]),
     }
 }
 
-fn build_object_file_line<'a>(
-    name: &'a str,
+fn build_object_file_line(
+    name: &str,
     size_byte: usize,
-    last_modified: &'a DateTime<Local>,
-    filter: &'a str,
+    last_modified: &DateTime<Local>,
+    filter: &str,
+    highlight: Style,
     width: u16,
-) -> Line<'a> {
+    full_key: bool,
+) -> Line<'static> {
     let size = format_size_byte(size_byte);
     let date = format_datetime(last_modified);
-    let date_w: usize = 19;
+    let date_w = datetime_width();
     let size_w: usize = 10;
-    let name_w: usize = (width as usize) - date_w - size_w - 10 /* spaces */ - 4 /* border + pad */;
+    let columns_w = date_w + size_w + 8 /* spaces */;
+    let name_w = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border + pad */);
 
+    // only the name is shown if the list is too narrow, e.g. next to the preview pane
+    let show_columns = name_w >= ITEM_MIN_NAME_WIDTH + columns_w;
+    let name_w = if show_columns {
+        name_w - columns_w
+    } else {
+        name_w
+    };
+    // the end of the full key is more informative than the common prefix
+    let name = if full_key {
+        truncate_head_with_ellipsis(name, name_w)
+    } else {
+        name.to_string()
+    };
     let name = format!("{:<name_w$}", name, name_w = name_w);
-    let date = format!("{:<date_w$}", date, date_w = date_w);
-    let size = format!("{:>size_w$}", size, size_w = size_w);
 
-    if filter.is_empty() {
-        Line::from(vec![
-            " ".into(),
-            name.into(),
-            "    ".into(),
-            date.into(),
-            "    ".into(),
-            size.into(),
-            " ".into(),
-        ])
-    } else {
-        let (before, highlighted, after) = split_str(&name, filter).unwrap();
-        Line::from(vec![
-            " ".into(),
-            before.into(),
-            highlighted.fg(HIGHLIGHTED_ITEM_TEXT_COLOR),
-            after.into(),
-            "    ".into(),
-            date.into(),
-            "    ".into(),
-            size.into(),
-            " ".into(),
-        ])
+    let mut spans: Vec<Span> = vec![" ".into()];
+    match split_filter_match(&name, filter, full_key) {
+        None => spans.push(name.into()),
+        Some((before, highlighted, after)) => {
+            spans.push(before.into());
+            spans.push(Span::styled(highlighted, highlight));
+            spans.push(after.into());
+        }
+    }
+    if show_columns {
+        let date = format!("{:<date_w$}", date, date_w = date_w);
+        let size = format!("{:>size_w$}", size, size_w = size_w);
+        spans.extend(["    ".into(), date.into(), "    ".into(), size.into()]);
     }
+    spans.push(" ".into());
+    Line::from(spans)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{event, set_cells};
+    use crate::{event, set_cells, ui::common::set_datetime_format};
 
     use super::*;
     use chrono::NaiveDateTime;
@@ -457,7 +1298,7 @@ mod tests {
                     last_modified: parse_datetime("2023-12-31 09:00:00"),
                 },
             ];
-            let mut page = ObjectListPage::new(items, tx);
+            let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
             let area = Rect::new(0, 0, 60, 10);
             page.render(f, area);
         })?;
@@ -487,6 +1328,87 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_datetime_format() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        set_datetime_format(Some("%b %e %Y %H:%M".to_string()));
+        terminal.draw(|f| {
+            let items = vec![
+                ObjectItem::File {
+                    name: "file1".to_string(),
+                    size_byte: 1024 + 10,
+                    last_modified: parse_datetime("2024-01-02 13:01:02"),
+                },
+                ObjectItem::File {
+                    name: "file2".to_string(),
+                    size_byte: 1024 * 999,
+                    last_modified: parse_datetime("2023-12-31 09:00:00"),
+                },
+            ];
+            let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+        set_datetime_format(None);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  file1                  Jan  2 2024 13:01      1.01 KiB  │",
+            "│  file2                  Dec 31 2023 09:00       999 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_empty() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let mut page = ObjectListPage::new(Vec::new(), ListConfig::default(), tx);
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌──────────────────────────────────────────────────────────┐",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                        No objects                        │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (25..35, [5]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -500,7 +1422,7 @@ mod tests {
                     last_modified: parse_datetime("2024-01-02 13:01:02"),
                 })
                 .collect();
-            let mut page = ObjectListPage::new(items, tx);
+            let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
             let area = Rect::new(0, 0, 60, 10);
             page.render(f, area);
         })?;
@@ -528,6 +1450,506 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_toggle_type_filter() {
+        let (tx, _) = event::new();
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+            ObjectItem::Dir {
+                name: "dir2".to_string(),
+            },
+            ObjectItem::File {
+                name: "file2".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+        assert_eq!(page.filtered_indices, [0, 1, 2, 3]);
+
+        page.toggle_type_filter();
+        assert_eq!(page.filtered_indices, [1, 3]);
+
+        page.toggle_type_filter();
+        assert_eq!(page.filtered_indices, [0, 2]);
+
+        page.toggle_type_filter();
+        assert_eq!(page.filtered_indices, [0, 1, 2, 3]);
+
+        // combined with the text filter
+        page.toggle_type_filter();
+        page.filter_input_state.paste("2");
+        page.apply_filter();
+        assert_eq!(page.filtered_indices, [3]);
+
+        page.reset_filter();
+        assert_eq!(page.filtered_indices, [1, 3]);
+    }
+
+    #[test]
+    fn test_render_with_type_filter() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                ObjectItem::Dir {
+                    name: "dir1".to_string(),
+                },
+                ObjectItem::File {
+                    name: "file1".to_string(),
+                    size_byte: 1024 + 10,
+                    last_modified: parse_datetime("2024-01-02 13:01:02"),
+                },
+            ];
+            let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+            page.toggle_type_filter();
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ Files only ─────────────────────────────────────── 1 / 1 ┐",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_truncated() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+        page.set_next_token(Some("token".to_string()));
+        page.list_state.selected = 1;
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ Showing first 2 of many, m: Load more ──────────── 2 / 2 ┐",
+            "│  dir1/                                                   │",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir item
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        // the banner disappears when the last page is loaded, and the selection is kept
+        let more = vec![ObjectItem::File {
+            name: "file2".to_string(),
+            size_byte: 10,
+            last_modified: parse_datetime("2024-01-02 13:01:02"),
+        }];
+        page.append_items(more, None);
+        assert_eq!(page.list_state.selected, 1);
+        assert_eq!(page.object_items.len(), 3);
+        assert_eq!(page.next_token(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_full_key() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "daily".to_string(),
+            },
+            ObjectItem::File {
+                name: "report.csv".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+        page.set_prefix("logs/2024/january/");
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  daily/                                                  │",
+            "│  report.csv           2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir item
+            (3..9, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        // the keys which do not fit are cut off from the head
+        page.handle_key(KeyEvent::from(KeyCode::Char('K')));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  logs/2024/january/daily/                                │",
+            "│  ...ary/report.csv    2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir item
+            (3..27, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_dir_by_name() {
+        let (tx, _) = event::new();
+
+        let items = vec![
+            ObjectItem::File {
+                name: "foo".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+            ObjectItem::Dir {
+                name: "foo".to_string(),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+
+        assert!(page.select_dir_by_name("foo"));
+        assert_eq!(page.list_state.selected, 1);
+        assert!(!page.select_dir_by_name("bar"));
+    }
+
+    #[test]
+    fn test_toggle_and_clear_marks() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+            ObjectItem::File {
+                name: "file2".to_string(),
+                size_byte: 2048,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+        let area = Rect::new(0, 0, 30, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        // dirs are not marked, but the selection moves down
+        page.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert!(page.marked_indices.is_empty());
+        assert_eq!(page.list_state.selected, 1);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        page.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert_eq!(page.marked_indices, HashSet::from([1, 2]));
+        let marked_items = page.marked_items();
+        let names: Vec<&str> = marked_items.iter().map(|i| i.name()).collect();
+        assert_eq!(names, vec!["file1", "file2"]);
+
+        // toggling again unmarks the file
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        page.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert_eq!(page.marked_indices, HashSet::from([1]));
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 3 / 3 ┐                              ",
+            "│  dir1/                     │                              ",
+            "│ *file1                     │                              ",
+            "│  file2                     │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "└────────────────────────────┘                              ",
+        ]);
+        set_cells! { expected =>
+            // dir name is bold
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // marked indicator
+            (2..3, [2]) => fg: Color::Yellow, modifier: Modifier::BOLD,
+            // selected item
+            (2..28, [3]) => bg: Color::Cyan, fg: Color::Black,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(page.marked_indices.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_newest_index() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+            ObjectItem::File {
+                name: "file2".to_string(),
+                size_byte: 2048,
+                last_modified: parse_datetime("2024-03-04 05:06:07"),
+            },
+            ObjectItem::File {
+                name: "file3".to_string(),
+                size_byte: 4096,
+                last_modified: parse_datetime("2024-02-03 04:05:06"),
+            },
+        ];
+        assert_eq!(newest_index(&items, &[0, 1, 2, 3]), Some(2));
+        assert_eq!(newest_index(&items, &[0, 1, 3]), Some(3));
+        assert_eq!(newest_index(&items, &[0]), None);
+
+        let list_config = ListConfig {
+            mark_newest: true,
+            ..Default::default()
+        };
+        let mut page = ObjectListPage::new(items, list_config, tx);
+        let area = Rect::new(0, 0, 30, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('N')));
+        assert_eq!(page.list_state.selected, 2);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('g')));
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 4 ┐                              ",
+            "│  dir1/                     │                              ",
+            "│  file1                     │                              ",
+            "│ +file2                     │                              ",
+            "│  file3                     │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "│                            │                              ",
+            "└────────────────────────────┘                              ",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // dir name is bold
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // newest indicator
+            (2..3, [3]) => fg: Color::Green,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_head_preview() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let items = vec![
+            ObjectItem::Dir {
+                name: "dir1".to_string(),
+            },
+            ObjectItem::File {
+                name: "file1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+            },
+        ];
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+        let area = Rect::new(0, 0, 60, 10);
+        terminal.draw(|f| page.render(f, area))?;
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('P')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        terminal.draw(|f| page.render(f, area))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 2 / 2 ┐┌Preview─────────────────────┐",
+            "│  dir1/                     ││ Loading...                 │",
+            "│  file1                     ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "└────────────────────────────┘└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir name is bold
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..28, [2]) => bg: Color::Cyan, fg: Color::Black,
+            // loading message
+            (32..42, [1]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_by_number_prefix() {
+        let (tx, _) = event::new();
+
+        let items = (1..=20)
+            .map(|i| ObjectItem::Dir {
+                name: format!("dir{}", i),
+            })
+            .collect();
+        let mut page = ObjectListPage::new(items, ListConfig::default(), tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('1')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('2')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(page.list_state.selected, 11);
+
+        // out of range selects the last item
+        page.handle_key(KeyEvent::from(KeyCode::Char('9')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('9')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(page.list_state.selected, 19);
+
+        // the prefix is discarded by other keys
+        page.handle_key(KeyEvent::from(KeyCode::Char('3')));
+        page.handle_key(KeyEvent::from(KeyCode::Esc));
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(page.list_state.selected, 19);
+        page.handle_key(KeyEvent::from(KeyCode::Char('g')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('3')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(page.list_state.selected, 2);
+    }
+
+    #[test]
+    fn test_build_line_with_highlight_style() {
+        let highlight = Style::default()
+            .fg(Color::Yellow)
+            .bg(Color::Blue)
+            .underlined();
+
+        let line = build_object_dir_line("dir-abc", "ab", highlight, false);
+        assert_eq!(line.spans[2].content, "ab");
+        assert_eq!(line.spans[2].style, highlight.bold());
+
+        let last_modified = parse_datetime("2024-01-02 13:01:02");
+        let line = build_object_file_line(
+            "file-abc.txt",
+            10,
+            &last_modified,
+            "ab",
+            highlight,
+            60,
+            false,
+        );
+        assert_eq!(line.spans[1].content, "file-");
+        assert_eq!(line.spans[2].content, "ab");
+        assert_eq!(line.spans[2].style, highlight);
+        assert_eq!(line.spans[3].style, Style::default());
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -1,56 +1,99 @@
+use std::time::{Duration, Instant};
+
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{layout::Rect, Frame};
 
 use crate::{
-    config::PreviewConfig,
-    event::{AppEventType, Sender},
+    config::{ClipboardConfig, PreviewConfig, PreviewHandler},
+    event::{AppEventType, Sender, TailUpdate},
     key_code, key_code_char,
     object::{FileDetail, RawObject},
     pages::util::{build_helps, build_short_helps},
-    widget::{InputDialog, InputDialogState, TextPreview, TextPreviewState},
+    ui::common::format_size_byte,
+    util::{is_binary_content, ByteRange},
+    widget::{
+        InputDialog, InputDialogState, SaveDialog, SaveDialogState, TextPreview, TextPreviewState,
+    },
 };
 
+const TAIL_INTERVAL: Duration = Duration::from_secs(2);
+
 #[derive(Debug)]
 pub struct ObjectPreviewPage {
     state: TextPreviewState,
+    highlight: bool,
+    tail: Option<TailState>,
+    // only the head of the object is loaded if it is larger than the limit
+    truncated: bool,
+    // only this part of the object is loaded if given
+    range: Option<ByteRange>,
 
     file_detail: FileDetail,
     file_version_id: Option<String>,
     object: RawObject,
     path: String,
+    download_dir: String,
+    clipboard_config: ClipboardConfig,
 
     view_state: ViewState,
 
     tx: Sender,
 }
 
+#[derive(Debug)]
+struct TailState {
+    last_polled: Option<Instant>,
+    polling: bool,
+}
+
 #[derive(Debug, Default)]
 enum ViewState {
     #[default]
     Default,
-    SaveDialog(InputDialogState),
+    SaveDialog(SaveDialogState),
+    GotoLineDialog(InputDialogState),
+    RangeDialog(InputDialogState),
 }
 
 impl ObjectPreviewPage {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         file_detail: FileDetail,
         file_version_id: Option<String>,
         object: RawObject,
         path: String,
+        download_dir: String,
         preview_config: PreviewConfig,
+        clipboard_config: ClipboardConfig,
         tx: Sender,
     ) -> Self {
-        let (state, msg) = TextPreviewState::new(&file_detail, &object, preview_config.highlight);
+        let handler = preview_config.handler(&file_detail.name);
+        if handler == Some(PreviewHandler::Unknown) {
+            tx.send(AppEventType::NotifyWarn(format!(
+                "Unknown preview handler for {}, only text and hex are supported",
+                file_detail.name
+            )));
+        }
+        let (state, msg) =
+            TextPreviewState::new(&file_detail, &object, preview_config.highlight, handler);
         if let Some(msg) = msg {
             tx.send(AppEventType::NotifyWarn(msg));
         }
 
+        let truncated = file_detail.size_byte > preview_config.max_bytes;
+
         Self {
             state,
+            highlight: preview_config.highlight,
+            tail: None,
+            truncated,
+            range: None,
             object,
             file_detail,
             file_version_id,
             path,
+            download_dir,
+            clipboard_config,
             view_state: ViewState::Default,
             tx,
         }
@@ -69,15 +112,18 @@ impl ObjectPreviewPage {
                     self.state.scroll_lines_state.scroll_forward();
                 }
                 key_code_char!('k') => {
+                    self.stop_tail();
                     self.state.scroll_lines_state.scroll_backward();
                 }
                 key_code_char!('f') => {
                     self.state.scroll_lines_state.scroll_page_forward();
                 }
                 key_code_char!('b') => {
+                    self.stop_tail();
                     self.state.scroll_lines_state.scroll_page_backward();
                 }
                 key_code_char!('g') => {
+                    self.stop_tail();
                     self.state.scroll_lines_state.scroll_to_top();
                 }
                 key_code_char!('G') => {
@@ -95,12 +141,34 @@ impl ObjectPreviewPage {
                 key_code_char!('n') => {
                     self.state.scroll_lines_state.toggle_number();
                 }
+                key_code_char!('x') => {
+                    self.state
+                        .toggle_hex(&self.file_detail, &self.object, self.highlight);
+                }
+                key_code_char!(':') => {
+                    self.open_goto_line_dialog();
+                }
+                key_code_char!('r') => {
+                    self.open_range_dialog();
+                }
+                key_code_char!('t') => {
+                    self.toggle_tail();
+                }
+                key_code_char!('c') => {
+                    self.copy_content();
+                }
                 key_code_char!('s') => {
                     self.download();
                 }
                 key_code_char!('S') => {
                     self.open_save_dialog();
                 }
+                key_code_char!('e') => {
+                    self.open_in_external_app();
+                }
+                key_code_char!('O') => {
+                    self.download_and_open();
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -121,6 +189,50 @@ impl ObjectPreviewPage {
                     state.handle_key_event(key);
                 }
             },
+            ViewState::GotoLineDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_goto_line_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    if let Ok(line) = state.input().trim().parse() {
+                        self.tail = None;
+                        self.state.scroll_lines_state.scroll_to_line(line);
+                    }
+                    self.close_goto_line_dialog();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::RangeDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_range_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    match ByteRange::parse(state.input(), self.file_detail.size_byte) {
+                        Ok(range) => {
+                            self.close_range_dialog();
+                            self.tx.send(AppEventType::OpenPreviewRange(
+                                self.file_detail.clone(),
+                                self.file_version_id.clone(),
+                                range,
+                            ));
+                        }
+                        Err(msg) => {
+                            self.tx.send(AppEventType::NotifyWarn(msg));
+                        }
+                    }
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
         }
     }
 
@@ -128,16 +240,37 @@ impl ObjectPreviewPage {
         let preview = TextPreview::new(
             self.file_detail.name.as_str(),
             self.file_version_id.as_deref(),
-        );
+        )
+        .tailing(self.tail.is_some())
+        .truncated_size(self.truncated.then_some(self.object.bytes.len()))
+        .range(self.range.map(|r| (r.offset, r.end())));
         f.render_stateful_widget(preview, area, &mut self.state);
 
         if let ViewState::SaveDialog(state) = &mut self.view_state {
-            let save_dialog = InputDialog::default().title("Save As").max_width(40);
+            let save_dialog = SaveDialog::default();
             f.render_stateful_widget(save_dialog, area, state);
 
             let (cursor_x, cursor_y) = state.cursor();
             f.set_cursor(cursor_x, cursor_y);
         }
+
+        if let ViewState::GotoLineDialog(state) = &mut self.view_state {
+            let goto_line_dialog = InputDialog::default().title("Go to Line").max_width(20);
+            f.render_stateful_widget(goto_line_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
+
+        if let ViewState::RangeDialog(state) = &mut self.view_state {
+            let range_dialog = InputDialog::default()
+                .title("Preview bytes (OFFSET+LENGTH)")
+                .max_width(40);
+            f.render_stateful_widget(range_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor(cursor_x, cursor_y);
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -150,15 +283,33 @@ impl ObjectPreviewPage {
                 (&["h/l"], "Scroll left/right"),
                 (&["w"], "Toggle wrap"),
                 (&["n"], "Toggle number"),
+                (&["x"], "Toggle hex view"),
+                (&[":"], "Go to line"),
+                (&["r"], "Preview bytes at offset"),
+                (&["t"], "Toggle tail (follow appended content)"),
+                (&["c"], "Copy content to clipboard"),
                 (&["Backspace"], "Close preview"),
                 (&["s"], "Download object"),
                 (&["S"], "Download object as"),
+                (&["O"], "Download object and open it"),
+                (&["e"], "Open object in external app"),
             ],
             ViewState::SaveDialog(_) => &[
                 (&["Ctrl-c"], "Quit app"),
                 (&["Esc"], "Close save dialog"),
+                (&["Tab"], "Complete path"),
                 (&["Enter"], "Download object"),
             ],
+            ViewState::GotoLineDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close dialog"),
+                (&["Enter"], "Go to line"),
+            ],
+            ViewState::RangeDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close dialog"),
+                (&["Enter"], "Preview bytes"),
+            ],
         };
 
         build_helps(helps)
@@ -176,9 +327,20 @@ impl ObjectPreviewPage {
             ],
             ViewState::SaveDialog(_) => &[
                 (&["Esc"], "Close", 2),
+                (&["Tab"], "Complete", 3),
                 (&["Enter"], "Download", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::GotoLineDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Go", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::RangeDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Preview", 1),
+                (&["?"], "Help", 0),
+            ],
         };
 
         build_short_helps(helps)
@@ -186,21 +348,196 @@ impl ObjectPreviewPage {
 }
 
 impl ObjectPreviewPage {
+    fn toggle_tail(&mut self) {
+        if self.tail.is_some() {
+            self.tail = None;
+            return;
+        }
+        if self.file_version_id.is_some() {
+            let msg = "Tail is not available for a specific version".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if self.range.is_some() {
+            let msg = "Tail is not available for a byte range preview".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if self.truncated {
+            // the rest of the object would be loaded as appended content
+            let msg = "Tail is not available for a truncated preview".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.tail = Some(TailState {
+            last_polled: None,
+            polling: false,
+        });
+        self.state.scroll_lines_state.scroll_to_last_page();
+    }
+
+    pub fn stop_tail(&mut self) {
+        self.tail = None;
+    }
+
+    // returns true if the object should be checked for changes now
+    pub fn poll_tail(&mut self, now: Instant) -> bool {
+        match &mut self.tail {
+            Some(tail) if !tail.polling => {
+                let due = tail
+                    .last_polled
+                    .is_none_or(|t| now.duration_since(t) >= TAIL_INTERVAL);
+                if due {
+                    tail.polling = true;
+                }
+                due
+            }
+            _ => false,
+        }
+    }
+
+    pub fn tail_target(&self) -> (String, usize) {
+        (self.file_detail.name.clone(), self.object.bytes.len())
+    }
+
+    pub fn complete_tail(&mut self, name: &str, update: TailUpdate) {
+        if name != self.file_detail.name {
+            return;
+        }
+        if let Some(tail) = &mut self.tail {
+            tail.polling = false;
+            tail.last_polled = Some(Instant::now());
+        }
+
+        match update {
+            TailUpdate::Unchanged => return,
+            TailUpdate::Appended(bytes) => self.object.bytes.extend(bytes),
+            TailUpdate::Reloaded(object) => self.object = object,
+        }
+        self.state
+            .update(&self.file_detail, &self.object, self.highlight);
+        // the content is still updated if tail was stopped while loading, but not scrolled
+        if self.tail.is_some() {
+            self.state.scroll_lines_state.scroll_to_last_page();
+        }
+    }
+
     fn open_save_dialog(&mut self) {
-        self.view_state = ViewState::SaveDialog(InputDialogState::default());
+        let state = SaveDialogState::new(self.download_dir.clone());
+        self.view_state = ViewState::SaveDialog(state);
     }
 
     pub fn close_save_dialog(&mut self) {
         self.view_state = ViewState::Default;
     }
 
+    fn open_goto_line_dialog(&mut self) {
+        self.view_state = ViewState::GotoLineDialog(InputDialogState::default());
+    }
+
+    fn close_goto_line_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_range_dialog(&mut self) {
+        self.view_state = ViewState::RangeDialog(InputDialogState::default());
+    }
+
+    fn close_range_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    // the whole range is loaded, so it is not truncated even if larger than the limit
+    pub fn set_range(&mut self, range: ByteRange) {
+        self.range = Some(range);
+        self.truncated = false;
+        self.state.set_offset(range.offset);
+    }
+
+    fn copy_content(&self) {
+        match self.content_to_copy() {
+            Ok(value) => {
+                let name = format!("{} ({} bytes)", self.file_detail.name, value.len());
+                self.tx.send(AppEventType::CopyToClipboard(name, value));
+            }
+            Err(msg) => {
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
+    // only small text objects, large ones are better downloaded than pasted somewhere
+    fn content_to_copy(&self) -> Result<String, String> {
+        let bytes = &self.object.bytes;
+        let max_size = self.clipboard_config.content_max_size;
+        if bytes.len() > max_size {
+            let msg = format!(
+                "Object larger than {} cannot be copied to clipboard",
+                format_size_byte(max_size)
+            );
+            return Err(msg);
+        }
+        if self.truncated {
+            return Err("Truncated preview cannot be copied to clipboard".into());
+        }
+        if is_binary_content(&self.file_detail.content_type, bytes) {
+            return Err("Binary object cannot be copied to clipboard".into());
+        }
+        let Ok(mut content) = String::from_utf8(bytes.clone()) else {
+            return Err("Object is not valid UTF-8 and cannot be copied to clipboard".into());
+        };
+        if self.clipboard_config.strip_trailing_newline {
+            let len = content.trim_end_matches(['\n', '\r']).len();
+            content.truncate(len);
+        }
+        Ok(content)
+    }
+
     fn download(&self) {
+        if self.truncated {
+            // only the head has been loaded, so download the whole object in the same way as the detail
+            let file_detail = self.file_detail.clone();
+            let version_id = self.file_version_id.clone();
+            self.tx
+                .send(AppEventType::DetailDownloadObject(file_detail, version_id));
+            return;
+        }
         // object has been already downloaded, so send completion event to save file
         let obj = self.object.clone();
         let path = self.path.clone();
         self.tx.send(AppEventType::PreviewDownloadObject(obj, path));
     }
 
+    fn download_and_open(&self) {
+        if self.truncated {
+            let file_detail = self.file_detail.clone();
+            let version_id = self.file_version_id.clone();
+            self.tx.send(AppEventType::DetailDownloadAndOpenObject(
+                file_detail,
+                version_id,
+            ));
+            return;
+        }
+        let obj = self.object.clone();
+        let path = self.path.clone();
+        self.tx
+            .send(AppEventType::PreviewDownloadAndOpenObject(obj, path));
+    }
+
+    fn open_in_external_app(&self) {
+        if self.truncated {
+            let file_detail = self.file_detail.clone();
+            let version_id = self.file_version_id.clone();
+            self.tx
+                .send(AppEventType::OpenInExternalApp(file_detail, version_id));
+            return;
+        }
+        let obj = self.object.clone();
+        let name = self.file_detail.name.clone();
+        self.tx
+            .send(AppEventType::PreviewOpenInExternalApp(obj, name));
+    }
+
     fn download_as(&self, input: String) {
         let input: String = input.trim().into();
         if input.is_empty() {
@@ -225,6 +562,8 @@ mod tests {
     use chrono::{DateTime, Local, NaiveDateTime};
     use itertools::Itertools;
     use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+    use rstest::rstest;
+    use std::collections::BTreeMap;
 
     fn object(ss: &[&str]) -> RawObject {
         RawObject {
@@ -247,16 +586,25 @@ mod tests {
             ];
             let object = object(&preview);
             let file_path = "file.txt".to_string();
+            let download_dir = "download".to_string();
             let preview_config = PreviewConfig::default();
-            let mut page =
-                ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+            let mut page = ObjectPreviewPage::new(
+                file_detail,
+                None,
+                object,
+                file_path,
+                download_dir,
+                preview_config,
+                ClipboardConfig::default(),
+                tx,
+            );
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
 
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
-            "┌Preview [file.txt]──────────┐",
+            "┌Preview [file.txt (latest)]─┐",
             "│ 1 Hello, world!            │",
             "│ 2 This is a test file.     │",
             "│ 3 This file is used for    │",
@@ -276,6 +624,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_specific_version() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        // wider than the other tests to fit the version id in the title
+        let mut terminal = Terminal::new(TestBackend::new(70, 5))?;
+
+        terminal.draw(|f| {
+            let object = object(&["Hello, world!"]);
+            let mut page = ObjectPreviewPage::new(
+                file_detail(),
+                Some("3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrH".to_string()),
+                object,
+                "file.txt".to_string(),
+                "download".to_string(),
+                PreviewConfig::default(),
+                ClipboardConfig::default(),
+                tx,
+            );
+            let area = Rect::new(0, 0, 70, 5);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt (Version ID: 3HL4kqtJlcpXroDTDmJ+rmSpXd3dIbrH)]───┐",
+            "│ 1 Hello, world!                                                    │",
+            "│                                                                    │",
+            "│                                                                    │",
+            "└────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -286,16 +673,25 @@ mod tests {
             let preview = ["Hello, world!"; 20];
             let object = object(&preview);
             let file_path = "file.txt".to_string();
+            let download_dir = "download".to_string();
             let preview_config = PreviewConfig::default();
-            let mut page =
-                ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+            let mut page = ObjectPreviewPage::new(
+                file_detail,
+                None,
+                object,
+                file_path,
+                download_dir,
+                preview_config,
+                ClipboardConfig::default(),
+                tx,
+            );
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
         })?;
 
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
-            "┌Preview [file.txt]──────────┐",
+            "┌Preview [file.txt (latest)]─┐",
             "│  1 Hello, world!           │",
             "│  2 Hello, world!           │",
             "│  3 Hello, world!           │",
@@ -330,9 +726,18 @@ mod tests {
             ];
             let object = object(&preview);
             let file_path = "file.txt".to_string();
+            let download_dir = "download".to_string();
             let preview_config = PreviewConfig::default();
-            let mut page =
-                ObjectPreviewPage::new(file_detail, None, object, file_path, preview_config, tx);
+            let mut page = ObjectPreviewPage::new(
+                file_detail,
+                None,
+                object,
+                file_path,
+                download_dir,
+                preview_config,
+                ClipboardConfig::default(),
+                tx,
+            );
             page.open_save_dialog();
             let area = Rect::new(0, 0, 30, 10);
             page.render(f, area);
@@ -340,12 +745,12 @@ mod tests {
 
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
-            "┌Preview [file.txt]──────────┐",
+            "┌Preview [file.txt (latest)]─┐",
             "│ 1 Hello, world!            │",
             "│ 2 This is a test file.     │",
             "│ ╭Save As─────────────────╮ │",
             "│ │                        │ │",
-            "│ ╰────────────────────────╯ │",
+            "│ ╰download/───────────────╯ │",
             "│                            │",
             "│                            │",
             "│                            │",
@@ -353,6 +758,8 @@ mod tests {
         ]);
         set_cells! { expected =>
             ([2], 1..3) => fg: Color::DarkGray,
+            // hint
+            (3..12, [5]) => fg: Color::DarkGray,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -367,6 +774,208 @@ mod tests {
             .unwrap()
     }
 
+    #[test]
+    fn test_render_tail_appended() -> std::io::Result<()> {
+        let (tx, _) = event::new();
+        // wider than the other tests to fit the title
+        let mut terminal = Terminal::new(TestBackend::new(40, 10))?;
+
+        let object = object(&["line 1", "line 2", "line 3"]);
+        let mut page = ObjectPreviewPage::new(
+            file_detail(),
+            None,
+            object,
+            "file.txt".to_string(),
+            "download".to_string(),
+            PreviewConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('t')));
+
+        let now = Instant::now();
+        assert!(page.poll_tail(now));
+        // the previous poll has not completed yet
+        assert!(!page.poll_tail(now + TAIL_INTERVAL));
+        assert_eq!(page.tail_target(), ("file.txt".to_string(), 20));
+
+        let appended = (4..=10).map(|i| format!("\nline {}", i)).join("");
+        page.complete_tail("file.txt", TailUpdate::Appended(appended.into_bytes()));
+
+        terminal.draw(|f| {
+            let area = Rect::new(0, 0, 40, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt (latest)] (tailing)─┐",
+            "│  3 line 3                            │",
+            "│  4 line 4                            │",
+            "│  5 line 5                            │",
+            "│  6 line 6                            │",
+            "│  7 line 7                            │",
+            "│  8 line 8                            │",
+            "│  9 line 9                            │",
+            "│ 10 line 10                           │",
+            "└──────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..4, 1..9) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_stops_on_scroll_up() {
+        let (tx, _) = event::new();
+
+        let mut page = ObjectPreviewPage::new(
+            file_detail(),
+            None,
+            object(&["line 1", "line 2"]),
+            "file.txt".to_string(),
+            "download".to_string(),
+            PreviewConfig::default(),
+            ClipboardConfig::default(),
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('t')));
+        assert!(page.tail.is_some());
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert!(page.tail.is_none());
+        assert!(!page.poll_tail(Instant::now()));
+
+        // replaced with a smaller object
+        let reloaded = object(&["new"]);
+        page.complete_tail("file.txt", TailUpdate::Reloaded(reloaded));
+        assert_eq!(page.tail_target(), ("file.txt".to_string(), 3));
+    }
+
+    #[rstest]
+    #[case(false, 64, "line 1\nline 2\n")]
+    #[case(true, 64, "line 1\nline 2")]
+    #[case(false, 10, "")]
+    fn test_copy_content(
+        #[case] strip_trailing_newline: bool,
+        #[case] content_max_size: usize,
+        #[case] expected: &str,
+    ) {
+        let (tx, rx) = event::new();
+
+        let clipboard_config = ClipboardConfig {
+            content_max_size,
+            strip_trailing_newline,
+            ..Default::default()
+        };
+        let mut page = ObjectPreviewPage::new(
+            file_detail(),
+            None,
+            object(&["line 1", "line 2", ""]),
+            "file.txt".to_string(),
+            "download".to_string(),
+            PreviewConfig::default(),
+            clipboard_config,
+            tx,
+        );
+        page.handle_key(KeyEvent::from(KeyCode::Char('c')));
+
+        let event = rx.recv_find(|event| {
+            matches!(
+                event,
+                AppEventType::CopyToClipboard(_, _) | AppEventType::NotifyWarn(_)
+            )
+        });
+        match event {
+            AppEventType::CopyToClipboard(name, value) => {
+                assert_eq!(name, format!("file.txt ({} bytes)", expected.len()));
+                assert_eq!(value, expected);
+            }
+            // too large to copy
+            AppEventType::NotifyWarn(_) => assert_eq!(expected, ""),
+            _ => panic!("content is not copied"),
+        }
+    }
+
+    #[test]
+    fn test_unknown_handler() {
+        let (tx, rx) = event::new();
+        let preview_config = PreviewConfig {
+            handlers: BTreeMap::from([("txt".to_string(), PreviewHandler::Unknown)]),
+            ..Default::default()
+        };
+        ObjectPreviewPage::new(
+            file_detail(),
+            None,
+            object(&["Hello, world!"]),
+            "file.txt".to_string(),
+            "download".to_string(),
+            preview_config,
+            ClipboardConfig::default(),
+            tx,
+        );
+
+        match rx.recv_find(|event| matches!(event, AppEventType::NotifyWarn(_))) {
+            AppEventType::NotifyWarn(msg) => assert_eq!(
+                msg,
+                "Unknown preview handler for file.txt, only text and hex are supported"
+            ),
+            _ => panic!("unknown handler is not warned"),
+        }
+    }
+
+    #[test]
+    fn test_truncated_preview() -> std::io::Result<()> {
+        let (tx, rx) = event::new();
+        let mut terminal = Terminal::new(TestBackend::new(50, 4))?;
+
+        // only the head of the object has been loaded
+        let preview_config = PreviewConfig {
+            max_bytes: 16,
+            ..Default::default()
+        };
+        let mut page = ObjectPreviewPage::new(
+            file_detail(),
+            None,
+            object(&["Hello, world!", "Thank"]),
+            "file.txt".to_string(),
+            "download".to_string(),
+            preview_config,
+            ClipboardConfig::default(),
+            tx,
+        );
+        terminal.draw(|f| page.render(f, Rect::new(0, 0, 50, 4)))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview [file.txt (latest)] (first 19 B only)───┐",
+            "│ 1 Hello, world!                                │",
+            "│ 2 Thank                                        │",
+            "└────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2], [1, 2]) => fg: Color::DarkGray,
+        }
+        terminal.backend().assert_buffer(&expected);
+
+        // the whole object is downloaded instead of the loaded bytes
+        page.handle_key(KeyEvent::from(KeyCode::Char('s')));
+        let event = rx.recv_find(|event| {
+            matches!(
+                event,
+                AppEventType::DetailDownloadObject(_, _)
+                    | AppEventType::PreviewDownloadObject(_, _)
+            )
+        });
+        assert!(matches!(event, AppEventType::DetailDownloadObject(_, None)));
+
+        Ok(())
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(30, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -380,12 +989,22 @@ mod tests {
             size_byte: 1024 + 10,
             last_modified: parse_datetime("2024-01-02 13:01:02"),
             e_tag: "bef684de-a260-48a4-8178-8a535ecccadb".to_string(),
+            checksum_algorithm: "".to_string(),
+            checksum: "".to_string(),
+            version_id: "".to_string(),
             content_type: "text/plain".to_string(),
+            content_encoding: "".to_string(),
+            content_disposition: "".to_string(),
+            cache_control: "".to_string(),
             storage_class: "STANDARD".to_string(),
+            server_side_encryption: "".to_string(),
+            sse_kms_key_id: "".to_string(),
+            bucket: "bucket-1".to_string(),
             key: "file.txt".to_string(),
             s3_uri: "s3://bucket-1/file.txt".to_string(),
             arn: "arn:aws:s3:::bucket-1/file.txt".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file.txt".to_string(),
+            public: None,
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    config::PreviewConfig,
+    config::{ClipboardConfig, DetailConfig, ListConfig, PreviewConfig},
     event::Sender,
     object::{BucketItem, FileDetail, FileVersion, ObjectItem, RawObject},
     pages::{
@@ -25,19 +25,32 @@ impl Page {
         Self::Initializing(Box::new(InitializingPage::new(tx)))
     }
 
-    pub fn of_bucket_list(bucket_items: Vec<BucketItem>, tx: Sender) -> Self {
-        Self::BucketList(Box::new(BucketListPage::new(bucket_items, tx)))
+    pub fn of_bucket_list(
+        bucket_items: Vec<BucketItem>,
+        list_config: ListConfig,
+        tx: Sender,
+    ) -> Self {
+        Self::BucketList(Box::new(BucketListPage::new(bucket_items, list_config, tx)))
     }
 
-    pub fn of_object_list(object_items: Vec<ObjectItem>, tx: Sender) -> Self {
-        Self::ObjectList(Box::new(ObjectListPage::new(object_items, tx)))
+    pub fn of_object_list(
+        object_items: Vec<ObjectItem>,
+        list_config: ListConfig,
+        tx: Sender,
+    ) -> Self {
+        Self::ObjectList(Box::new(ObjectListPage::new(object_items, list_config, tx)))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn of_object_detail(
         file_detail: FileDetail,
         file_versions: Vec<FileVersion>,
         object_items: Vec<ObjectItem>,
         list_state: ScrollListState,
+        download_dir: String,
+        list_config: ListConfig,
+        detail_config: DetailConfig,
+        clipboard_config: ClipboardConfig,
         tx: Sender,
     ) -> Self {
         Self::ObjectDetail(Box::new(ObjectDetailPage::new(
@@ -45,16 +58,23 @@ impl Page {
             file_versions,
             object_items,
             list_state,
+            download_dir,
+            list_config,
+            detail_config,
+            clipboard_config,
             tx,
         )))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn of_object_preview(
         file_detail: FileDetail,
         file_version_id: Option<String>,
         object: RawObject,
         path: String,
+        download_dir: String,
         preview_config: PreviewConfig,
+        clipboard_config: ClipboardConfig,
         tx: Sender,
     ) -> Self {
         Self::ObjectPreview(Box::new(ObjectPreviewPage::new(
@@ -62,7 +82,9 @@ impl Page {
             file_version_id,
             object,
             path,
+            download_dir,
             preview_config,
+            clipboard_config,
             tx,
         )))
     }
@@ -85,6 +107,13 @@ impl Page {
         }
     }
 
+    pub fn as_mut_object_list(&mut self) -> &mut ObjectListPage {
+        match self {
+            Self::ObjectList(page) => &mut *page,
+            page => panic!("Page is not ObjectList: {:?}", page),
+        }
+    }
+
     pub fn as_mut_object_detail(&mut self) -> &mut ObjectDetailPage {
         match self {
             Self::ObjectDetail(page) => &mut *page,
@@ -140,7 +169,11 @@ impl PageStack {
         self.stack.first().unwrap()
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Page> {
+    pub fn head_mut(&mut self) -> &mut Page {
+        self.stack.first_mut().unwrap()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Page> {
         self.stack.iter()
     }
 }
@@ -1,3 +1,12 @@
+use ratatui::{
+    style::{Color, Stylize},
+    text::Span,
+};
+
+const LIST_NUMBER_COLOR: Color = Color::DarkGray;
+
+const MANAGEMENT_CONSOLE_KEYS: [&str; 3] = ["x", "X", "U"];
+
 pub fn build_helps(helps: &[(&[&str], &str)]) -> Vec<String> {
     helps
         .iter()
@@ -13,6 +22,18 @@ pub fn build_helps(helps: &[(&[&str], &str)]) -> Vec<String> {
         .collect()
 }
 
+// removes the keys to open or copy the management console if it is not available
+pub fn filter_management_console_helps<'a>(
+    helps: &[(&'a [&'a str], &'a str)],
+    enabled: bool,
+) -> Vec<(&'a [&'a str], &'a str)> {
+    helps
+        .iter()
+        .filter(|(keys, _)| enabled || !keys.iter().all(|k| MANAGEMENT_CONSOLE_KEYS.contains(k)))
+        .copied()
+        .collect()
+}
+
 pub fn build_short_helps(helps: &[(&[&str], &str, usize)]) -> Vec<(String, usize)> {
     helps
         .iter()
@@ -27,3 +48,51 @@ pub fn build_short_helps(helps: &[(&[&str], &str, usize)]) -> Vec<(String, usize
         })
         .collect()
 }
+
+// digits typed before a key are used as its count, like `42G` in vim
+pub fn push_number_prefix(prefix: Option<usize>, c: char) -> usize {
+    let n = c.to_digit(10).unwrap_or_default() as usize;
+    prefix
+        .unwrap_or_default()
+        .saturating_mul(10)
+        .saturating_add(n)
+}
+
+pub fn build_list_number(number: usize, width: usize) -> Span<'static> {
+    format!("{:>width$} ", number, width = width).fg(LIST_NUMBER_COLOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_number_prefix() {
+        let n = push_number_prefix(None, '4');
+        assert_eq!(n, 4);
+        let n = push_number_prefix(Some(n), '2');
+        assert_eq!(n, 42);
+        let n = push_number_prefix(Some(n), '0');
+        assert_eq!(n, 420);
+        assert_eq!(push_number_prefix(Some(usize::MAX), '9'), usize::MAX);
+    }
+
+    #[test]
+    fn test_filter_management_console_helps() {
+        let helps: &[(&[&str], &str)] = &[
+            (&["y"], "Copy S3 URI to clipboard"),
+            (&["x"], "Open management console in browser"),
+            (&["X"], "Copy management console URL to clipboard"),
+            (&["?"], "Show help"),
+        ];
+
+        let actual = build_helps(&filter_management_console_helps(helps, true));
+        assert_eq!(actual.len(), 4);
+
+        let actual = build_helps(&filter_management_console_helps(helps, false));
+        assert_eq!(
+            actual,
+            vec!["<y>: Copy S3 URI to clipboard", "<?>: Show help"]
+        );
+    }
+}
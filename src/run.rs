@@ -3,7 +3,7 @@ use ratatui::{backend::Backend, Terminal};
 use std::io::Result;
 
 use crate::{
-    app::{App, Notification},
+    app::{App, AppDialog, Notification},
     event::{AppEventType, Receiver},
     key_code, key_code_char,
     pages::page::Page,
@@ -18,12 +18,26 @@ pub async fn run<B: Backend>(
     loop {
         terminal.draw(|f| ui::render(f, app))?;
 
-        let event = rx.recv();
-        tracing::debug!("event received: {:?}", event);
+        let event = loop {
+            match rx.recv() {
+                // redraw only while the spinner is shown
+                AppEventType::Tick if !app.app_view_state.is_loading => {
+                    app.tick_tail();
+                    app.tick_head_preview();
+                    continue;
+                }
+                event => break event,
+            }
+        };
+        if !matches!(event, AppEventType::Tick) {
+            tracing::debug!("event received: {:?}", event);
+        }
 
         match event {
             AppEventType::Quit => {
-                return Ok(());
+                if app.request_quit() {
+                    return Ok(());
+                }
             }
             AppEventType::Key(key) => {
                 if matches!(key, key_code_char!('c', Ctrl)) {
@@ -36,6 +50,12 @@ pub async fn run<B: Backend>(
                     continue;
                 }
 
+                if matches!(key, key_code_char!('y', Ctrl)) {
+                    // before the notification is cleared by the key input
+                    app.copy_notification();
+                    continue;
+                }
+
                 if matches!(app.app_view_state.notification, Notification::Error(_)) {
                     if matches!(app.page_stack.current_page(), Page::Initializing(_)) {
                         return Ok(());
@@ -53,11 +73,56 @@ pub async fn run<B: Backend>(
                     app.clear_notification();
                 }
 
+                if let AppDialog::OverwriteConfirm(_) = app.app_view_state.dialog {
+                    match key {
+                        key_code_char!('y') => app.overwrite_downloaded_object(),
+                        key_code_char!('r') => app.rename_downloaded_object(),
+                        key_code_char!('n') | key_code!(KeyCode::Esc) => {
+                            app.cancel_downloaded_object()
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                if let AppDialog::CredentialsExpired = app.app_view_state.dialog {
+                    match key {
+                        key_code_char!('r') => app.reload_credentials(),
+                        key_code_char!('q') => return Ok(()),
+                        key_code!(KeyCode::Esc) => app.close_credentials_expired(),
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                if let AppDialog::QuitConfirm = app.app_view_state.dialog {
+                    if app.handle_quit_confirm_key(key) {
+                        return Ok(());
+                    }
+                    continue;
+                }
+
                 if matches!(key, key_code!(KeyCode::F(12))) {
                     app.dump_app();
                     continue;
                 }
 
+                match key {
+                    key_code_char!('n', Ctrl) => {
+                        app.open_new_tab();
+                        continue;
+                    }
+                    key_code_char!('t', Ctrl) => {
+                        app.next_tab();
+                        continue;
+                    }
+                    key_code_char!('w', Ctrl) => {
+                        app.close_current_tab();
+                        continue;
+                    }
+                    _ => {}
+                }
+
                 match app.page_stack.current_page_mut() {
                     Page::Initializing(page) => page.handle_key(key),
                     Page::BucketList(page) => page.handle_key(key),
@@ -70,12 +135,36 @@ pub async fn run<B: Backend>(
             AppEventType::Resize(width, height) => {
                 app.resize(width, height);
             }
+            AppEventType::Tick => {
+                app.app_view_state.tick();
+            }
             AppEventType::Initialize(client, bucket) => {
                 app.initialize(client, bucket);
             }
             AppEventType::CompleteInitialize(result) => {
                 app.complete_initialize(result);
             }
+            AppEventType::CompleteLoadAccountId(result) => {
+                app.complete_load_account_id(result);
+            }
+            AppEventType::CompleteReloadCredentials(client) => {
+                app.complete_reload_credentials(client);
+            }
+            AppEventType::CompleteLoadBucketRegion(result) => {
+                app.complete_load_bucket_region(result);
+            }
+            AppEventType::CompleteLoadBucketVersioning(result) => {
+                app.complete_load_bucket_versioning(result);
+            }
+            AppEventType::CompleteLoadBucketPublic(result) => {
+                app.complete_load_bucket_public(result);
+            }
+            AppEventType::ReloadBuckets => {
+                app.reload_buckets();
+            }
+            AppEventType::CompleteReloadBuckets(result) => {
+                app.complete_reload_buckets(result);
+            }
             AppEventType::LoadObjects => {
                 app.load_objects();
             }
@@ -88,9 +177,24 @@ pub async fn run<B: Backend>(
             AppEventType::CompleteLoadObject(result) => {
                 app.complete_load_object(result);
             }
+            AppEventType::PeekObject => {
+                app.peek_object();
+            }
+            AppEventType::CompletePeekObject(result) => {
+                app.complete_peek_object(result);
+            }
             AppEventType::DownloadObject(file_detail, version_id) => {
                 app.download_object(file_detail, version_id);
             }
+            AppEventType::DownloadObjects(items) => {
+                app.download_objects(items);
+            }
+            AppEventType::CompleteDownloadObjects(result) => {
+                app.complete_download_objects(result);
+            }
+            AppEventType::DownloadAndOpenObject(file_detail, version_id) => {
+                app.download_and_open_object(file_detail, version_id);
+            }
             AppEventType::DownloadObjectAs(file_detail, input, version_id) => {
                 app.download_object_as(file_detail, input, version_id);
             }
@@ -103,6 +207,18 @@ pub async fn run<B: Backend>(
             AppEventType::CompletePreviewObject(result) => {
                 app.complete_preview_object(result);
             }
+            AppEventType::PreviewObjectRange(file_detail, version_id, range) => {
+                app.preview_object_range(file_detail, version_id, range);
+            }
+            AppEventType::CompletePreviewObjectRange(result) => {
+                app.complete_preview_object_range(result);
+            }
+            AppEventType::CompleteTailObject(result) => {
+                app.complete_tail_object(result);
+            }
+            AppEventType::CompleteLoadObjectHead(result) => {
+                app.complete_load_object_head(result);
+            }
             AppEventType::BucketListMoveDown => {
                 app.bucket_list_move_down();
             }
@@ -115,30 +231,138 @@ pub async fn run<B: Backend>(
             AppEventType::BackToBucketList => {
                 app.back_to_bucket_list();
             }
+            AppEventType::BackToBucketRoot => {
+                app.back_to_bucket_root();
+            }
             AppEventType::OpenPreview(file_detail, version_id) => {
                 app.open_preview(file_detail, version_id);
             }
+            AppEventType::OpenPreviewRange(file_detail, version_id, range) => {
+                app.open_preview_range(file_detail, version_id, range);
+            }
             AppEventType::DetailDownloadObject(file_detail, version_id) => {
                 app.detail_download_object(file_detail, version_id);
             }
+            AppEventType::DetailDownloadAndOpenObject(file_detail, version_id) => {
+                app.detail_download_and_open_object(file_detail, version_id);
+            }
             AppEventType::DetailDownloadObjectAs(file_detail, input, version_id) => {
                 app.detail_download_object_as(file_detail, input, version_id);
             }
             AppEventType::PreviewDownloadObject(obj, path) => {
                 app.preview_download_object(obj, path);
             }
+            AppEventType::PreviewDownloadAndOpenObject(obj, path) => {
+                app.preview_download_and_open_object(obj, path);
+            }
             AppEventType::PreviewDownloadObjectAs(file_detail, input, version_id) => {
                 app.preview_download_object_as(file_detail, input, version_id);
             }
+            AppEventType::OpenInExternalApp(file_detail, version_id) => {
+                app.open_in_external_app(file_detail, version_id);
+            }
+            AppEventType::CompleteOpenInExternalApp(result) => {
+                app.complete_open_in_external_app(result);
+            }
+            AppEventType::PreviewOpenInExternalApp(obj, name) => {
+                app.preview_open_in_external_app(obj, name);
+            }
             AppEventType::BucketListOpenManagementConsole => {
                 app.bucket_list_open_management_console();
             }
+            AppEventType::BucketListCopyManagementConsoleUrl => {
+                app.bucket_list_copy_management_console_url();
+            }
             AppEventType::ObjectListOpenManagementConsole => {
                 app.object_list_open_management_console();
             }
+            AppEventType::ObjectListCopyManagementConsoleUrl => {
+                app.object_list_copy_management_console_url();
+            }
+            AppEventType::BucketListCopyS3Uri => {
+                app.bucket_list_copy_s3_uri();
+            }
+            AppEventType::BucketListLoadPolicy => {
+                app.bucket_list_load_policy();
+            }
+            AppEventType::CompleteBucketListLoadPolicy(result) => {
+                app.complete_bucket_list_load_policy(result);
+            }
+            AppEventType::BucketListLoadProperties => {
+                app.bucket_list_load_properties();
+            }
+            AppEventType::CompleteBucketListLoadProperties(result) => {
+                app.complete_bucket_list_load_properties(result);
+            }
+            AppEventType::ObjectListCopyS3Uri => {
+                app.object_list_copy_s3_uri();
+            }
+            AppEventType::ObjectListToggleFlat => {
+                app.object_list_toggle_flat();
+            }
+            AppEventType::ObjectListLoadMore => {
+                app.object_list_load_more();
+            }
+            AppEventType::CompleteLoadMoreObjects(result) => {
+                app.complete_load_more_objects(result);
+            }
+            AppEventType::ObjectListReload => {
+                app.object_list_reload();
+            }
+            AppEventType::ReloadObjects => {
+                app.reload_objects();
+            }
+            AppEventType::CompleteReloadObjects(result) => {
+                app.complete_reload_objects(result);
+            }
+            AppEventType::ObjectListCopyKey => {
+                app.object_list_copy_key();
+            }
+            AppEventType::ObjectListCountObjects => {
+                app.object_list_count_objects();
+            }
+            AppEventType::CompleteCountObjects(result) => {
+                app.complete_count_objects(result);
+            }
+            AppEventType::ExportListing(format) => {
+                app.export_listing(format);
+            }
             AppEventType::ObjectDetailOpenManagementConsole(name) => {
                 app.object_detail_open_management_console(name);
             }
+            AppEventType::ObjectDetailOpenParentManagementConsole => {
+                app.object_detail_open_parent_management_console();
+            }
+            AppEventType::ObjectDetailCopyManagementConsoleUrl(name) => {
+                app.object_detail_copy_management_console_url(name);
+            }
+            AppEventType::ObjectDetailLoadAcl(key, version_id) => {
+                app.object_detail_load_acl(key, version_id);
+            }
+            AppEventType::CompleteObjectDetailLoadAcl(result) => {
+                app.complete_object_detail_load_acl(result);
+            }
+            AppEventType::ObjectListLoadPrefixVersions(range) => {
+                app.object_list_load_prefix_versions(range);
+            }
+            AppEventType::CompleteObjectListLoadPrefixVersions(result) => {
+                app.complete_object_list_load_prefix_versions(result);
+            }
+            AppEventType::ObjectListOpenPrefixVersion(key, version_id) => {
+                app.object_list_open_prefix_version(key, version_id);
+            }
+            AppEventType::LoadMultipartUploads => {
+                app.load_multipart_uploads();
+            }
+            AppEventType::CompleteLoadMultipartUploads(result) => {
+                app.complete_load_multipart_uploads(result);
+            }
+            AppEventType::AbortMultipartUpload(key, upload_id) => {
+                app.abort_multipart_upload(key, upload_id);
+            }
+            AppEventType::CompleteAbortMultipartUpload(result) => {
+                app.complete_abort_multipart_upload(result);
+            }
             AppEventType::CloseCurrentPage => {
                 app.close_current_page();
             }
@@ -148,6 +372,12 @@ pub async fn run<B: Backend>(
             AppEventType::CopyToClipboard(name, value) => {
                 app.copy_to_clipboard(name, value);
             }
+            AppEventType::CopyAsDataUri(file_detail) => {
+                app.copy_as_data_uri(file_detail);
+            }
+            AppEventType::CompleteCopyAsDataUri(result) => {
+                app.complete_copy_as_data_uri(result);
+            }
             AppEventType::NotifyInfo(msg) => {
                 app.info_notification(msg);
             }
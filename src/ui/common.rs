@@ -1,8 +1,51 @@
-use chrono::{DateTime, Local};
-use ratatui::layout::{Constraint, Layout, Rect};
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+use chrono::{DateTime, Local, TimeZone};
+use humansize::FormatSizeOptions;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+};
+
+use crate::config::SizeUnits;
+
+// shared by all pages so that the toggle applies to every displayed time
+static RELATIVE_DATETIME: AtomicBool = AtomicBool::new(false);
+
+static DECIMAL_SIZE_UNITS: AtomicBool = AtomicBool::new(false);
+
+static NO_COLOR: AtomicBool = AtomicBool::new(false);
+
+// the color of the selected items without a background, e.g. in the dialogs and the detail tab
+const MONOCHROME_SELECTED_COLOR: Color = Color::Cyan;
+
+// the management console is only for AWS, so it is disabled for S3-compatible endpoints
+static MANAGEMENT_CONSOLE: AtomicBool = AtomicBool::new(true);
+
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+// the longest relative datetime, e.g. "59 minutes ago"
+const RELATIVE_DATETIME_WIDTH: usize = 14;
+
+thread_local! {
+    // set from the config on the main thread, where all the pages are rendered.
+    // being per thread also lets the tests render with another format without affecting each other.
+    static DATETIME_FORMAT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// style of the part of the items matching the filter
+static HIGHLIGHT_STYLE: RwLock<Style> = RwLock::new(Style::new().fg(Color::Red));
 
 pub fn calc_centered_dialog_rect(r: Rect, dialog_width: u16, dialog_height: u16) -> Rect {
-    let vertical_pad = (r.height - dialog_height) / 2;
+    // the dialog is cut off if it does not fit
+    let vertical_pad = r.height.saturating_sub(dialog_height) / 2;
     let vertical_layout = Layout::vertical(Constraint::from_lengths([
         vertical_pad,
         dialog_height,
@@ -10,7 +53,7 @@ pub fn calc_centered_dialog_rect(r: Rect, dialog_width: u16, dialog_height: u16)
     ]))
     .split(r);
 
-    let horizontal_pad = (r.width - dialog_width) / 2;
+    let horizontal_pad = r.width.saturating_sub(dialog_width) / 2;
     Layout::horizontal(Constraint::from_lengths([
         horizontal_pad,
         dialog_width,
@@ -19,8 +62,27 @@ pub fn calc_centered_dialog_rect(r: Rect, dialog_width: u16, dialog_height: u16)
     .split(vertical_layout[1])[1]
 }
 
+pub fn set_size_units(units: SizeUnits) {
+    DECIMAL_SIZE_UNITS.store(units == SizeUnits::Decimal, Ordering::Relaxed);
+}
+
+pub fn size_format_options() -> FormatSizeOptions {
+    if DECIMAL_SIZE_UNITS.load(Ordering::Relaxed) {
+        size_format_options_of(SizeUnits::Decimal)
+    } else {
+        size_format_options_of(SizeUnits::Binary)
+    }
+}
+
+fn size_format_options_of(units: SizeUnits) -> FormatSizeOptions {
+    match units {
+        SizeUnits::Binary => FormatSizeOptions::from(humansize::BINARY),
+        SizeUnits::Decimal => FormatSizeOptions::from(humansize::DECIMAL),
+    }
+}
+
 pub fn format_size_byte(size_byte: usize) -> String {
-    humansize::format_size_i(size_byte, humansize::BINARY)
+    humansize::format_size_i(size_byte, size_format_options())
 }
 
 #[cfg(not(feature = "imggen"))]
@@ -33,12 +95,221 @@ pub fn format_version(_version: &str) -> &str {
     "GeJeVLwoQlknMCcSa"
 }
 
+pub fn set_no_color(no_color: bool) {
+    NO_COLOR.store(no_color, Ordering::Relaxed);
+}
+
+pub fn is_no_color() -> bool {
+    NO_COLOR.load(Ordering::Relaxed)
+}
+
+pub fn set_management_console(enabled: bool) {
+    MANAGEMENT_CONSOLE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_management_console_enabled() -> bool {
+    MANAGEMENT_CONSOLE.load(Ordering::Relaxed)
+}
+
+pub fn set_highlight_style(style: Style) {
+    *HIGHLIGHT_STYLE.write().unwrap() = style;
+}
+
+pub fn highlight_style() -> Style {
+    *HIGHLIGHT_STYLE.read().unwrap()
+}
+
+// applied to the whole frame after rendering, so that the pages do not have to care about it.
+// cells with a background color are selected or highlighted ones, which are shown in reverse video instead.
+// markers only in a foreground color (e.g. the selected entry of a dialog, the filter match, the public mark)
+// would disappear, so they are given a modifier too. gray is only for less important text, so it is left as is.
+pub fn apply_monochrome(buf: &mut Buffer) {
+    for cell in buf.content.iter_mut() {
+        match (cell.bg, cell.fg) {
+            (Color::Reset, MONOCHROME_SELECTED_COLOR) => {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            (Color::Reset, Color::Reset | Color::DarkGray | Color::Gray) => {}
+            (Color::Reset, _) => {
+                cell.modifier.insert(Modifier::UNDERLINED);
+            }
+            _ => {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+// the format must have been validated, otherwise formatting panics
+pub fn set_datetime_format(format: Option<String>) {
+    DATETIME_FORMAT.with_borrow_mut(|f| *f = format);
+}
+
+pub fn set_relative_datetime(relative: bool) {
+    RELATIVE_DATETIME.store(relative, Ordering::Relaxed);
+}
+
+pub fn toggle_relative_datetime() {
+    RELATIVE_DATETIME.fetch_xor(true, Ordering::Relaxed);
+}
+
 #[cfg(not(feature = "imggen"))]
 pub fn format_datetime(datetime: &DateTime<Local>) -> String {
-    datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+    if RELATIVE_DATETIME.load(Ordering::Relaxed) {
+        format_relative_datetime(datetime, &Local::now())
+    } else {
+        format_absolute_datetime(datetime)
+    }
+}
+
+fn format_absolute_datetime(datetime: &DateTime<Local>) -> String {
+    DATETIME_FORMAT.with_borrow(|format| {
+        format_datetime_with(
+            datetime,
+            format.as_deref().unwrap_or(DEFAULT_DATETIME_FORMAT),
+        )
+    })
+}
+
+// the width of the date columns, which fits any datetime formatted by format_datetime
+pub fn datetime_width() -> usize {
+    let width = DATETIME_FORMAT.with_borrow(|format| {
+        datetime_width_with(format.as_deref().unwrap_or(DEFAULT_DATETIME_FORMAT))
+    });
+    // the relative datetimes can be toggled at any time, so the width is kept the same
+    width.max(RELATIVE_DATETIME_WIDTH)
+}
+
+// formats a datetime with the longest month and weekday names, and two-digit fields
+pub fn datetime_width_with(format: &str) -> usize {
+    Local
+        .with_ymd_and_hms(2024, 9, 25, 23, 59, 59)
+        .earliest()
+        .map_or(0, |datetime| {
+            format_datetime_with(&datetime, format).chars().count()
+        })
+}
+
+pub fn format_datetime_with(datetime: &DateTime<Local>, format: &str) -> String {
+    datetime.format(format).to_string()
+}
+
+pub fn format_relative_datetime(datetime: &DateTime<Local>, now: &DateTime<Local>) -> String {
+    let delta = now.signed_duration_since(datetime);
+    if delta.num_seconds() < 0 {
+        // clock skew, just show as is
+        return format_absolute_datetime(datetime);
+    }
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {} ago", unit)
+        } else {
+            format!("{} {}s ago", n, unit)
+        }
+    };
+    match delta.num_days() {
+        0 => match (delta.num_hours(), delta.num_minutes()) {
+            (0, 0) => "just now".to_string(),
+            (0, m) => plural(m, "minute"),
+            (h, _) => plural(h, "hour"),
+        },
+        1 => "yesterday".to_string(),
+        d if d < 30 => plural(d, "day"),
+        d if d < 365 => plural(d / 30, "month"),
+        d => plural(d / 365, "year"),
+    }
 }
 
 #[cfg(feature = "imggen")]
 pub fn format_datetime(_datetime: &DateTime<Local>) -> String {
     String::from("2024-01-02 13:04:05")
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDateTime};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(SizeUnits::Binary, 999, "999 B")]
+    #[case(SizeUnits::Binary, 1000, "1000 B")]
+    #[case(SizeUnits::Binary, 1023, "1023 B")]
+    #[case(SizeUnits::Binary, 1024, "1 KiB")]
+    #[case(SizeUnits::Binary, 1024 * 1024, "1 MiB")]
+    #[case(SizeUnits::Decimal, 999, "999 B")]
+    #[case(SizeUnits::Decimal, 1000, "1 kB")]
+    #[case(SizeUnits::Decimal, 1024, "1.02 kB")]
+    #[case(SizeUnits::Decimal, 1000 * 1000, "1 MB")]
+    #[trace]
+    fn test_format_size_byte_units(
+        #[case] units: SizeUnits,
+        #[case] size_byte: usize,
+        #[case] expected: &str,
+    ) {
+        let actual = humansize::format_size_i(size_byte, size_format_options_of(units));
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(Duration::seconds(0), "just now")]
+    #[case(Duration::seconds(59), "just now")]
+    #[case(Duration::minutes(1), "1 minute ago")]
+    #[case(Duration::minutes(59), "59 minutes ago")]
+    #[case(Duration::hours(1), "1 hour ago")]
+    #[case(Duration::hours(3), "3 hours ago")]
+    #[case(Duration::hours(23), "23 hours ago")]
+    #[case(Duration::hours(24), "yesterday")]
+    #[case(Duration::hours(47), "yesterday")]
+    #[case(Duration::days(2), "2 days ago")]
+    #[case(Duration::days(29), "29 days ago")]
+    #[case(Duration::days(30), "1 month ago")]
+    #[case(Duration::days(364), "12 months ago")]
+    #[case(Duration::days(365), "1 year ago")]
+    #[case(Duration::days(365 * 3), "3 years ago")]
+    #[case(Duration::seconds(-10), "2024-01-02 13:01:12")]
+    #[trace]
+    fn test_format_relative_datetime(#[case] delta: Duration, #[case] expected: &str) {
+        let now = NaiveDateTime::parse_from_str("2024-01-02 13:01:02", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap();
+        let datetime = now - delta;
+        assert_eq!(format_relative_datetime(&datetime, &now), expected);
+    }
+
+    #[rstest]
+    #[case(DEFAULT_DATETIME_FORMAT, "2024-01-02 13:01:02")]
+    #[case("%Y-%m-%dT%H:%M:%S", "2024-01-02T13:01:02")]
+    #[case("%d/%m/%Y %H:%M", "02/01/2024 13:01")]
+    #[case("%b %e %Y", "Jan  2 2024")]
+    fn test_format_datetime_with(#[case] format: &str, #[case] expected: &str) {
+        let datetime = NaiveDateTime::parse_from_str("2024-01-02 13:01:02", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap();
+        assert_eq!(format_datetime_with(&datetime, format), expected);
+    }
+
+    #[rstest]
+    #[case(DEFAULT_DATETIME_FORMAT, 19)]
+    #[case("%d/%m/%Y %H:%M", 16)]
+    #[case("%b %e %Y", 11)]
+    #[case("%A, %B %e, %Y", 29)]
+    fn test_datetime_width_with(#[case] format: &str, #[case] expected: usize) {
+        assert_eq!(datetime_width_with(format), expected);
+    }
+
+    #[test]
+    fn test_datetime_width() {
+        set_datetime_format(Some("%Y-%m-%d".to_string()));
+        assert_eq!(datetime_width(), RELATIVE_DATETIME_WIDTH);
+        set_datetime_format(Some("%A, %B %e, %Y %H:%M:%S".to_string()));
+        assert_eq!(datetime_width(), 38);
+        set_datetime_format(None);
+        assert_eq!(datetime_width(), 19);
+    }
+}
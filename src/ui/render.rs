@@ -1,17 +1,17 @@
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
-    style::{Color, Modifier, Stylize},
+    style::{Color, Modifier, Style, Stylize},
     text::Line,
-    widgets::{Block, BorderType, Padding, Paragraph},
+    widgets::{Block, BorderType, Padding, Paragraph, Tabs, Wrap},
     Frame,
 };
 
 use crate::{
-    app::{App, Notification},
-    pages::page::Page,
-    ui::common::calc_centered_dialog_rect,
+    app::{App, AppDialog, Notification},
+    pages::page::{Page, PageStack},
+    ui::common::{apply_monochrome, calc_centered_dialog_rect, is_no_color},
     util,
-    widget::{Dialog, Header},
+    widget::{Dialog, Header, StatusBar},
 };
 
 const SHORT_HELP_COLOR: Color = Color::DarkGray;
@@ -21,17 +21,34 @@ const WARN_STATUS_COLOR: Color = Color::Yellow;
 const ERROR_STATUS_COLOR: Color = Color::Red;
 
 pub fn render(f: &mut Frame, app: &mut App) {
+    let (min_width, min_height) = app.min_terminal_size();
+    if f.size().width < min_width || f.size().height < min_height {
+        // the layouts of the pages assume some space, and would break on a tiny terminal
+        let msg = build_too_small_message(f.size(), min_width, min_height);
+        f.render_widget(msg, f.size());
+        return;
+    }
+
     let chunks = Layout::vertical([
         Constraint::Length(header_height(app)),
+        Constraint::Length(tabs_height(app)),
         Constraint::Min(0),
         Constraint::Length(2),
+        Constraint::Length(status_bar_height(app)),
     ])
     .split(f.size());
 
     render_header(f, chunks[0], app);
-    render_content(f, chunks[1], app);
-    render_footer(f, chunks[2], app);
+    render_tabs(f, chunks[1], app);
+    render_content(f, chunks[2], app);
+    render_footer(f, chunks[3], app);
+    render_status_bar(f, chunks[4], app);
+    render_app_dialog(f, app);
     render_loading_dialog(f, app);
+
+    if is_no_color() {
+        apply_monochrome(f.buffer_mut());
+    }
 }
 
 fn header_height(app: &App) -> u16 {
@@ -41,6 +58,22 @@ fn header_height(app: &App) -> u16 {
     }
 }
 
+fn tabs_height(app: &App) -> u16 {
+    if app.tabs().len() > 1 {
+        1
+    } else {
+        0 // Hide tabs if there is only one
+    }
+}
+
+fn status_bar_height(app: &App) -> u16 {
+    if app.status_bar_enabled() {
+        1
+    } else {
+        0
+    }
+}
+
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
     if area.is_empty() {
         return;
@@ -49,6 +82,14 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(header, area);
 }
 
+fn render_tabs(f: &mut Frame, area: Rect, app: &App) {
+    if area.is_empty() {
+        return;
+    }
+    let tabs = build_tabs(app);
+    f.render_widget(tabs, area);
+}
+
 fn render_content(f: &mut Frame, area: Rect, app: &mut App) {
     match app.page_stack.current_page_mut() {
         Page::Initializing(page) => page.render(f, area),
@@ -85,6 +126,41 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+fn render_status_bar(f: &mut Frame, area: Rect, app: &App) {
+    if area.is_empty() {
+        return;
+    }
+    let status_bar = build_status_bar(app);
+    f.render_widget(status_bar, area);
+}
+
+fn render_app_dialog(f: &mut Frame, app: &App) {
+    match &app.app_view_state.dialog {
+        AppDialog::OverwriteConfirm(result) => {
+            let confirm = build_overwrite_confirm_dialog(&result.path);
+            let dialog_width = f.size().width.saturating_sub(4).min(60);
+            let area = calc_centered_dialog_rect(f.size(), dialog_width, 6);
+            let dialog = Dialog::new(Box::new(confirm));
+            f.render_widget_ref(dialog, area);
+        }
+        AppDialog::QuitConfirm => {
+            let confirm = build_quit_confirm_dialog();
+            let dialog_width = f.size().width.saturating_sub(4).min(40);
+            let area = calc_centered_dialog_rect(f.size(), dialog_width, 5);
+            let dialog = Dialog::new(Box::new(confirm));
+            f.render_widget_ref(dialog, area);
+        }
+        AppDialog::CredentialsExpired => {
+            let dialog = build_credentials_expired_dialog();
+            let dialog_width = f.size().width.saturating_sub(4).min(60);
+            let area = calc_centered_dialog_rect(f.size(), dialog_width, 7);
+            let dialog = Dialog::new(Box::new(dialog));
+            f.render_widget_ref(dialog, area);
+        }
+        AppDialog::None => {}
+    }
+}
+
 fn render_loading_dialog(f: &mut Frame, app: &App) {
     if app.app_view_state.is_loading {
         let loading = build_loading_dialog("Loading...");
@@ -95,25 +171,69 @@ fn render_loading_dialog(f: &mut Frame, app: &App) {
 }
 
 fn build_header(app: &App) -> Header {
-    let mut target_pages: Vec<&Page> = app
-        .page_stack
+    let breadcrumb = build_breadcrumb(&app.page_stack);
+    let mut header = Header::new(breadcrumb).read_only(app.read_only());
+    if let Some(region) = app.region() {
+        header = header.account(region, app.account_id());
+    }
+    if app.app_view_state.is_loading {
+        header.loading_frame(app.app_view_state.loading_frame)
+    } else {
+        header
+    }
+}
+
+fn build_status_bar(app: &App) -> StatusBar {
+    let mut status_bar = StatusBar::default();
+    if let Some((bucket, depth)) = app.current_location() {
+        status_bar = status_bar.bucket(bucket, depth);
+    }
+    match app.page_stack.current_page() {
+        Page::BucketList(page) => status_bar = status_bar.item_count(page.item_count()),
+        Page::ObjectList(page) => status_bar = status_bar.item_count(page.item_count()),
+        _ => {}
+    }
+    if app.app_view_state.is_loading {
+        status_bar = status_bar.operation("Loading...");
+    }
+    status_bar
+}
+
+fn build_tabs(app: &App) -> Tabs<'_> {
+    let titles: Vec<String> = app
+        .tabs()
+        .into_iter()
+        .enumerate()
+        .map(|(i, page_stack)| {
+            let breadcrumb = build_breadcrumb(page_stack);
+            let name = breadcrumb.last().map_or("Buckets", |s| s.as_str());
+            format!("{}: {}", i + 1, name)
+        })
+        .collect();
+    Tabs::new(titles)
+        .select(app.current_tab())
+        .padding(" ", " ")
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED))
+}
+
+fn build_breadcrumb(page_stack: &PageStack) -> Vec<String> {
+    let mut target_pages: Vec<&Page> = page_stack
         .iter()
         .filter(|page| matches!(page, Page::BucketList(_) | Page::ObjectList(_)))
         .collect();
     target_pages.pop(); // Remove the last item (current page)
 
-    let breadcrumb: Vec<String> = target_pages
+    target_pages
         .iter()
         .map(|page| match page {
             Page::BucketList(page) => page.current_selected_item().name.clone(),
             Page::ObjectList(page) => page.current_selected_item().name().into(),
             _ => unreachable!(),
         })
-        .collect();
-    Header::new(breadcrumb)
+        .collect()
 }
 
-fn build_short_help(app: &App, width: u16) -> Paragraph {
+fn build_short_help(app: &App, width: u16) -> Paragraph<'_> {
     let helps = match app.page_stack.current_page() {
         Page::Initializing(page) => page.short_helps(),
         Page::BucketList(page) => page.short_helps(),
@@ -123,39 +243,91 @@ fn build_short_help(app: &App, width: u16) -> Paragraph {
         Page::Help(page) => page.short_helps(),
     };
     let pad = Padding::horizontal(2);
-    let max_width = (width - pad.left - pad.right) as usize;
+    let max_width = width.saturating_sub(pad.left + pad.right) as usize;
     let help = build_short_help_string(&helps, max_width);
     Paragraph::new(help.fg(SHORT_HELP_COLOR)).block(Block::default().padding(pad))
 }
 
+fn build_too_small_message(size: Rect, min_width: u16, min_height: u16) -> Paragraph<'static> {
+    let lines = vec![
+        Line::from("Terminal too small".add_modifier(Modifier::BOLD)),
+        Line::from(format!("{}x{}", size.width, size.height)),
+        Line::from(format!("(min {}x{})", min_width, min_height)),
+    ];
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+}
+
 fn build_short_help_string(helps: &[(String, usize)], max_width: usize) -> String {
     let delimiter = ", ";
     let ss = util::prune_strings_to_fit_width(helps, max_width, delimiter);
     ss.join(delimiter)
 }
 
-fn build_info_status(msg: &str) -> Paragraph {
+fn build_info_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.fg(INFO_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_success_status(msg: &str) -> Paragraph {
+fn build_success_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.add_modifier(Modifier::BOLD).fg(SUCCESS_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_warn_status(msg: &str) -> Paragraph {
+fn build_warn_status(msg: &str) -> Paragraph<'_> {
     Paragraph::new(msg.add_modifier(Modifier::BOLD).fg(WARN_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_error_status(err: &str) -> Paragraph {
+fn build_error_status(err: &str) -> Paragraph<'_> {
     let err = format!("ERROR: {}", err);
     Paragraph::new(err.add_modifier(Modifier::BOLD).fg(ERROR_STATUS_COLOR))
         .block(Block::default().padding(Padding::horizontal(2)))
 }
 
-fn build_loading_dialog(msg: &str) -> Paragraph {
+fn build_overwrite_confirm_dialog(path: &str) -> Paragraph<'_> {
+    let text = vec![
+        Line::from("File already exists:".add_modifier(Modifier::BOLD)),
+        Line::from(path),
+        Line::from(""),
+        Line::from("<y>: Overwrite, <r>: Rename, <n>: Cancel".fg(SHORT_HELP_COLOR)),
+    ];
+    Paragraph::new(text).block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1)),
+    )
+}
+
+fn build_quit_confirm_dialog() -> Paragraph<'static> {
+    let text = vec![
+        Line::from("Quit stu?".add_modifier(Modifier::BOLD)),
+        Line::from(""),
+        Line::from("<y>: Quit, <n>: Cancel".fg(SHORT_HELP_COLOR)),
+    ];
+    Paragraph::new(text).block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1)),
+    )
+}
+
+fn build_credentials_expired_dialog() -> Paragraph<'static> {
+    let text = vec![
+        Line::from("Credentials have expired".add_modifier(Modifier::BOLD)),
+        Line::from(""),
+        Line::from("Sign in again (e.g. `aws sso login`) and reload them."),
+        Line::from("<r>: Reload, <q>: Quit, <Esc>: Close".fg(SHORT_HELP_COLOR)),
+    ];
+    Paragraph::new(text).block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1)),
+    )
+}
+
+fn build_loading_dialog(msg: &str) -> Paragraph<'_> {
     let text = Line::from(msg.add_modifier(Modifier::BOLD));
     Paragraph::new(text).alignment(Alignment::Center).block(
         Block::bordered()
@@ -163,3 +335,35 @@ fn build_loading_dialog(msg: &str) -> Paragraph {
             .padding(Padding::vertical(1)),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+    use crate::{config::Config, event, set_cells};
+
+    use super::*;
+
+    #[test]
+    fn test_render_too_small() -> std::io::Result<()> {
+        let (tx, _rx) = event::new();
+        let mut app = App::new(Config::default(), tx, 10, 3);
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 3))?;
+        terminal.draw(|f| render(f, &mut app))?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " Terminal ",
+            " too small",
+            "   10x3   ",
+        ]);
+        set_cells! { expected =>
+            (1..9, [0]) => modifier: Modifier::BOLD,
+            (1..10, [1]) => modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+        Ok(())
+    }
+}
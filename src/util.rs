@@ -1,8 +1,50 @@
+use std::{
+    collections::VecDeque,
+    io::Read,
+    time::{Duration, Instant},
+};
+
+use base64::Engine;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeDelta};
+use sha1::Digest;
+
 pub fn to_preview_string(bytes: &[u8]) -> String {
     // fixme: consider file type
     String::from_utf8_lossy(bytes).into()
 }
 
+// the content type is often not set properly, so the bytes are checked if it does not tell
+pub fn is_binary_content(content_type: &str, bytes: &[u8]) -> bool {
+    const TEXT_SUBTYPES: [&str; 6] = ["json", "xml", "yaml", "javascript", "csv", "toml"];
+    let mime = content_type.split(';').next().unwrap_or_default().trim();
+    if mime.starts_with("text/") || TEXT_SUBTYPES.iter().any(|t| mime.contains(t)) {
+        return false;
+    }
+    if mime.is_empty() || mime.ends_with("/octet-stream") {
+        return bytes.contains(&0);
+    }
+    true
+}
+
+// only the common formats, to tell what a binary object is when it cannot be previewed
+pub fn detect_binary_format(bytes: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: [(&[u8], &str); 9] = [
+        (b"\x89PNG\r\n\x1a\n", "PNG"),
+        (b"\xff\xd8\xff", "JPEG"),
+        (b"GIF8", "GIF"),
+        (b"%PDF-", "PDF"),
+        (b"PK\x03\x04", "ZIP"),
+        (b"\x1f\x8b", "gzip"),
+        (b"\x7fELF", "ELF"),
+        (b"BZh", "bzip2"),
+        (b"7z\xbc\xaf\x27\x1c", "7z"),
+    ];
+    SIGNATURES
+        .iter()
+        .find(|(magic, _)| bytes.starts_with(magic))
+        .map(|(_, name)| *name)
+}
+
 pub fn prune_strings_to_fit_width(
     words_with_priority: &[(String, usize)],
     max_width: usize,
@@ -77,11 +119,47 @@ pub fn digits(n: usize) -> usize {
 pub fn extension_from_file_name(filename: &str) -> String {
     filename
         .split('.')
-        .last()
+        .next_back()
         .map(|s| s.to_string())
         .unwrap_or_default()
 }
 
+pub fn add_number_suffix_to_file_name(path: &str, n: usize) -> String {
+    let (dir, file_name) = match path.rfind(std::path::is_separator) {
+        Some(i) => path.split_at(i + 1),
+        None => ("", path),
+    };
+    match file_name.rfind('.') {
+        Some(i) if i > 0 => {
+            let (stem, ext) = file_name.split_at(i);
+            format!("{}{} ({}){}", dir, stem, n, ext)
+        }
+        _ => format!("{}{} ({})", dir, file_name, n),
+    }
+}
+
+pub fn truncate_with_ellipsis(s: &str, max_width: usize) -> String {
+    const ELLIPSIS: &str = "...";
+    if s.chars().count() <= max_width {
+        return s.to_string();
+    }
+    let take = max_width.saturating_sub(ELLIPSIS.len());
+    let head: String = s.chars().take(take).collect();
+    format!("{}{}", head, ELLIPSIS)
+}
+
+// keeps the end of the string, e.g. for paths whose last part matters the most
+pub fn truncate_head_with_ellipsis(s: &str, max_width: usize) -> String {
+    const ELLIPSIS: &str = "...";
+    let count = s.chars().count();
+    if count <= max_width {
+        return s.to_string();
+    }
+    let take = max_width.saturating_sub(ELLIPSIS.len());
+    let tail: String = s.chars().skip(count - take).collect();
+    format!("{}{}", ELLIPSIS, tail)
+}
+
 pub fn split_str(s: &str, sp: &str) -> Option<(String, String, String)> {
     s.find(sp).map(|start| {
         let mut chars = s.chars();
@@ -92,6 +170,236 @@ pub fn split_str(s: &str, sp: &str) -> Option<(String, String, String)> {
     })
 }
 
+pub fn rsplit_str(s: &str, sp: &str) -> Option<(String, String, String)> {
+    s.rfind(sp).map(|start| {
+        let end = start + sp.len();
+        (
+            s[..start].to_string(),
+            s[start..end].to_string(),
+            s[end..].to_string(),
+        )
+    })
+}
+
+// the rate is measured over the last few seconds so that it does not jump around with each chunk
+const TRANSFER_RATE_WINDOW: Duration = Duration::from_secs(3);
+
+#[derive(Debug)]
+pub struct TransferRate {
+    total: usize,
+    samples: VecDeque<(Instant, usize)>,
+}
+
+impl TransferRate {
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            samples: VecDeque::new(),
+        }
+    }
+
+    pub fn update(&mut self, now: Instant, current: usize) {
+        self.samples.push_back((now, current));
+        // keep the oldest sample in the window as the base of the rate
+        while self.samples.len() > 2
+            && now.duration_since(self.samples[1].0) >= TRANSFER_RATE_WINDOW
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    // bytes per second
+    pub fn rate(&self) -> Option<f64> {
+        let (first_at, first) = self.samples.front()?;
+        let (last_at, last) = self.samples.back()?;
+        let elapsed = last_at.duration_since(*first_at).as_secs_f64();
+        if elapsed <= 0.0 || last < first {
+            return None;
+        }
+        Some((last - first) as f64 / elapsed)
+    }
+
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate().filter(|r| *r > 0.0)?;
+        let (_, current) = self.samples.back()?;
+        let remaining = self.total.saturating_sub(*current);
+        Some(Duration::from_secs_f64(remaining as f64 / rate))
+    }
+}
+
+pub fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 60 * 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {}m", secs / (60 * 60), (secs / 60) % 60)
+    }
+}
+
+// a time window given as `FROM..TO`, either end can be omitted (a value without `..` is the start)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub from: Option<DateTime<Local>>,
+    // exclusive
+    pub to: Option<DateTime<Local>>,
+}
+
+impl TimeRange {
+    pub fn parse(s: &str) -> Result<TimeRange, String> {
+        let (from, to) = s.split_once("..").unwrap_or((s, ""));
+        let from = parse_range_end(from, false)?;
+        let to = parse_range_end(to, true)?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from >= to {
+                return Err("The start of the range must be before the end".into());
+            }
+        }
+        Ok(TimeRange { from, to })
+    }
+
+    pub fn contains(&self, t: &DateTime<Local>) -> bool {
+        self.from.is_none_or(|from| from <= *t) && self.to.is_none_or(|to| *t < to)
+    }
+}
+
+// a date without time covers the whole day, so the end of the range is the start of the next day
+fn parse_range_end(s: &str, end: bool) -> Result<Option<DateTime<Local>>, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    let datetime = if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        let datetime = date.and_hms_opt(0, 0, 0).unwrap();
+        if end {
+            datetime + TimeDelta::days(1)
+        } else {
+            datetime
+        }
+    } else {
+        ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(s, f).ok())
+            .ok_or_else(|| format!("Invalid date: {}", s))?
+    };
+    datetime
+        .and_local_timezone(Local)
+        .earliest()
+        .map(Some)
+        .ok_or_else(|| format!("Invalid date: {}", s))
+}
+
+// a part of an object given as `OFFSET+LENGTH`, the numbers can also be hex with `0x`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl ByteRange {
+    // the length is cut off at the end of the object
+    pub fn parse(s: &str, size_byte: usize) -> Result<ByteRange, String> {
+        let (offset, length) = s
+            .split_once('+')
+            .ok_or_else(|| format!("Invalid range: {}", s))?;
+        let offset = parse_byte_count(offset)?;
+        let length = parse_byte_count(length)?;
+        if offset >= size_byte {
+            return Err(format!(
+                "The offset must be less than the object size ({} bytes)",
+                size_byte
+            ));
+        }
+        if length == 0 {
+            return Err("The length must be greater than 0".into());
+        }
+        let length = length.min(size_byte - offset);
+        Ok(ByteRange { offset, length })
+    }
+
+    // inclusive, as in the Range header
+    pub fn end(&self) -> usize {
+        self.offset + self.length - 1
+    }
+
+    pub fn header(&self) -> String {
+        format!("bytes={}-{}", self.offset, self.end())
+    }
+}
+
+fn parse_byte_count(s: &str) -> Result<usize, String> {
+    let s = s.trim();
+    let n = match s.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    n.map_err(|_| format!("Invalid number: {}", s))
+}
+
+// the additional checksums supported by S3, which are base64 encoded big-endian values
+enum ChecksumHasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Sha1(sha1::Sha1),
+    Sha256(sha2::Sha256),
+}
+
+impl ChecksumHasher {
+    fn new(algorithm: &str) -> Option<Self> {
+        match algorithm {
+            "CRC32" => Some(Self::Crc32(crc32fast::Hasher::new())),
+            "CRC32C" => Some(Self::Crc32c(0)),
+            "SHA1" => Some(Self::Sha1(sha1::Sha1::new())),
+            "SHA256" => Some(Self::Sha256(sha2::Sha256::new())),
+            _ => None,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Crc32(h) => h.update(bytes),
+            Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, bytes),
+            Self::Sha1(h) => h.update(bytes),
+            Self::Sha256(h) => h.update(bytes),
+        }
+    }
+
+    fn finalize(self) -> String {
+        let bytes = match self {
+            Self::Crc32(h) => h.finalize().to_be_bytes().to_vec(),
+            Self::Crc32c(crc) => crc.to_be_bytes().to_vec(),
+            Self::Sha1(h) => h.finalize().to_vec(),
+            Self::Sha256(h) => h.finalize().to_vec(),
+        };
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+}
+
+// returns None if the checksum cannot be compared with the whole content,
+// i.e. the algorithm is not supported or the checksum is of the parts of multipart upload (e.g. `xxx-3`)
+pub fn verify_checksum<R: Read>(
+    algorithm: &str,
+    expected: &str,
+    mut reader: R,
+) -> std::io::Result<Option<bool>> {
+    if expected.contains('-') {
+        return Ok(None);
+    }
+    let Some(mut hasher) = ChecksumHasher::new(algorithm) else {
+        return Ok(None);
+    };
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(Some(hasher.finalize() == expected))
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -172,6 +480,43 @@ mod tests {
         assert_eq!(extension_from_file_name("a.gif.txt"), "txt");
     }
 
+    #[rstest]
+    #[case("a.txt", 1, "a (1).txt")]
+    #[case("a.txt", 12, "a (12).txt")]
+    #[case("a.tar.gz", 1, "a.tar (1).gz")]
+    #[case("a", 1, "a (1)")]
+    #[case(".bashrc", 1, ".bashrc (1)")]
+    #[case("/tmp/dir/a.txt", 2, "/tmp/dir/a (2).txt")]
+    #[case("/tmp/dir.d/a", 1, "/tmp/dir.d/a (1)")]
+    #[trace]
+    fn test_add_number_suffix_to_file_name(
+        #[case] path: &str,
+        #[case] n: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(add_number_suffix_to_file_name(path, n), expected);
+    }
+
+    #[rstest]
+    #[case("text/plain", b"abc", false)]
+    #[case("text/csv; charset=utf-8", b"a,b", false)]
+    #[case("application/json", b"{}", false)]
+    #[case("image/svg+xml", b"<svg>", false)]
+    #[case("image/png", b"\x89PNG", true)]
+    #[case("application/zip", b"PK", true)]
+    #[case("application/octet-stream", b"abc", false)]
+    #[case("binary/octet-stream", b"a\0c", true)]
+    #[case("", b"abc", false)]
+    #[case("", b"a\0c", true)]
+    #[trace]
+    fn test_is_binary_content(
+        #[case] content_type: &str,
+        #[case] bytes: &[u8],
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_binary_content(content_type, bytes), expected);
+    }
+
     #[test]
     fn test_split_str() {
         fn assert(s: &str, sp: &str, expected: Option<(&str, &str, &str)>) {
@@ -188,4 +533,163 @@ mod tests {
         assert("abc", "abc", Some(("", "abc", "")));
         assert("abcdefg", "cd", Some(("ab", "cd", "efg")));
     }
+
+    #[test]
+    fn test_rsplit_str() {
+        fn assert(s: &str, sp: &str, expected: Option<(&str, &str, &str)>) {
+            let actual = rsplit_str(s, sp);
+            assert_eq!(
+                actual,
+                expected.map(|(a, b, c)| (a.into(), b.into(), c.into()))
+            );
+        }
+        assert("abc", "b", Some(("a", "b", "c")));
+        assert("abcabc", "b", Some(("abca", "b", "c")));
+        assert("a/b/a", "a", Some(("a/b/", "a", "")));
+        assert("abc", "d", None);
+    }
+
+    #[rstest]
+    #[case("abc", 5, "abc")]
+    #[case("abcde", 5, "abcde")]
+    #[case("dir/sub/file.txt", 10, "...ile.txt")]
+    #[case("abcdef", 2, "...")]
+    fn test_truncate_head_with_ellipsis(
+        #[case] s: &str,
+        #[case] max_width: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(truncate_head_with_ellipsis(s, max_width), expected);
+    }
+
+    #[rstest]
+    #[case(b"\x89PNG\r\n\x1a\n\x00\x00", Some("PNG"))]
+    #[case(b"PK\x03\x04\x14\x00", Some("ZIP"))]
+    #[case(b"%PDF-1.7\n", Some("PDF"))]
+    #[case(b"\x1f\x8b\x08\x00", Some("gzip"))]
+    #[case(b"\x7fELF\x02\x01", Some("ELF"))]
+    #[case(b"\x00\x01\x02\x03", None)]
+    #[case(b"", None)]
+    fn test_detect_binary_format(#[case] bytes: &[u8], #[case] expected: Option<&str>) {
+        assert_eq!(detect_binary_format(bytes), expected);
+    }
+
+    #[test]
+    fn test_time_range_parse() {
+        let range = TimeRange::parse("2024-01-02..2024-01-03 12:00").unwrap();
+        assert_eq!(range.from, Some(local("2024-01-02 00:00:00")));
+        assert_eq!(range.to, Some(local("2024-01-03 12:00:00")));
+
+        // the end date includes the whole day
+        let range = TimeRange::parse("..2024-01-03").unwrap();
+        assert_eq!(range.from, None);
+        assert_eq!(range.to, Some(local("2024-01-04 00:00:00")));
+
+        let range = TimeRange::parse("2024-01-02 13:01:02").unwrap();
+        assert_eq!(range.from, Some(local("2024-01-02 13:01:02")));
+        assert_eq!(range.to, None);
+
+        assert_eq!(TimeRange::parse("").unwrap(), TimeRange::default());
+        assert!(TimeRange::parse("yesterday").is_err());
+        assert!(TimeRange::parse("2024-01-03..2024-01-02").is_err());
+    }
+
+    #[rstest]
+    #[case("0+16", 100, Ok((0, 16)))]
+    #[case("10 + 20", 100, Ok((10, 20)))]
+    #[case("0x10+0x20", 100, Ok((16, 32)))]
+    #[case("90+20", 100, Ok((90, 10)))]
+    #[case("100+1", 100, Err(()))]
+    #[case("10+0", 100, Err(()))]
+    #[case("10", 100, Err(()))]
+    #[case("a+10", 100, Err(()))]
+    fn test_byte_range_parse(
+        #[case] s: &str,
+        #[case] size_byte: usize,
+        #[case] expected: Result<(usize, usize), ()>,
+    ) {
+        let actual = ByteRange::parse(s, size_byte)
+            .map(|r| (r.offset, r.length))
+            .map_err(|_| ());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_time_range_contains() {
+        let range = TimeRange::parse("2024-01-02..2024-01-02").unwrap();
+        assert!(!range.contains(&local("2024-01-01 23:59:59")));
+        assert!(range.contains(&local("2024-01-02 00:00:00")));
+        assert!(range.contains(&local("2024-01-02 23:59:59")));
+        assert!(!range.contains(&local("2024-01-03 00:00:00")));
+    }
+
+    fn local(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transfer_rate() {
+        let now = Instant::now();
+        let mb = 1_000_000;
+        let mut rate = TransferRate::new(100 * mb);
+        assert_eq!(rate.rate(), None);
+        assert_eq!(rate.eta(), None);
+
+        rate.update(now, 0);
+        assert_eq!(rate.rate(), None);
+
+        rate.update(now + Duration::from_secs(1), 10 * mb);
+        assert_eq!(rate.rate(), Some(10.0 * mb as f64));
+        assert_eq!(rate.eta(), Some(Duration::from_secs(9)));
+
+        // a burst is smoothed over the window
+        rate.update(now + Duration::from_secs(2), 40 * mb);
+        assert_eq!(rate.rate(), Some(20.0 * mb as f64));
+        assert_eq!(rate.eta(), Some(Duration::from_secs(3)));
+
+        // samples older than the window are dropped
+        rate.update(now + Duration::from_secs(5), 50 * mb);
+        rate.update(now + Duration::from_secs(6), 60 * mb);
+        assert_eq!(rate.rate(), Some(5.0 * mb as f64));
+        assert_eq!(rate.eta(), Some(Duration::from_secs(8)));
+    }
+
+    #[rstest]
+    #[case(0, "0s")]
+    #[case(59, "59s")]
+    #[case(60, "1m 0s")]
+    #[case(125, "2m 5s")]
+    #[case(3600 + 120, "1h 2m")]
+    fn test_format_eta(#[case] secs: u64, #[case] expected: &str) {
+        assert_eq!(format_eta(Duration::from_secs(secs)), expected);
+    }
+
+    #[rstest]
+    #[case("CRC32", "y/Q5Jg==")]
+    #[case("CRC32C", "4waSgw==")]
+    #[case("SHA1", "98O8HYCOBHMq32eZZczDTKeuNEE=")]
+    #[case("SHA256", "FeKw08M4keuw8e9gnsQZQgwg4yDOlMZfvIwzEkSOsiU=")]
+    fn test_verify_checksum(#[case] algorithm: &str, #[case] checksum: &str) {
+        let content = "123456789".as_bytes();
+        assert_eq!(
+            verify_checksum(algorithm, checksum, content).unwrap(),
+            Some(true)
+        );
+        let modified = "123456780".as_bytes();
+        assert_eq!(
+            verify_checksum(algorithm, checksum, modified).unwrap(),
+            Some(false)
+        );
+    }
+
+    #[rstest]
+    #[case("CRC32", "y/Q5Jg==-3")]
+    #[case("MD5", "JfnnlDI7RTiF9RgfG2JNCw==")]
+    fn test_verify_checksum_unverifiable(#[case] algorithm: &str, #[case] checksum: &str) {
+        let content = "123456789".as_bytes();
+        assert_eq!(verify_checksum(algorithm, checksum, content).unwrap(), None);
+    }
 }
@@ -1,21 +1,45 @@
 mod bar;
+mod bucket_policy_dialog;
+mod bucket_properties_dialog;
 mod copy_detail_dialog;
 mod dialog;
+mod dialog_lines;
 mod divider;
+mod export_dialog;
+mod head_preview;
 mod header;
 mod input_dialog;
+mod multipart_uploads_dialog;
+mod object_acl_dialog;
+mod peek_dialog;
+mod prefix_versions_dialog;
+mod save_dialog;
 mod scroll;
 mod scroll_lines;
 mod scroll_list;
+mod status_bar;
 mod text_preview;
 
 pub use bar::Bar;
+pub use bucket_policy_dialog::{BucketPolicyDialog, BucketPolicyDialogState};
+pub use bucket_properties_dialog::{BucketPropertiesDialog, BucketPropertiesDialogState};
 pub use copy_detail_dialog::{CopyDetailDialog, CopyDetailDialogState};
 pub use dialog::Dialog;
+pub use dialog_lines::{
+    build_acl_lines, build_loading_lines, build_message_line, build_section_line,
+};
 pub use divider::Divider;
+pub use export_dialog::{ExportDialog, ExportDialogState};
+pub use head_preview::{HeadPreview, HeadPreviewState};
 pub use header::Header;
 pub use input_dialog::{InputDialog, InputDialogState};
+pub use multipart_uploads_dialog::{MultipartUploadsDialog, MultipartUploadsDialogState};
+pub use object_acl_dialog::{ObjectAclDialog, ObjectAclDialogState};
+pub use peek_dialog::{PeekDialog, PeekDialogState};
+pub use prefix_versions_dialog::{PrefixVersionsDialog, PrefixVersionsDialogState};
+pub use save_dialog::{SaveDialog, SaveDialogState};
 pub use scroll::ScrollBar;
 pub use scroll_lines::{ScrollLines, ScrollLinesOptions, ScrollLinesState};
 pub use scroll_list::{ScrollList, ScrollListState};
+pub use status_bar::StatusBar;
 pub use text_preview::{TextPreview, TextPreviewState};
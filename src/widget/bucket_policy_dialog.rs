@@ -0,0 +1,182 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::Line,
+    widgets::{Block, BorderType, Clear, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    object::{Acl, BucketPolicy},
+    ui::common::calc_centered_dialog_rect,
+    widget::{
+        build_acl_lines, build_loading_lines, build_message_line, build_section_line, Dialog,
+        ScrollLines, ScrollLinesOptions, ScrollLinesState,
+    },
+};
+
+#[derive(Debug)]
+pub struct BucketPolicyDialogState {
+    name: String,
+    scroll_lines_state: ScrollLinesState,
+}
+
+impl BucketPolicyDialogState {
+    pub fn new(name: impl Into<String>) -> Self {
+        let scroll_lines_state =
+            ScrollLinesState::new(build_loading_lines(), ScrollLinesOptions::new(false, false));
+        Self {
+            name: name.into(),
+            scroll_lines_state,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_policy(&mut self, policy: &BucketPolicy, acl: &Acl) {
+        let lines = build_policy_lines(policy, acl);
+        self.scroll_lines_state.set_lines(lines);
+    }
+
+    pub fn scroll_forward(&mut self) {
+        self.scroll_lines_state.scroll_forward();
+    }
+
+    pub fn scroll_backward(&mut self) {
+        self.scroll_lines_state.scroll_backward();
+    }
+
+    pub fn scroll_page_forward(&mut self) {
+        self.scroll_lines_state.scroll_page_forward();
+    }
+
+    pub fn scroll_page_backward(&mut self) {
+        self.scroll_lines_state.scroll_page_backward();
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll_lines_state.scroll_to_top();
+    }
+
+    pub fn scroll_to_end(&mut self) {
+        self.scroll_lines_state.scroll_to_end();
+    }
+
+    pub fn scroll_right(&mut self) {
+        self.scroll_lines_state.scroll_right();
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.scroll_lines_state.scroll_left();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BucketPolicyDialog {}
+
+impl StatefulWidget for BucketPolicyDialog {
+    type State = BucketPolicyDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(80);
+        let dialog_height = area.height.saturating_sub(2).min(30);
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        // ScrollLines is not a WidgetRef, so only the background is cleared by the dialog
+        let dialog = Dialog::new(Box::new(Clear));
+        dialog.render_ref(area, buf);
+
+        let title = format!("Policy [{}]", state.name);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(title);
+        ScrollLines::default()
+            .block(block)
+            .render(area, buf, &mut state.scroll_lines_state);
+    }
+}
+
+fn build_policy_lines(policy: &BucketPolicy, acl: &Acl) -> Vec<Line<'static>> {
+    let mut lines = vec![build_section_line("Policy")];
+    match policy {
+        BucketPolicy::Document(document) => {
+            lines.extend(document.lines().map(|l| Line::raw(l.to_string())));
+        }
+        BucketPolicy::NotSet => {
+            lines.push(build_message_line("No bucket policy is set"));
+        }
+        BucketPolicy::AccessDenied => {
+            lines.push(build_message_line(
+                "Access denied (s3:GetBucketPolicy is not allowed)",
+            ));
+        }
+    }
+
+    lines.push(Line::default());
+
+    lines.push(build_section_line("ACL"));
+    lines.extend(build_acl_lines(
+        acl,
+        "Access denied (s3:GetBucketAcl is not allowed)",
+    ));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Modifier;
+
+    use crate::{object::AclGrant, set_cells};
+
+    use super::*;
+
+    #[test]
+    fn test_render_bucket_policy_dialog() {
+        let mut state = BucketPolicyDialogState::new("bucket-1");
+        let policy = BucketPolicy::Document(
+            "{\n  \"Statement\": [],\n  \"Version\": \"2012-10-17\"\n}".to_string(),
+        );
+        let acl = Acl::Grants {
+            owner: "owner-id".to_string(),
+            grants: vec![AclGrant {
+                grantee: "AllUsers (group)".to_string(),
+                permission: "READ".to_string(),
+            }],
+        };
+        state.set_policy(&policy, &acl);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 16));
+        BucketPolicyDialog::default().render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "  ╭Policy [bucket-1]─────────────────╮  ",
+            "  │ Policy:                          │  ",
+            "  │ {                                │  ",
+            "  │   \"Statement\": [],               │  ",
+            "  │   \"Version\": \"2012-10-17\"        │  ",
+            "  │ }                                │  ",
+            "  │                                  │  ",
+            "  │ ACL:                             │  ",
+            "  │        Owner: owner-id           │  ",
+            "  │                                  │  ",
+            "  │ Grants:                          │  ",
+            "  │         READ: AllUsers (group)   │  ",
+            "  │                                  │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // section titles are bold
+            (4..11, [2]) => modifier: Modifier::BOLD,
+            (4..8, [8]) => modifier: Modifier::BOLD,
+            (4..11, [11]) => modifier: Modifier::BOLD,
+            // labels are bold
+            (4..18, [9, 12]) => modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
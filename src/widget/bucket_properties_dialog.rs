@@ -0,0 +1,149 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    object::BucketItem,
+    ui::common::{calc_centered_dialog_rect, format_datetime},
+    widget::{Dialog, ScrollLines, ScrollLinesOptions, ScrollLinesState},
+};
+
+const LOADING_COLOR: Color = Color::DarkGray;
+const UNKNOWN_COLOR: Color = Color::DarkGray;
+
+const UNKNOWN: &str = "unknown";
+
+#[derive(Debug)]
+pub struct BucketPropertiesDialogState {
+    name: String,
+    scroll_lines_state: ScrollLinesState,
+}
+
+impl BucketPropertiesDialogState {
+    pub fn new(name: impl Into<String>) -> Self {
+        let scroll_lines_state =
+            ScrollLinesState::new(build_loading_lines(), ScrollLinesOptions::new(false, false));
+        Self {
+            name: name.into(),
+            scroll_lines_state,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_properties(&mut self, item: &BucketItem) {
+        let lines = build_properties_lines(item);
+        self.scroll_lines_state.set_lines(lines);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BucketPropertiesDialog {}
+
+impl StatefulWidget for BucketPropertiesDialog {
+    type State = BucketPropertiesDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(60);
+        let dialog_height = 4 /* lines */ + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        // ScrollLines is not a WidgetRef, so only the background is cleared by the dialog
+        let dialog = Dialog::new(Box::new(Clear));
+        dialog.render_ref(area, buf);
+
+        let title = format!("Properties [{}]", state.name);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(title);
+        ScrollLines::default()
+            .block(block)
+            .render(area, buf, &mut state.scroll_lines_state);
+    }
+}
+
+fn build_loading_lines() -> Vec<Line<'static>> {
+    vec![Line::from("Loading...".fg(LOADING_COLOR))]
+}
+
+fn build_properties_lines(item: &BucketItem) -> Vec<Line<'static>> {
+    [
+        ("Region", item.region.clone()),
+        ("Versioning", item.versioning.clone()),
+        ("Encryption", item.encryption.clone()),
+        ("Created", item.creation_date.as_ref().map(format_datetime)),
+    ]
+    .into_iter()
+    .map(|(label, value)| build_line(label, value))
+    .collect()
+}
+
+fn build_line(label: &str, value: Option<String>) -> Line<'static> {
+    let value = match value {
+        Some(value) => Span::raw(value),
+        None => UNKNOWN.fg(UNKNOWN_COLOR),
+    };
+    Line::from(vec![
+        format!("{:>10}: ", label).add_modifier(Modifier::BOLD),
+        value,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, NaiveDateTime};
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_bucket_properties_dialog() {
+        let mut state = BucketPropertiesDialogState::new("bucket-1");
+        let item = BucketItem {
+            name: "bucket-1".to_string(),
+            creation_date: Some(parse_datetime("2024-01-02 13:01:02")),
+            region: Some("ap-northeast-1".to_string()),
+            versioning: Some("enabled".to_string()),
+            encryption: None,
+            public: None,
+        };
+        state.set_properties(&item);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 8));
+        BucketPropertiesDialog::default().render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "  ╭Properties [bucket-1]─────────────╮  ",
+            "  │     Region: ap-northeast-1       │  ",
+            "  │ Versioning: enabled              │  ",
+            "  │ Encryption: unknown              │  ",
+            "  │    Created: 2024-01-02 13:01:02  │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // labels are bold
+            (4..16, [2, 3, 4, 5]) => modifier: Modifier::BOLD,
+            // the property failed to load
+            (16..23, [4]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use itsuki::zero_indexed_enum;
 use ratatui::{
     buffer::Buffer,
@@ -7,75 +9,270 @@ use ratatui::{
     widgets::{block::Title, Block, BorderType, List, ListItem, Padding, Widget, WidgetRef},
 };
 
-use crate::{object::FileDetail, ui::common::calc_centered_dialog_rect, widget::Dialog};
+use crate::{
+    object::FileDetail, ui::common::calc_centered_dialog_rect, util::truncate_with_ellipsis,
+    widget::Dialog,
+};
 
 const SELECTED_COLOR: Color = Color::Cyan;
 
-#[derive(Default)]
+const VALUE_INDENT: &str = "  ";
+
 #[zero_indexed_enum]
 enum ItemType {
-    #[default]
     Key,
     S3Uri,
+    VersionedS3Uri,
     Arn,
     ObjectUrl,
     Etag,
+    Checksum,
+    ContentEncoding,
+    ContentDisposition,
+    CacheControl,
+    DataUri,
 }
 
 impl ItemType {
-    pub fn name_and_value(&self, file_detail: &FileDetail) -> (String, String) {
+    pub fn name_and_value(
+        &self,
+        file_detail: &FileDetail,
+        version_id: Option<&str>,
+    ) -> (String, String) {
         let (name, value) = match self {
-            Self::Key => ("Key", &file_detail.key),
-            Self::S3Uri => ("S3 URI", &file_detail.s3_uri),
-            Self::Arn => ("ARN", &file_detail.arn),
-            Self::ObjectUrl => ("Object URL", &file_detail.object_url),
-            Self::Etag => ("ETag", &file_detail.e_tag),
+            Self::Key => ("Key", file_detail.key.clone()),
+            Self::S3Uri => ("S3 URI", file_detail.s3_uri.clone()),
+            Self::VersionedS3Uri => (
+                "S3 URI (version)",
+                version_id
+                    .map(|v| build_versioned_s3_uri(&file_detail.s3_uri, v))
+                    .unwrap_or_default(),
+            ),
+            Self::Arn => ("ARN", file_detail.arn.clone()),
+            Self::ObjectUrl => ("Object URL", file_detail.object_url.clone()),
+            Self::Etag => ("ETag", file_detail.e_tag.clone()),
+            Self::Checksum => ("Checksum", file_detail.checksum.clone()),
+            Self::ContentEncoding => ("Content-Encoding", file_detail.content_encoding.clone()),
+            Self::ContentDisposition => (
+                "Content-Disposition",
+                file_detail.content_disposition.clone(),
+            ),
+            Self::CacheControl => ("Cache-Control", file_detail.cache_control.clone()),
+            // the actual value is built after downloading the object
+            Self::DataUri => (
+                "Data URI",
+                format!("data:{};base64,...", file_detail.content_type),
+            ),
+        };
+        (name.into(), value)
+    }
+}
+
+fn build_versioned_s3_uri(s3_uri: &str, version_id: &str) -> String {
+    format!("{}?versionId={}", s3_uri, version_id)
+}
+
+// user defined entries in the config, e.g. `aws s3api get-object --bucket {bucket} --key {key} -`
+#[derive(Debug, Clone)]
+struct CopyTemplate {
+    name: String,
+    format: String,
+}
+
+impl CopyTemplate {
+    fn name_and_value(
+        &self,
+        file_detail: &FileDetail,
+        version_id: Option<&str>,
+    ) -> (String, String) {
+        let value = expand_template(&self.format, file_detail, version_id);
+        (self.name.clone(), value)
+    }
+}
+
+// unknown placeholders are left as they are.
+// `:q` after the name quotes the value for POSIX shells, e.g. `{key:q}` for keys with spaces
+fn expand_template(format: &str, file_detail: &FileDetail, version_id: Option<&str>) -> String {
+    let value = |placeholder: &str| {
+        let (name, quoted) = match placeholder.strip_suffix(":q") {
+            Some(name) => (name, true),
+            None => (placeholder, false),
+        };
+        let value = match name {
+            "bucket" => file_detail.bucket.as_str(),
+            "key" => file_detail.key.as_str(),
+            "version" => version_id.unwrap_or_default(),
+            "etag" => file_detail.e_tag.as_str(),
+            "uri" => file_detail.s3_uri.as_str(),
+            _ => return None,
         };
-        (name.into(), value.into())
+        Some(if quoted {
+            shell_quote(value)
+        } else {
+            value.to_string()
+        })
+    };
+
+    let mut expanded = String::new();
+    let mut rest = format;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let replaced = rest
+            .find('}')
+            .and_then(|end| value(&rest[1..end]).map(|v| (end, v)));
+        match replaced {
+            Some((end, v)) => {
+                expanded.push_str(&v);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                expanded.push('{');
+                rest = &rest[1..];
+            }
+        }
     }
+    expanded.push_str(rest);
+    expanded
+}
+
+// single quotes keep everything as is, except for the single quotes themselves
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 pub struct CopyDetailDialogState {
-    selected: ItemType,
+    items: Vec<ItemType>,
+    templates: Vec<CopyTemplate>,
+    selected: usize,
+    // the version selected in the version tab, to pin it in the S3 URI
+    version_id: Option<String>,
+    // the values are cut off at the width of the dialog if not set
+    value_width: Option<usize>,
 }
 
 impl CopyDetailDialogState {
+    pub fn new(
+        file_detail: &FileDetail,
+        data_uri_enabled: bool,
+        version_id: Option<String>,
+        templates: &BTreeMap<String, String>,
+    ) -> Self {
+        let items = ItemType::vars_vec()
+            .into_iter()
+            .filter(|item_type| match item_type {
+                ItemType::DataUri => data_uri_enabled,
+                // optional metadata such as Cache-Control is not shown if the object does not have it
+                _ => !item_type
+                    .name_and_value(file_detail, version_id.as_deref())
+                    .1
+                    .is_empty(),
+            })
+            .collect();
+        // the templates with the version would be broken without it, like the versioned S3 URI
+        let templates = templates
+            .iter()
+            .filter(|(_, format)| version_id.is_some() || !format.contains("{version}"))
+            .map(|(name, format)| CopyTemplate {
+                name: name.clone(),
+                format: format.clone(),
+            })
+            .collect();
+        Self {
+            items,
+            templates,
+            selected: 0,
+            version_id,
+            value_width: None,
+        }
+    }
+
+    pub fn set_value_width(&mut self, width: Option<usize>) {
+        self.value_width = width;
+    }
+
+    fn len(&self) -> usize {
+        self.items.len() + self.templates.len()
+    }
+
     pub fn select_next(&mut self) {
-        self.selected = self.selected.next();
+        if self.len() == 0 {
+            return;
+        }
+        self.selected = (self.selected + 1) % self.len();
     }
 
     pub fn select_prev(&mut self) {
-        self.selected = self.selected.prev();
+        if self.len() == 0 {
+            return;
+        }
+        self.selected = (self.selected + self.len() - 1) % self.len();
     }
 
     pub fn selected_name_and_value(&self, file_detail: &FileDetail) -> (String, String) {
-        self.selected.name_and_value(file_detail)
+        self.name_and_values(file_detail).swap_remove(self.selected)
+    }
+
+    // only for display, the value copied is the one of selected_name_and_value
+    fn display_name_and_values(
+        &self,
+        file_detail: &FileDetail,
+        max_width: usize,
+    ) -> Vec<(String, String)> {
+        let width = self.value_width.map_or(max_width, |w| w.min(max_width));
+        self.name_and_values(file_detail)
+            .into_iter()
+            .map(|(name, value)| (name, truncate_with_ellipsis(&value, width)))
+            .collect()
+    }
+
+    // the templates follow the fixed items
+    fn name_and_values(&self, file_detail: &FileDetail) -> Vec<(String, String)> {
+        let version_id = self.version_id.as_deref();
+        let items = self
+            .items
+            .iter()
+            .map(|item_type| item_type.name_and_value(file_detail, version_id));
+        let templates = self
+            .templates
+            .iter()
+            .map(|template| template.name_and_value(file_detail, version_id));
+        items.chain(templates).collect()
+    }
+
+    pub fn is_data_uri_selected(&self) -> bool {
+        self.items.get(self.selected) == Some(&ItemType::DataUri)
     }
 }
 
 pub struct CopyDetailDialog<'a> {
-    state: CopyDetailDialogState,
+    state: &'a CopyDetailDialogState,
     file_detail: &'a FileDetail,
 }
 
 impl<'a> CopyDetailDialog<'a> {
-    pub fn new(state: CopyDetailDialogState, file_detail: &'a FileDetail) -> Self {
+    pub fn new(state: &'a CopyDetailDialogState, file_detail: &'a FileDetail) -> Self {
         Self { state, file_detail }
     }
 }
 
 impl Widget for CopyDetailDialog<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let selected = self.state.selected.val();
-        let list_items: Vec<ListItem> = ItemType::vars_vec()
-            .iter()
+        let dialog_width = area.width.saturating_sub(4).min(80);
+        let value_width = (dialog_width as usize)
+            .saturating_sub(2 /* border */ + 2 /* padding */ + VALUE_INDENT.len());
+
+        let selected = self.state.selected;
+        let list_items: Vec<ListItem> = self
+            .state
+            .display_name_and_values(self.file_detail, value_width)
+            .into_iter()
             .enumerate()
-            .map(|(i, item_type)| build_list_item(i, selected, *item_type, self.file_detail))
+            .map(|(i, (name, value))| build_list_item(i, selected, name, value))
             .collect();
 
-        let dialog_width = (area.width - 4).min(80);
-        let dialog_height = 2 * 5 /* list */ + 2 /* border */;
+        let dialog_height = 2 * list_items.len() as u16 /* list */ + 2 /* border */;
         let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
 
         let title = Title::from("Copy");
@@ -90,16 +287,10 @@ impl Widget for CopyDetailDialog<'_> {
     }
 }
 
-fn build_list_item(
-    i: usize,
-    selected: usize,
-    item_type: ItemType,
-    file_detail: &FileDetail,
-) -> ListItem {
-    let (name, value) = item_type.name_and_value(file_detail);
+fn build_list_item(i: usize, selected: usize, name: String, value: String) -> ListItem<'static> {
     let item = ListItem::new(vec![
         Line::from(format!("{}:", name).add_modifier(Modifier::BOLD)),
-        Line::from(format!("  {}", value)),
+        Line::from(format!("{}{}", VALUE_INDENT, value)),
     ]);
     if i == selected {
         item.fg(SELECTED_COLOR)
@@ -111,6 +302,7 @@ fn build_list_item(
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, Local, NaiveDateTime};
+    use rstest::rstest;
 
     use crate::set_cells;
 
@@ -118,9 +310,9 @@ mod tests {
 
     #[test]
     fn test_render_copy_detail_dialog() {
-        let state = CopyDetailDialogState::default();
         let file_detail = file_detail();
-        let copy_detail_dialog = CopyDetailDialog::new(state, &file_detail);
+        let state = CopyDetailDialogState::new(&file_detail, false, None, &BTreeMap::new());
+        let copy_detail_dialog = CopyDetailDialog::new(&state, &file_detail);
 
         let mut buf = Buffer::empty(Rect::new(0, 0, 40, 20));
         copy_detail_dialog.render(buf.area, &mut buf);
@@ -139,9 +331,9 @@ mod tests {
             "  │ ARN:                             │  ",
             "  │   arn:aws:s3:::bucket-1/file.txt │  ",
             "  │ Object URL:                      │  ",
-            "  │   https://bucket-1.s3.ap-northea │  ",
+            "  │   https://bucket-1.s3.ap-nort... │  ",
             "  │ ETag:                            │  ",
-            "  │   bef684de-a260-48a4-8178-8a535e │  ",
+            "  │   bef684de-a260-48a4-8178-8a5... │  ",
             "  ╰──────────────────────────────────╯  ",
             "                                        ",
             "                                        ",
@@ -166,18 +358,232 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_versioned_s3_uri() {
+        let file_detail = file_detail();
+
+        let mut state = CopyDetailDialogState::new(
+            &file_detail,
+            false,
+            Some("v1.abc".into()),
+            &BTreeMap::new(),
+        );
+        state.select_next();
+        state.select_next();
+        assert_eq!(
+            state.selected_name_and_value(&file_detail),
+            (
+                "S3 URI (version)".to_string(),
+                "s3://bucket-1/file.txt?versionId=v1.abc".to_string()
+            )
+        );
+
+        // not offered without a version
+        let state = CopyDetailDialogState::new(&file_detail, false, None, &BTreeMap::new());
+        assert!(!state.items.contains(&ItemType::VersionedS3Uri));
+    }
+
+    #[test]
+    fn test_value_width() {
+        let file_detail = file_detail();
+        let mut state = CopyDetailDialogState::new(&file_detail, false, None, &BTreeMap::new());
+        state.set_value_width(Some(16));
+        state.select_prev();
+
+        // the value is cut off only in the dialog
+        let (_, displayed) = state
+            .display_name_and_values(&file_detail, 30)
+            .pop()
+            .unwrap();
+        assert_eq!(displayed, "bef684de-a260...");
+        let (_, copied) = state.selected_name_and_value(&file_detail);
+        assert_eq!(copied, "bef684de-a260-48a4-8178-8a535ecccadb");
+
+        // the width of the dialog is the limit even if the overridden width is larger
+        state.set_value_width(Some(100));
+        let (_, displayed) = state
+            .display_name_and_values(&file_detail, 30)
+            .pop()
+            .unwrap();
+        assert_eq!(displayed, "bef684de-a260-48a4-8178-8a5...");
+    }
+
+    #[test]
+    fn test_render_copy_detail_dialog_with_data_uri() {
+        let file_detail = file_detail();
+        let mut state = CopyDetailDialogState::new(&file_detail, true, None, &BTreeMap::new());
+        state.select_prev();
+        let copy_detail_dialog = CopyDetailDialog::new(&state, &file_detail);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 20));
+        copy_detail_dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "                                        ",
+            "                                        ",
+            "  ╭Copy──────────────────────────────╮  ",
+            "  │ Key:                             │  ",
+            "  │   file.txt                       │  ",
+            "  │ S3 URI:                          │  ",
+            "  │   s3://bucket-1/file.txt         │  ",
+            "  │ ARN:                             │  ",
+            "  │   arn:aws:s3:::bucket-1/file.txt │  ",
+            "  │ Object URL:                      │  ",
+            "  │   https://bucket-1.s3.ap-nort... │  ",
+            "  │ ETag:                            │  ",
+            "  │   bef684de-a260-48a4-8178-8a5... │  ",
+            "  │ Data URI:                        │  ",
+            "  │   data:text/plain;base64,...     │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+            "                                        ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // "Key" is bold
+            (4..8, [4]) => modifier: Modifier::BOLD,
+            // "S3 URI" is bold
+            (4..11, [6]) => modifier: Modifier::BOLD,
+            // "ARN" is bold
+            (4..8, [8]) => modifier: Modifier::BOLD,
+            // "Object URL" is bold
+            (4..15, [10]) => modifier: Modifier::BOLD,
+            // "ETag" is bold
+            (4..9, [12]) => modifier: Modifier::BOLD,
+            // "Data URI" is bold
+            (4..13, [14]) => modifier: Modifier::BOLD,
+            // selected item
+            (4..36, [14, 15]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case("{bucket}/{key}", None, "bucket-1/file.txt")]
+    #[case(
+        "{uri} {etag}",
+        None,
+        "s3://bucket-1/file.txt bef684de-a260-48a4-8178-8a535ecccadb"
+    )]
+    #[case("{key}@{version}", Some("v1"), "file.txt@v1")]
+    #[case("{key}@{version}", None, "file.txt@")]
+    #[case("{unknown} {key", None, "{unknown} {key")]
+    #[case("--key {key:q}", None, "--key 'file.txt'")]
+    #[case("{unknown:q}", None, "{unknown:q}")]
+    fn test_expand_template(
+        #[case] format: &str,
+        #[case] version_id: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let file_detail = file_detail();
+        assert_eq!(expand_template(format, &file_detail, version_id), expected);
+    }
+
+    #[rstest]
+    #[case("my file.txt", "'my file.txt'")]
+    #[case("it's", "'it'\\''s'")]
+    #[case("$(rm -rf ~)", "'$(rm -rf ~)'")]
+    fn test_shell_quote(#[case] s: &str, #[case] expected: &str) {
+        assert_eq!(shell_quote(s), expected);
+    }
+
+    #[test]
+    fn test_templates() {
+        let file_detail = file_detail();
+        let templates = BTreeMap::from([
+            ("Get".to_string(), "get {bucket} {key}".to_string()),
+            (
+                "Get version".to_string(),
+                "get {bucket} {key} {version}".to_string(),
+            ),
+        ]);
+
+        let mut state =
+            CopyDetailDialogState::new(&file_detail, false, Some("v1".into()), &templates);
+        state.select_prev();
+        assert_eq!(
+            state.selected_name_and_value(&file_detail),
+            (
+                "Get version".to_string(),
+                "get bucket-1 file.txt v1".to_string()
+            )
+        );
+
+        // the template with the version is not offered without a version
+        let mut state = CopyDetailDialogState::new(&file_detail, false, None, &templates);
+        state.select_prev();
+        assert_eq!(
+            state.selected_name_and_value(&file_detail),
+            ("Get".to_string(), "get bucket-1 file.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_items() {
+        let mut file_detail = file_detail();
+
+        let state = CopyDetailDialogState::new(&file_detail, false, None, &BTreeMap::new());
+        assert_eq!(
+            state.items,
+            vec![
+                ItemType::Key,
+                ItemType::S3Uri,
+                ItemType::Arn,
+                ItemType::ObjectUrl,
+                ItemType::Etag,
+            ]
+        );
+
+        file_detail.content_encoding = "gzip".to_string();
+        file_detail.cache_control = "max-age=3600".to_string();
+        let mut state = CopyDetailDialogState::new(&file_detail, true, None, &BTreeMap::new());
+        assert_eq!(
+            state.items[5..],
+            [
+                ItemType::ContentEncoding,
+                ItemType::CacheControl,
+                ItemType::DataUri,
+            ]
+        );
+
+        state.select_prev();
+        assert!(state.is_data_uri_selected());
+        state.select_prev();
+        let (name, value) = state.selected_name_and_value(&file_detail);
+        assert_eq!(
+            (name.as_str(), value.as_str()),
+            ("Cache-Control", "max-age=3600")
+        );
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected, 0);
+    }
+
     fn file_detail() -> FileDetail {
         FileDetail {
             name: "file.txt".to_string(),
             size_byte: 1024 + 10,
             last_modified: parse_datetime("2024-01-02 13:01:02"),
             e_tag: "bef684de-a260-48a4-8178-8a535ecccadb".to_string(),
+            checksum_algorithm: "".to_string(),
+            checksum: "".to_string(),
+            version_id: "".to_string(),
             content_type: "text/plain".to_string(),
+            content_encoding: "".to_string(),
+            content_disposition: "".to_string(),
+            cache_control: "".to_string(),
             storage_class: "STANDARD".to_string(),
+            server_side_encryption: "".to_string(),
+            sse_kms_key_id: "".to_string(),
+            bucket: "bucket-1".to_string(),
             key: "file.txt".to_string(),
             s3_uri: "s3://bucket-1/file.txt".to_string(),
             arn: "arn:aws:s3:::bucket-1/file.txt".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file.txt".to_string(),
+            public: None,
         }
     }
 
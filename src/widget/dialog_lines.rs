@@ -0,0 +1,54 @@
+use ratatui::{
+    style::{Color, Modifier, Stylize},
+    text::Line,
+};
+
+use crate::object::Acl;
+
+const LOADING_COLOR: Color = Color::DarkGray;
+const MESSAGE_COLOR: Color = Color::DarkGray;
+const OWNER_MARK_COLOR: Color = Color::DarkGray;
+
+pub fn build_loading_lines() -> Vec<Line<'static>> {
+    vec![Line::from("Loading...".fg(LOADING_COLOR))]
+}
+
+pub fn build_acl_lines(acl: &Acl, access_denied_msg: &str) -> Vec<Line<'static>> {
+    match acl {
+        Acl::Grants { owner, grants } => {
+            let mut lines = vec![
+                build_label_line("Owner", owner),
+                Line::default(),
+                build_section_line("Grants"),
+            ];
+            if grants.is_empty() {
+                lines.push(build_message_line("No grants"));
+            }
+            for grant in grants {
+                let mut line = build_label_line(&grant.permission, &grant.grantee);
+                // the owner usually has a grant too, so make it distinguishable from others
+                if &grant.grantee == owner {
+                    line.push_span(" (owner)".fg(OWNER_MARK_COLOR));
+                }
+                lines.push(line);
+            }
+            lines
+        }
+        Acl::AccessDenied => vec![build_message_line(access_denied_msg)],
+    }
+}
+
+pub fn build_section_line(title: &str) -> Line<'static> {
+    Line::from(format!("{}:", title).add_modifier(Modifier::BOLD))
+}
+
+fn build_label_line(label: &str, value: &str) -> Line<'static> {
+    Line::from(vec![
+        format!("{:>12}: ", label).add_modifier(Modifier::BOLD),
+        value.to_string().into(),
+    ])
+}
+
+pub fn build_message_line(msg: &str) -> Line<'static> {
+    Line::from(msg.to_string().fg(MESSAGE_COLOR))
+}
@@ -0,0 +1,102 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    widgets::{block::Title, Block, BorderType, List, ListItem, Padding, Widget, WidgetRef},
+};
+
+use crate::{export::ExportFormat, ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ExportDialogState {
+    selected: ExportFormat,
+}
+
+impl ExportDialogState {
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.prev();
+    }
+
+    pub fn selected_format(&self) -> ExportFormat {
+        self.selected
+    }
+}
+
+pub struct ExportDialog {
+    state: ExportDialogState,
+}
+
+impl ExportDialog {
+    pub fn new(state: ExportDialogState) -> Self {
+        Self { state }
+    }
+}
+
+impl Widget for ExportDialog {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let formats = ExportFormat::vars_vec();
+        let list_items: Vec<ListItem> = formats
+            .iter()
+            .map(|format| {
+                let item = ListItem::new(format.name());
+                if *format == self.state.selected {
+                    item.fg(SELECTED_COLOR)
+                } else {
+                    item
+                }
+            })
+            .collect();
+
+        let dialog_width = area.width.saturating_sub(4).min(30);
+        let dialog_height = formats.len() as u16 + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Export");
+        let list = List::new(list_items).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(list));
+        dialog.render_ref(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_export_dialog() {
+        let mut state = ExportDialogState::default();
+        state.select_next();
+        let export_dialog = ExportDialog::new(state);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 6));
+        export_dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                              ",
+            "  ╭Export──────────────────╮  ",
+            "  │ CSV                    │  ",
+            "  │ JSON                   │  ",
+            "  ╰────────────────────────╯  ",
+            "                              ",
+        ]);
+        set_cells! { expected =>
+            (4..26, [3]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
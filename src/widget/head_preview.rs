@@ -0,0 +1,229 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, Padding, Paragraph, Widget},
+};
+
+use crate::{
+    object::ObjectHead,
+    util::{is_binary_content, to_preview_string},
+};
+
+// wait until the selection settles so that moving through the list does not send many requests
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+const MESSAGE_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug)]
+enum HeadPreviewContent {
+    None,
+    Loading,
+    Text(Vec<String>),
+    Binary,
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct HeadPreviewState {
+    target: Option<String>,
+    selected_at: Option<Instant>,
+    requested: bool,
+    content: HeadPreviewContent,
+}
+
+impl Default for HeadPreviewState {
+    fn default() -> Self {
+        Self {
+            target: None,
+            selected_at: None,
+            requested: false,
+            content: HeadPreviewContent::None,
+        }
+    }
+}
+
+impl HeadPreviewState {
+    // target is the name of the selected file, or None if a dir is selected
+    pub fn select(&mut self, target: Option<(&str, usize)>) {
+        if self.target.as_deref() == target.map(|(name, _)| name) {
+            return;
+        }
+        self.target = target.map(|(name, _)| name.to_string());
+        self.selected_at = None;
+        self.requested = false;
+        self.content = match target {
+            None => HeadPreviewContent::None,
+            Some((_, 0)) => {
+                // a range request for an empty object fails, so there is nothing to fetch
+                self.requested = true;
+                HeadPreviewContent::Text(Vec::new())
+            }
+            Some(_) => HeadPreviewContent::Loading,
+        };
+    }
+
+    // returns the name of the object to fetch if the selection has not changed for a while
+    pub fn poll(&mut self, now: Instant) -> Option<String> {
+        if self.requested {
+            return None;
+        }
+        let target = self.target.as_ref()?;
+        match self.selected_at {
+            None => {
+                self.selected_at = Some(now);
+                None
+            }
+            Some(t) if now.duration_since(t) >= DEBOUNCE => {
+                self.requested = true;
+                Some(target.clone())
+            }
+            Some(_) => None,
+        }
+    }
+
+    pub fn set_head(&mut self, name: &str, head: ObjectHead) {
+        // ignore the result for the item no longer selected
+        if self.target.as_deref() != Some(name) {
+            return;
+        }
+        self.content = if is_binary_content(&head.content_type, &head.obj.bytes) {
+            HeadPreviewContent::Binary
+        } else {
+            let s = to_preview_string(&head.obj.bytes);
+            HeadPreviewContent::Text(s.lines().map(String::from).collect())
+        };
+    }
+
+    pub fn set_error(&mut self, msg: impl Into<String>) {
+        if let HeadPreviewContent::Loading = self.content {
+            self.content = HeadPreviewContent::Error(msg.into());
+        }
+    }
+}
+
+pub struct HeadPreview<'a> {
+    state: &'a HeadPreviewState,
+}
+
+impl<'a> HeadPreview<'a> {
+    pub fn new(state: &'a HeadPreviewState) -> Self {
+        Self { state }
+    }
+}
+
+impl Widget for HeadPreview<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = match &self.state.content {
+            HeadPreviewContent::None => Vec::new(),
+            HeadPreviewContent::Loading => vec![build_message_line("Loading...")],
+            HeadPreviewContent::Text(lines) => {
+                lines.iter().map(|l| Line::raw(l.as_str())).collect()
+            }
+            HeadPreviewContent::Binary => vec![build_message_line("Binary file is not previewed")],
+            HeadPreviewContent::Error(msg) => vec![build_message_line(msg)],
+        };
+
+        let title = Title::from("Preview");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .title(title)
+                .padding(Padding::horizontal(1)),
+        );
+        paragraph.render(area, buf);
+    }
+}
+
+fn build_message_line(msg: &str) -> Line<'_> {
+    Line::from(msg.fg(MESSAGE_COLOR))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{object::RawObject, set_cells};
+
+    use super::*;
+
+    #[test]
+    fn test_poll_debounce() {
+        let mut state = HeadPreviewState::default();
+        let now = Instant::now();
+
+        state.select(Some(("file1.txt", 10)));
+        assert_eq!(state.poll(now), None);
+        state.select(Some(("file2.txt", 10)));
+        assert_eq!(state.poll(now), None);
+        assert_eq!(state.poll(now + DEBOUNCE / 2), None);
+        assert_eq!(state.poll(now + DEBOUNCE), Some("file2.txt".to_string()));
+        // requested only once
+        assert_eq!(state.poll(now + DEBOUNCE * 2), None);
+
+        // dirs and empty files are not fetched
+        state.select(None);
+        assert_eq!(state.poll(now + DEBOUNCE * 3), None);
+        assert_eq!(state.poll(now + DEBOUNCE * 4), None);
+        state.select(Some(("empty.txt", 0)));
+        assert_eq!(state.poll(now + DEBOUNCE * 5), None);
+        assert_eq!(state.poll(now + DEBOUNCE * 6), None);
+    }
+
+    #[test]
+    fn test_render_head_preview() {
+        let mut state = HeadPreviewState::default();
+        state.select(Some(("file.txt", 100)));
+        let head = ObjectHead {
+            content_type: "text/plain".to_string(),
+            obj: RawObject {
+                bytes: "line 1\nline 2\nline 3".as_bytes().to_vec(),
+            },
+        };
+        state.set_head("file.txt", head);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+        HeadPreview::new(&state).render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌Preview───────────┐",
+            "│ line 1           │",
+            "│ line 2           │",
+            "│ line 3           │",
+            "│                  │",
+            "└──────────────────┘",
+        ]);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_head_preview_binary() {
+        let mut state = HeadPreviewState::default();
+        state.select(Some(("image.png", 100)));
+        let head = ObjectHead {
+            content_type: "image/png".to_string(),
+            obj: RawObject {
+                bytes: vec![0x89, 0x50, 0x4e, 0x47],
+            },
+        };
+        state.set_head("image.png", head);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 32, 4));
+        HeadPreview::new(&state).render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌Preview───────────────────────┐",
+            "│ Binary file is not previewed │",
+            "│                              │",
+            "└──────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..30, [1]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -1,18 +1,48 @@
 use ratatui::{
     buffer::Buffer,
-    layout::{Margin, Rect},
-    widgets::{Block, Padding, Paragraph, Widget},
+    layout::{Alignment, Margin, Rect},
+    widgets::{block::Title, Block, Padding, Paragraph, Widget},
 };
 
 use crate::{constant::APP_NAME, util::prune_strings_to_fit_width};
 
+const READ_ONLY_LABEL: &str = "[RO]";
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
 pub struct Header {
     breadcrumb: Vec<String>,
+    account: Option<String>,
+    loading_frame: Option<usize>,
+    read_only: bool,
 }
 
 impl Header {
     pub fn new(breadcrumb: Vec<String>) -> Header {
-        Header { breadcrumb }
+        Header {
+            breadcrumb,
+            account: None,
+            loading_frame: None,
+            read_only: false,
+        }
+    }
+
+    pub fn account(mut self, region: &str, account_id: Option<&str>) -> Header {
+        let account = match account_id {
+            Some(account_id) => format!("{} ({})", account_id, region),
+            None => region.to_string(),
+        };
+        self.account = Some(account);
+        self
+    }
+
+    pub fn loading_frame(mut self, frame: usize) -> Header {
+        self.loading_frame = Some(frame);
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Header {
+        self.read_only = read_only;
+        self
     }
 }
 
@@ -31,10 +61,25 @@ impl Header {
         let pad = Padding::horizontal(1);
         let max_width = (inner_area.width - pad.left - pad.right) as usize;
 
+        let account = self.account.clone();
+        let loading_frame = self.loading_frame;
+        let title = if self.read_only {
+            format!("{} {}", APP_NAME, READ_ONLY_LABEL)
+        } else {
+            APP_NAME.to_string()
+        };
         let current_key_str = self.build_current_key_str(max_width);
 
-        let paragraph =
-            Paragraph::new(current_key_str).block(Block::bordered().title(APP_NAME).padding(pad));
+        let mut block = Block::bordered().title(title).padding(pad);
+        if let Some(account) = account {
+            let account = format!(" {} ", account);
+            block = block.title(Title::from(account).alignment(Alignment::Right));
+        }
+        if let Some(frame) = loading_frame {
+            let spinner = format!(" {} ", SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]);
+            block = block.title(Title::from(spinner).alignment(Alignment::Right));
+        }
+        let paragraph = Paragraph::new(current_key_str).block(block);
 
         paragraph.render(area, buf);
     }
@@ -123,4 +168,67 @@ mod tests {
         ]);
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn test_render_header_loading() {
+        let cases = [(0, "⠋"), (1, "⠙"), (9, "⠏"), (10, "⠋")];
+        for (frame, spinner) in cases {
+            let header = Header::new(vec!["bucket".to_string()]).loading_frame(frame);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 30 + 4, 3));
+            header.render(buf.area, &mut buf);
+
+            let expected = Buffer::with_lines([
+                format!("┌STU────────────────────────── {} ┐", spinner),
+                "│ bucket                         │".to_string(),
+                "└────────────────────────────────┘".to_string(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn test_render_header_account() {
+        let header =
+            Header::new(vec!["bucket".to_string()]).account("ap-northeast-1", Some("123456789012"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40 + 4, 3));
+        header.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌STU──────── 123456789012 (ap-northeast-1) ┐",
+            "│ bucket                                   │",
+            "└──────────────────────────────────────────┘",
+        ]);
+        assert_eq!(buf, expected);
+
+        // account id is not available
+        let header = Header::new(vec!["bucket".to_string()])
+            .account("ap-northeast-1", None)
+            .loading_frame(0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40 + 4, 3));
+        header.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌STU─────────────────── ap-northeast-1 ─ ⠋ ┐",
+            "│ bucket                                   │",
+            "└──────────────────────────────────────────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_header_read_only() {
+        let header = Header::new(vec!["bucket".to_string()]).read_only(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30 + 4, 3));
+        header.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌STU [RO]────────────────────────┐",
+            "│ bucket                         │",
+            "└────────────────────────────────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
 }
@@ -2,11 +2,20 @@ use crossterm::event::KeyEvent;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+    style::{Color, Stylize},
+    widgets::{
+        block::{Position, Title},
+        Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef,
+    },
 };
 use tui_input::{backend::crossterm::EventHandler, Input};
 
-use crate::{ui::common::calc_centered_dialog_rect, widget::Dialog};
+use crate::{
+    file::read_from_clipboard, key_code_char, ui::common::calc_centered_dialog_rect,
+    util::truncate_head_with_ellipsis, widget::Dialog,
+};
+
+const HINT_COLOR: Color = Color::DarkGray;
 
 #[derive(Debug, Default)]
 pub struct InputDialogState {
@@ -19,6 +28,10 @@ impl InputDialogState {
         self.input.value()
     }
 
+    pub fn set_input(&mut self, input: &str) {
+        self.input = Input::new(input.into());
+    }
+
     pub fn clear_input(&mut self) {
         self.input.reset();
     }
@@ -28,15 +41,40 @@ impl InputDialogState {
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) {
+        if matches!(key, key_code_char!('v', Ctrl)) {
+            // ignore if the clipboard is not available or does not contain text
+            if let Ok(text) = read_from_clipboard() {
+                self.paste(&text);
+            }
+            return;
+        }
         let event = &crossterm::event::Event::Key(key);
         self.input.handle_event(event);
     }
+
+    pub fn paste(&mut self, text: &str) {
+        // the input is single-line, so newlines are stripped
+        let text: String = text.chars().filter(|c| *c != '\n' && *c != '\r').collect();
+
+        let value = self.input.value();
+        let cursor = self.input.cursor();
+        let byte_idx = value
+            .char_indices()
+            .nth(cursor)
+            .map_or(value.len(), |(i, _)| i);
+        let (before, after) = value.split_at(byte_idx);
+
+        let new_value = format!("{}{}{}", before, text, after);
+        let new_cursor = cursor + text.chars().count();
+        self.input = Input::new(new_value).with_cursor(new_cursor);
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct InputDialog {
     title: &'static str,
     max_width: Option<u16>,
+    hint: Option<String>,
 }
 
 impl InputDialog {
@@ -49,13 +87,18 @@ impl InputDialog {
         self.max_width = Some(max_width);
         self
     }
+
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
 }
 
 impl StatefulWidget for InputDialog {
     type State = InputDialogState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let mut dialog_width = area.width - 4;
+        let mut dialog_width = area.width.saturating_sub(4);
         if let Some(max_width) = self.max_width {
             dialog_width = dialog_width.min(max_width);
         }
@@ -63,17 +106,22 @@ impl StatefulWidget for InputDialog {
         let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
 
         // show the last `input_max_width` characters of the input
-        let input_max_width = (dialog_width - 4) as usize;
+        let input_max_width = dialog_width.saturating_sub(4) as usize;
         let input_start_index = state.input.visual_cursor().saturating_sub(input_max_width);
         let input_view: &str = &state.input.value()[input_start_index..];
 
         let title = Title::from(self.title);
-        let dialog_content = Paragraph::new(input_view).block(
-            Block::bordered()
-                .border_type(BorderType::Rounded)
-                .title(title)
-                .padding(Padding::horizontal(1)),
-        );
+        let mut block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(title)
+            .padding(Padding::horizontal(1));
+        if let Some(hint) = self.hint {
+            // show the last characters of the hint if it is too long
+            let hint = truncate_head_with_ellipsis(&hint, dialog_width.saturating_sub(2) as usize);
+            let hint = Title::from(hint.fg(HINT_COLOR)).position(Position::Bottom);
+            block = block.title(hint);
+        }
+        let dialog_content = Paragraph::new(input_view).block(block);
         let dialog = Dialog::new(Box::new(dialog_content));
         dialog.render_ref(dialog_area, buf);
 
@@ -90,6 +138,24 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_paste() {
+        let mut state = InputDialogState::default();
+
+        for c in "abcd".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        state.handle_key_event(KeyEvent::from(KeyCode::Left));
+        state.handle_key_event(KeyEvent::from(KeyCode::Left));
+
+        state.paste("xあ\ny\r\n");
+        assert_eq!(state.input(), "abxあycd");
+        assert_eq!(state.input.cursor(), 5);
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(state.input(), "abxあyzcd");
+    }
+
     #[test]
     fn test_render_input_dialog() {
         let mut state = InputDialogState::default();
@@ -0,0 +1,269 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Clear, ListItem, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    object::MultipartUpload,
+    ui::common::{calc_centered_dialog_rect, datetime_width, format_datetime},
+    util::truncate_with_ellipsis,
+    widget::{Dialog, ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const CONFIRM_HELP_COLOR: Color = Color::DarkGray;
+
+const ITEM_UPLOAD_ID_WIDTH: usize = 16;
+const ITEM_COLUMN_GAP: usize = 2;
+
+#[derive(Debug, Default)]
+pub struct MultipartUploadsDialogState {
+    bucket: Option<String>,
+    uploads: Option<Vec<MultipartUpload>>,
+    list_state: ScrollListState,
+    confirming: bool,
+}
+
+impl MultipartUploadsDialogState {
+    pub fn set_uploads(&mut self, bucket: impl Into<String>, uploads: Vec<MultipartUpload>) {
+        self.bucket = Some(bucket.into());
+        self.list_state = ScrollListState::new(uploads.len());
+        self.uploads = Some(uploads);
+    }
+
+    pub fn remove_upload(&mut self, upload_id: &str) {
+        let Some(uploads) = &mut self.uploads else {
+            return;
+        };
+        uploads.retain(|u| u.upload_id != upload_id);
+        // keep the position in the list as much as possible
+        let selected = self.list_state.selected;
+        self.list_state = ScrollListState::new(uploads.len());
+        self.list_state.select_index(selected);
+    }
+
+    pub fn selected_upload(&self) -> Option<&MultipartUpload> {
+        self.uploads.as_ref()?.get(self.list_state.selected)
+    }
+
+    pub fn is_confirming(&self) -> bool {
+        self.confirming
+    }
+
+    pub fn open_confirm(&mut self) {
+        self.confirming = self.selected_upload().is_some();
+    }
+
+    pub fn close_confirm(&mut self) {
+        self.confirming = false;
+    }
+
+    pub fn select_next(&mut self) {
+        self.list_state.select_next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.list_state.select_prev();
+    }
+
+    pub fn select_first(&mut self) {
+        self.list_state.select_first();
+    }
+
+    pub fn select_last(&mut self) {
+        self.list_state.select_last();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MultipartUploadsDialog {}
+
+impl StatefulWidget for MultipartUploadsDialog {
+    type State = MultipartUploadsDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(100);
+        let dialog_height = area.height.saturating_sub(2).min(20);
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        // ScrollList is not a WidgetRef, so only the background is cleared by the dialog
+        let dialog = Dialog::new(Box::new(Clear));
+        dialog.render_ref(area, buf);
+
+        let title = match &state.bucket {
+            Some(bucket) => format!("Multipart Uploads [{}]", bucket),
+            None => "Multipart Uploads".to_string(),
+        };
+        let (items, empty_message) = match &state.uploads {
+            Some(uploads) => {
+                let items = build_list_items(uploads, &state.list_state, area);
+                (items, "No incomplete multipart uploads")
+            }
+            None => (Vec::new(), "Loading..."),
+        };
+        ScrollList::new(items)
+            .title(title)
+            .empty_message(empty_message)
+            .render(area, buf, &mut state.list_state);
+
+        if state.confirming {
+            if let Some(upload) = state.selected_upload() {
+                let confirm = build_confirm_dialog(&upload.key);
+                let confirm_width = area.width.saturating_sub(4).min(60);
+                let confirm_area = calc_centered_dialog_rect(area, confirm_width, 6);
+                let dialog = Dialog::new(Box::new(confirm));
+                dialog.render_ref(confirm_area, buf);
+            }
+        }
+    }
+}
+
+fn build_list_items<'a>(
+    uploads: &'a [MultipartUpload],
+    list_state: &ScrollListState,
+    area: Rect,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+    uploads
+        .iter()
+        .enumerate()
+        .skip(list_state.offset)
+        .take(show_item_count)
+        .map(|(i, upload)| build_list_item(upload, i == list_state.selected, area.width))
+        .collect()
+}
+
+fn build_list_item(upload: &MultipartUpload, selected: bool, width: u16) -> ListItem<'static> {
+    let content_w: usize = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border */);
+    let date_w = datetime_width();
+    let columns_w = ITEM_COLUMN_GAP + ITEM_UPLOAD_ID_WIDTH + ITEM_COLUMN_GAP + date_w;
+    let key_w = content_w.saturating_sub(columns_w);
+
+    let key = truncate_with_ellipsis(&upload.key, key_w);
+    // upload ids are too long to show entirely, the head is enough to tell them apart
+    let upload_id = truncate_with_ellipsis(&upload.upload_id, ITEM_UPLOAD_ID_WIDTH);
+    let initiated = upload
+        .initiated
+        .as_ref()
+        .map(format_datetime)
+        .unwrap_or_default();
+    let gap = " ".repeat(ITEM_COLUMN_GAP);
+    let line = format!(
+        " {:<key_w$}{}{:<id_w$}{}{:>date_w$} ",
+        key,
+        gap,
+        upload_id,
+        gap,
+        initiated,
+        key_w = key_w,
+        id_w = ITEM_UPLOAD_ID_WIDTH,
+        date_w = date_w,
+    );
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(line)).style(style)
+}
+
+fn build_confirm_dialog(key: &str) -> Paragraph<'static> {
+    let text = vec![
+        Line::from("Abort multipart upload?".add_modifier(Modifier::BOLD)),
+        Line::from(key.to_string()),
+        Line::from(""),
+        Line::from("<y>: Abort, <n>: Cancel".fg(CONFIRM_HELP_COLOR)),
+    ];
+    Paragraph::new(text).block(
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .padding(Padding::horizontal(1)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, NaiveDateTime};
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_multipart_uploads_dialog() {
+        let mut state = MultipartUploadsDialogState::default();
+        let uploads = vec![
+            MultipartUpload {
+                key: "dir/large-file.bin".to_string(),
+                upload_id: "VXBsb2FkSWQtMTIzNDU2Nzg5MA".to_string(),
+                initiated: Some(parse_datetime("2024-01-02 13:01:02")),
+            },
+            MultipartUpload {
+                key: "backup.tar.gz".to_string(),
+                upload_id: "short-id".to_string(),
+                initiated: None,
+            },
+        ];
+        state.set_uploads("bucket-1", uploads);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 74, 7));
+        MultipartUploadsDialog::default().render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                                          ",
+            "  ┌ Multipart Uploads [bucket-1] ─────────────────────────────── 1 / 2 ┐  ",
+            "  │  dir/large-file.bin         VXBsb2FkSWQtM...  2024-01-02 13:01:02  │  ",
+            "  │  backup.tar.gz              short-id                               │  ",
+            "  │                                                                    │  ",
+            "  └────────────────────────────────────────────────────────────────────┘  ",
+            "                                                                          ",
+        ]);
+        set_cells! { expected =>
+            (4..70, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_remove_upload() {
+        let mut state = MultipartUploadsDialogState::default();
+        let uploads = ["a", "b", "c"]
+            .iter()
+            .map(|id| MultipartUpload {
+                key: format!("key-{}", id),
+                upload_id: id.to_string(),
+                initiated: None,
+            })
+            .collect();
+        state.set_uploads("bucket-1", uploads);
+
+        state.select_last();
+        state.remove_upload("c");
+        assert_eq!(state.selected_upload().unwrap().upload_id, "b");
+
+        state.select_first();
+        state.remove_upload("a");
+        assert_eq!(state.selected_upload().unwrap().upload_id, "b");
+
+        state.remove_upload("b");
+        assert_eq!(state.selected_upload(), None);
+        state.open_confirm();
+        assert!(!state.is_confirming());
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+}
@@ -0,0 +1,143 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, BorderType, Clear, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    object::Acl,
+    ui::common::calc_centered_dialog_rect,
+    widget::{
+        build_acl_lines, build_loading_lines, Dialog, ScrollLines, ScrollLinesOptions,
+        ScrollLinesState,
+    },
+};
+
+#[derive(Debug)]
+pub struct ObjectAclDialogState {
+    key: String,
+    scroll_lines_state: ScrollLinesState,
+}
+
+impl ObjectAclDialogState {
+    pub fn new(key: impl Into<String>) -> Self {
+        let scroll_lines_state =
+            ScrollLinesState::new(build_loading_lines(), ScrollLinesOptions::new(false, false));
+        Self {
+            key: key.into(),
+            scroll_lines_state,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn set_acl(&mut self, acl: &Acl) {
+        let lines = build_acl_lines(acl, "Access denied (s3:GetObjectAcl is not allowed)");
+        self.scroll_lines_state.set_lines(lines);
+    }
+
+    pub fn scroll_forward(&mut self) {
+        self.scroll_lines_state.scroll_forward();
+    }
+
+    pub fn scroll_backward(&mut self) {
+        self.scroll_lines_state.scroll_backward();
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll_lines_state.scroll_to_top();
+    }
+
+    pub fn scroll_to_end(&mut self) {
+        self.scroll_lines_state.scroll_to_end();
+    }
+
+    pub fn scroll_right(&mut self) {
+        self.scroll_lines_state.scroll_right();
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.scroll_lines_state.scroll_left();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ObjectAclDialog {}
+
+impl StatefulWidget for ObjectAclDialog {
+    type State = ObjectAclDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(80);
+        let dialog_height = area.height.saturating_sub(2).min(20);
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        // ScrollLines is not a WidgetRef, so only the background is cleared by the dialog
+        let dialog = Dialog::new(Box::new(Clear));
+        dialog.render_ref(area, buf);
+
+        let name = state.key.rsplit('/').next().unwrap_or(&state.key);
+        let title = format!("ACL [{}]", name);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title(title);
+        ScrollLines::default()
+            .block(block)
+            .render(area, buf, &mut state.scroll_lines_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{object::AclGrant, set_cells};
+
+    use super::*;
+
+    #[test]
+    fn test_render_object_acl_dialog() {
+        let mut state = ObjectAclDialogState::new("dir/file.txt");
+        let acl = Acl::Grants {
+            owner: "owner-name".to_string(),
+            grants: vec![
+                AclGrant {
+                    grantee: "owner-name".to_string(),
+                    permission: "FULL_CONTROL".to_string(),
+                },
+                AclGrant {
+                    grantee: "AllUsers (group)".to_string(),
+                    permission: "READ".to_string(),
+                },
+            ],
+        };
+        state.set_acl(&acl);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 44, 9));
+        ObjectAclDialog::default().render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                            ",
+            "  ╭ACL [file.txt]────────────────────────╮  ",
+            "  │        Owner: owner-name             │  ",
+            "  │                                      │  ",
+            "  │ Grants:                              │  ",
+            "  │ FULL_CONTROL: owner-name (owner)     │  ",
+            "  │         READ: AllUsers (group)       │  ",
+            "  ╰──────────────────────────────────────╯  ",
+            "                                            ",
+        ]);
+        set_cells! { expected =>
+            // labels are bold
+            (4..18, [2, 5, 6]) => modifier: Modifier::BOLD,
+            (4..11, [4]) => modifier: Modifier::BOLD,
+            // the grant of the owner is marked
+            (28..36, [5]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,200 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Stylize},
+    text::{Line, Span},
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, Widget, WidgetRef},
+};
+
+use crate::{
+    object::FileDetail,
+    ui::common::{calc_centered_dialog_rect, format_datetime, format_size_byte},
+    widget::Dialog,
+};
+
+const LOADING_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug)]
+pub struct PeekDialogState {
+    name: String,
+    detail: Option<Box<FileDetail>>, // to avoid "warning: large size difference between variants" for ViewState
+}
+
+impl PeekDialogState {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            detail: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_detail(&mut self, detail: FileDetail) {
+        self.detail = Some(Box::new(detail));
+    }
+}
+
+pub struct PeekDialog<'a> {
+    state: &'a PeekDialogState,
+}
+
+impl<'a> PeekDialog<'a> {
+    pub fn new(state: &'a PeekDialogState) -> Self {
+        Self { state }
+    }
+}
+
+impl Widget for PeekDialog<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = match &self.state.detail {
+            Some(detail) => build_detail_lines(detail),
+            None => build_loading_lines(&self.state.name),
+        };
+
+        let dialog_width = area.width.saturating_sub(4).min(60);
+        let dialog_height = 5 /* lines */ + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Peek");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(paragraph));
+        dialog.render_ref(area, buf);
+    }
+}
+
+fn build_detail_lines(detail: &FileDetail) -> Vec<Line<'static>> {
+    [
+        ("Name", detail.name.clone()),
+        ("Size", format_size_byte(detail.size_byte)),
+        ("Last Modified", format_datetime(&detail.last_modified)),
+        ("Content-Type", detail.content_type.clone()),
+        ("Storage class", detail.storage_class.clone()),
+    ]
+    .into_iter()
+    .map(|(label, value)| build_line(label, value))
+    .collect()
+}
+
+fn build_loading_lines(name: &str) -> Vec<Line<'static>> {
+    vec![
+        build_line("Name", name.to_string()),
+        Line::from("Loading...".fg(LOADING_COLOR)),
+    ]
+}
+
+fn build_line(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        format!("{:>13}: ", label).add_modifier(Modifier::BOLD),
+        Span::raw(value),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, NaiveDateTime};
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_peek_dialog() {
+        let mut state = PeekDialogState::new("file.txt");
+        state.set_detail(file_detail());
+        let peek_dialog = PeekDialog::new(&state);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+        peek_dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "  ╭Peek──────────────────────────────╮  ",
+            "  │          Name: file.txt          │  ",
+            "  │          Size: 1.01 KiB          │  ",
+            "  │ Last Modified: 2024-01-02 13:01: │  ",
+            "  │  Content-Type: text/plain        │  ",
+            "  │ Storage class: STANDARD          │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // labels are bold
+            (4..19, [2, 3, 4, 5, 6]) => modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_peek_dialog_loading() {
+        let state = PeekDialogState::new("file.txt");
+        let peek_dialog = PeekDialog::new(&state);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+        peek_dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "  ╭Peek──────────────────────────────╮  ",
+            "  │          Name: file.txt          │  ",
+            "  │ Loading...                       │  ",
+            "  │                                  │  ",
+            "  │                                  │  ",
+            "  │                                  │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // label is bold
+            (4..19, [2]) => modifier: Modifier::BOLD,
+            // loading message
+            (4..14, [3]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    fn file_detail() -> FileDetail {
+        FileDetail {
+            name: "file.txt".to_string(),
+            size_byte: 1024 + 10,
+            last_modified: parse_datetime("2024-01-02 13:01:02"),
+            e_tag: "bef684de-a260-48a4-8178-8a535ecccadb".to_string(),
+            checksum_algorithm: "".to_string(),
+            checksum: "".to_string(),
+            version_id: "".to_string(),
+            content_type: "text/plain".to_string(),
+            content_encoding: "".to_string(),
+            content_disposition: "".to_string(),
+            cache_control: "".to_string(),
+            storage_class: "STANDARD".to_string(),
+            server_side_encryption: "".to_string(),
+            sse_kms_key_id: "".to_string(),
+            bucket: "bucket-1".to_string(),
+            key: "file.txt".to_string(),
+            s3_uri: "s3://bucket-1/file.txt".to_string(),
+            arn: "arn:aws:s3:::bucket-1/file.txt".to_string(),
+            object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file.txt".to_string(),
+            public: None,
+        }
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+}
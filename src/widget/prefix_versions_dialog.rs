@@ -0,0 +1,213 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Clear, ListItem, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    object::PrefixVersion,
+    ui::common::{calc_centered_dialog_rect, datetime_width, format_datetime, format_size_byte},
+    util::truncate_with_ellipsis,
+    widget::{Dialog, ScrollList, ScrollListState},
+};
+
+const SELECTED_COLOR: Color = Color::Cyan;
+const SELECTED_ITEM_TEXT_COLOR: Color = Color::Black;
+const DELETE_MARKER_COLOR: Color = Color::DarkGray;
+
+const ITEM_SIZE_WIDTH: usize = 10;
+const ITEM_COLUMN_GAP: usize = 2;
+
+#[derive(Debug, Default)]
+pub struct PrefixVersionsDialogState {
+    prefix: Option<String>,
+    versions: Option<Vec<PrefixVersion>>,
+    list_state: ScrollListState,
+}
+
+impl PrefixVersionsDialogState {
+    pub fn set_versions(&mut self, prefix: impl Into<String>, versions: Vec<PrefixVersion>) {
+        self.prefix = Some(prefix.into());
+        self.list_state = ScrollListState::new(versions.len());
+        self.versions = Some(versions);
+    }
+
+    pub fn selected_version(&self) -> Option<&PrefixVersion> {
+        self.versions.as_ref()?.get(self.list_state.selected)
+    }
+
+    // the key relative to the prefix the versions were listed under
+    pub fn selected_relative_key(&self) -> Option<&str> {
+        let version = self.selected_version()?;
+        let prefix = self.prefix.as_deref().unwrap_or_default();
+        version.key.strip_prefix(prefix)
+    }
+
+    pub fn select_next(&mut self) {
+        self.list_state.select_next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.list_state.select_prev();
+    }
+
+    pub fn select_first(&mut self) {
+        self.list_state.select_first();
+    }
+
+    pub fn select_last(&mut self) {
+        self.list_state.select_last();
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PrefixVersionsDialog {}
+
+impl StatefulWidget for PrefixVersionsDialog {
+    type State = PrefixVersionsDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = area.width.saturating_sub(4).min(100);
+        let dialog_height = area.height.saturating_sub(2).min(20);
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        // ScrollList is not a WidgetRef, so only the background is cleared by the dialog
+        let dialog = Dialog::new(Box::new(Clear));
+        dialog.render_ref(area, buf);
+
+        let title = match &state.prefix {
+            Some(prefix) if !prefix.is_empty() => format!("Versions [{}]", prefix),
+            _ => "Versions".to_string(),
+        };
+        let (items, empty_message) = match (&state.versions, &state.prefix) {
+            (Some(versions), Some(prefix)) => {
+                let items = build_list_items(versions, prefix, &state.list_state, area);
+                (items, "No versions modified in the range")
+            }
+            _ => (Vec::new(), "Loading..."),
+        };
+        ScrollList::new(items)
+            .title(title)
+            .empty_message(empty_message)
+            .render(area, buf, &mut state.list_state);
+    }
+}
+
+fn build_list_items<'a>(
+    versions: &'a [PrefixVersion],
+    prefix: &str,
+    list_state: &ScrollListState,
+    area: Rect,
+) -> Vec<ListItem<'a>> {
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+    versions
+        .iter()
+        .enumerate()
+        .skip(list_state.offset)
+        .take(show_item_count)
+        .map(|(i, version)| build_list_item(version, prefix, i == list_state.selected, area.width))
+        .collect()
+}
+
+fn build_list_item(
+    version: &PrefixVersion,
+    prefix: &str,
+    selected: bool,
+    width: u16,
+) -> ListItem<'static> {
+    let content_w: usize = (width as usize).saturating_sub(2 /* spaces */ + 4 /* border */);
+    let date_w = datetime_width();
+    let columns_w = date_w + ITEM_COLUMN_GAP + ITEM_COLUMN_GAP + ITEM_SIZE_WIDTH;
+    let key_w = content_w.saturating_sub(columns_w);
+
+    let date = format_datetime(&version.last_modified);
+    let key = version.key.strip_prefix(prefix).unwrap_or(&version.key);
+    let key = truncate_with_ellipsis(key, key_w);
+    let size = if version.is_delete_marker {
+        "deleted".to_string()
+    } else {
+        format_size_byte(version.size_byte)
+    };
+    let gap = " ".repeat(ITEM_COLUMN_GAP);
+    let line = format!(
+        " {:<date_w$}{}{:<key_w$}{}{:>size_w$} ",
+        date,
+        gap,
+        key,
+        gap,
+        size,
+        date_w = date_w,
+        key_w = key_w,
+        size_w = ITEM_SIZE_WIDTH,
+    );
+
+    let style = if selected {
+        Style::default()
+            .bg(SELECTED_COLOR)
+            .fg(SELECTED_ITEM_TEXT_COLOR)
+    } else if version.is_delete_marker {
+        Style::default().fg(DELETE_MARKER_COLOR)
+    } else {
+        Style::default()
+    };
+    ListItem::new(Line::from(line)).style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, NaiveDateTime};
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_prefix_versions_dialog() {
+        let mut state = PrefixVersionsDialogState::default();
+        let versions = vec![
+            PrefixVersion {
+                key: "logs/2024/b.txt".to_string(),
+                version_id: "v2".to_string(),
+                size_byte: 0,
+                last_modified: parse_datetime("2024-01-02 15:00:00"),
+                is_delete_marker: true,
+            },
+            PrefixVersion {
+                key: "logs/a.txt".to_string(),
+                version_id: "v1".to_string(),
+                size_byte: 1024 + 10,
+                last_modified: parse_datetime("2024-01-02 13:01:02"),
+                is_delete_marker: false,
+            },
+        ];
+        state.set_versions("logs/", versions);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 64, 6));
+        PrefixVersionsDialog::default().render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                                ",
+            "  ┌ Versions [logs/] ───────────────────────────────── 1 / 2 ┐  ",
+            "  │  2024-01-02 15:00:00  2024/b.txt                deleted  │  ",
+            "  │  2024-01-02 13:01:02  a.txt                    1.01 KiB  │  ",
+            "  └──────────────────────────────────────────────────────────┘  ",
+            "                                                                ",
+        ]);
+        set_cells! { expected =>
+            (4..60, [2]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected_relative_key(), Some("2024/b.txt"));
+    }
+
+    fn parse_datetime(s: &str) -> DateTime<Local> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .unwrap()
+    }
+}
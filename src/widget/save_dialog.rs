@@ -0,0 +1,192 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+use crate::{
+    file::{download_file_path_in, path_completion_candidates},
+    key_code,
+    widget::{InputDialog, InputDialogState},
+};
+
+#[derive(Debug, Default)]
+pub struct SaveDialogState {
+    input_state: InputDialogState,
+    base_dir: String,
+    completion: Option<PathCompletion>,
+}
+
+#[derive(Debug)]
+struct PathCompletion {
+    candidates: Vec<String>,
+    index: usize,
+}
+
+impl SaveDialogState {
+    pub fn new(base_dir: String) -> Self {
+        Self {
+            base_dir,
+            ..Default::default()
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        self.input_state.input()
+    }
+
+    pub fn cursor(&self) -> (u16, u16) {
+        self.input_state.cursor()
+    }
+
+    pub fn resolved_path(&self) -> String {
+        download_file_path_in(&self.base_dir, self.input())
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Tab) => {
+                self.complete(true);
+            }
+            key_code!(KeyCode::BackTab) => {
+                self.complete(false);
+            }
+            _ => {
+                self.completion = None;
+                self.input_state.handle_key_event(key);
+            }
+        }
+    }
+
+    fn complete(&mut self, forward: bool) {
+        let completion = match self.completion.take() {
+            Some(PathCompletion { candidates, index }) => {
+                let n = candidates.len();
+                let index = if forward {
+                    (index + 1) % n
+                } else {
+                    (index + n - 1) % n
+                };
+                PathCompletion { candidates, index }
+            }
+            None => {
+                let candidates = path_completion_candidates(self.input(), &self.base_dir);
+                if candidates.is_empty() {
+                    return;
+                }
+                let index = if forward { 0 } else { candidates.len() - 1 };
+                PathCompletion { candidates, index }
+            }
+        };
+
+        self.input_state
+            .set_input(&completion.candidates[completion.index]);
+
+        // if there is only one candidate, the next completion starts from it (e.g. into the directory)
+        if completion.candidates.len() > 1 {
+            self.completion = Some(completion);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SaveDialog {}
+
+impl StatefulWidget for SaveDialog {
+    type State = SaveDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let input_dialog = InputDialog::default()
+            .title("Save As")
+            .max_width(40)
+            .hint(state.resolved_path());
+        input_dialog.render(area, buf, &mut state.input_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use ratatui::style::Color;
+    use tempfile::TempDir;
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_save_dialog() {
+        let mut state = SaveDialogState::new("/tmp/download".to_string());
+        let save_dialog = SaveDialog::default();
+
+        for c in "abc/def/ghi.txt".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+        save_dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                              ",
+            "  ╭Save As─────────────────╮  ",
+            "  │ abc/def/ghi.txt        │  ",
+            "  ╰...nload/abc/def/ghi.txt╯  ",
+            "                              ",
+        ]);
+        set_cells! { expected =>
+            (3..27, [3]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_complete_path() {
+        let base_dir = setup_dir();
+        let mut state = SaveDialogState::new(base_dir.path().to_string_lossy().into());
+
+        for c in "fo".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "foo.txt");
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "foo_dir/");
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "foo.txt");
+        state.handle_key_event(KeyEvent::from(KeyCode::BackTab));
+        assert_eq!(state.input(), "foo_dir/");
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Char('b')));
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "foo_dir/bar.txt");
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "foo_dir/bar.txtx");
+    }
+
+    #[test]
+    fn test_complete_path_nonexistent_dir() {
+        let base_dir = setup_dir();
+        let mut state = SaveDialogState::new(base_dir.path().to_string_lossy().into());
+
+        for c in "not_exist/fo".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(state.input(), "not_exist/fo");
+    }
+
+    // removed when the returned value is dropped
+    fn setup_dir() -> TempDir {
+        let base_dir = tempfile::tempdir().unwrap();
+        let path = base_dir.path();
+        fs::create_dir_all(path.join("foo_dir")).unwrap();
+        fs::write(path.join("foo.txt"), "").unwrap();
+        fs::write(path.join("baz.txt"), "").unwrap();
+        fs::write(path.join("foo_dir").join("bar.txt"), "").unwrap();
+        base_dir
+    }
+}
@@ -20,6 +20,9 @@ enum ScrollEvent {
     PageBackward,
     Top,
     End,
+    LastPage,
+    Line(usize),
+    IntoView(usize, usize),
     Right,
     Left,
 }
@@ -67,6 +70,13 @@ impl ScrollLinesState {
         }
     }
 
+    pub fn set_lines(&mut self, lines: Vec<Line<'static>>) {
+        self.max_digits = digits(lines.len());
+        self.max_line_width = lines.iter().map(Line::width).max().unwrap_or_default();
+        self.v_offset = self.v_offset.min(lines.len().saturating_sub(1));
+        self.lines = lines;
+    }
+
     pub fn scroll_forward(&mut self) {
         self.scroll_event = ScrollEvent::Forward;
     }
@@ -91,6 +101,21 @@ impl ScrollLinesState {
         self.scroll_event = ScrollEvent::End;
     }
 
+    // unlike scroll_to_end, the last line is shown at the bottom of the view
+    pub fn scroll_to_last_page(&mut self) {
+        self.scroll_event = ScrollEvent::LastPage;
+    }
+
+    // line is 1-indexed, same as the displayed line number
+    pub fn scroll_to_line(&mut self, line: usize) {
+        self.scroll_event = ScrollEvent::Line(line);
+    }
+
+    // first and last are 0-indexed, scrolls as little as possible to show the lines between them
+    pub fn scroll_into_view(&mut self, first: usize, last: usize) {
+        self.scroll_event = ScrollEvent::IntoView(first, last);
+    }
+
     pub fn scroll_right(&mut self) {
         self.scroll_event = ScrollEvent::Right;
     }
@@ -158,7 +183,7 @@ fn build_line_numbers_paragraph(
     state: &ScrollLinesState,
     text_area_width: usize,
     show_lines_count: usize,
-) -> Paragraph {
+) -> Paragraph<'_> {
     // may not be correct because the wrap of the text is calculated separately...
     let line_heights = wrapped_line_width_iter(
         &state.lines,
@@ -190,7 +215,7 @@ fn build_line_numbers_paragraph(
     )
 }
 
-fn build_lines_paragraph(state: &ScrollLinesState, show_lines_count: usize) -> Paragraph {
+fn build_lines_paragraph(state: &ScrollLinesState, show_lines_count: usize) -> Paragraph<'_> {
     let lines_content: Vec<Line> = state
         .lines
         .iter()
@@ -285,6 +310,34 @@ fn handle_scroll_events(state: &mut ScrollLinesState, width: usize, height: usiz
         ScrollEvent::End => {
             state.v_offset = state.lines.len().saturating_sub(1);
         }
+        ScrollEvent::LastPage => {
+            state.v_offset = calc_last_page_offset(state, width, height);
+        }
+        ScrollEvent::Line(line) => {
+            let last_page_offset = calc_last_page_offset(state, width, height);
+            state.v_offset = line.saturating_sub(1).min(last_page_offset);
+        }
+        ScrollEvent::IntoView(first, last) => {
+            if first < state.v_offset {
+                state.v_offset = first;
+            }
+            // keep the first line visible even if the lines do not fit in the page
+            while state.v_offset < first {
+                let visible_h: usize = wrapped_line_width_iter(
+                    &state.lines,
+                    state.v_offset,
+                    width,
+                    height,
+                    state.options.wrap,
+                )
+                .take(last + 1 - state.v_offset)
+                .sum();
+                if visible_h <= height {
+                    break;
+                }
+                state.v_offset += 1;
+            }
+        }
         ScrollEvent::Right => {
             if state.h_offset < state.max_line_width.saturating_sub(1) {
                 state.h_offset = state.h_offset.saturating_add(1);
@@ -300,6 +353,28 @@ fn handle_scroll_events(state: &mut ScrollLinesState, width: usize, height: usiz
     state.scroll_event = ScrollEvent::None;
 }
 
+fn calc_last_page_offset(state: &ScrollLinesState, width: usize, height: usize) -> usize {
+    let lines_count = state.lines.len();
+    let line_heights = wrapped_reversed_line_width_iter(
+        &state.lines,
+        lines_count,
+        width,
+        height,
+        state.options.wrap,
+    );
+    let mut fit_count = 0;
+    let mut total_h = 0;
+    for h in line_heights {
+        total_h += h;
+        if total_h > height {
+            break;
+        }
+        fit_count += 1;
+    }
+    // show at least the last line even if it does not fit in the page
+    lines_count.saturating_sub(fit_count.max(1))
+}
+
 fn wrapped_line_width_iter<'a>(
     lines: &'a [Line],
     offset: usize,
@@ -678,6 +753,74 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_scroll_lines_scroll_to_line() {
+        let mut state = state(true, true);
+
+        state.scroll_to_line(5);
+        let buf = render_scroll_lines(&mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌TITLE─────────────┐",
+            "│  5 aaa bbb ccc   │",
+            "│    ddd eee       │",
+            "│  6 aaaaaaaa      │",
+            "│    bbbbbbbb      │",
+            "│  7               │",
+            "└──────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2, 3], [1, 3, 5]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+
+        // past the end, clamped to the last page
+        state.scroll_to_line(100);
+        let buf = render_scroll_lines(&mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌TITLE─────────────┐",
+            "│ 14 aaa bbb ccc   │",
+            "│    ddd eee fff   │",
+            "│    ggg           │",
+            "│ 15 f             │",
+            "│ 16 g             │",
+            "└──────────────────┘",
+        ]);
+        set_cells! { expected =>
+            ([2, 3], [1, 4, 5]) => fg: Color::DarkGray,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_scroll_lines_scroll_into_view() {
+        let mut state = state(true, true);
+
+        // already visible
+        state.scroll_into_view(1, 2);
+        render_scroll_lines(&mut state);
+        assert_eq!(state.v_offset, 0);
+
+        // the wrapped line 8 takes 2 rows, so lines 8 to 11 fill the page
+        state.scroll_into_view(8, 10);
+        render_scroll_lines(&mut state);
+        assert_eq!(state.v_offset, 7);
+
+        state.scroll_into_view(2, 2);
+        render_scroll_lines(&mut state);
+        assert_eq!(state.v_offset, 2);
+
+        // the first line is kept visible if the lines do not fit in the page
+        state.scroll_into_view(3, 13);
+        render_scroll_lines(&mut state);
+        assert_eq!(state.v_offset, 3);
+    }
+
     fn state(number: bool, wrap: bool) -> ScrollLinesState {
         let lines: Vec<Line> = [
             "aaa bbb ccc ddd",
@@ -1,19 +1,35 @@
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Margin, Rect},
-    widgets::{Block, List, ListItem, Padding, StatefulWidget, Widget},
+    style::{Color, Stylize},
+    widgets::{block::Title, Block, List, ListItem, Padding, Paragraph, StatefulWidget, Widget},
 };
 
 use crate::util::digits;
 
 use crate::widget::ScrollBar;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+const EMPTY_MESSAGE_COLOR: Color = Color::DarkGray;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ScrollListState {
     pub selected: usize,
     pub offset: usize,
     total: usize,
     height: usize,
+    wrap: bool,
+}
+
+impl Default for ScrollListState {
+    fn default() -> Self {
+        Self {
+            selected: 0,
+            offset: 0,
+            total: 0,
+            height: 0,
+            wrap: true,
+        }
+    }
 }
 
 impl ScrollListState {
@@ -24,12 +40,20 @@ impl ScrollListState {
         }
     }
 
+    // whether to move to the other end when moving past the first or last item
+    pub fn wrap(mut self, wrap: bool) -> ScrollListState {
+        self.wrap = wrap;
+        self
+    }
+
     pub fn select_next(&mut self) {
         if self.total == 0 {
             return;
         }
         if self.selected >= self.total - 1 {
-            self.select_first();
+            if self.wrap {
+                self.select_first();
+            }
         } else {
             if self.selected - self.offset == self.height - 1 {
                 self.offset += 1;
@@ -43,7 +67,9 @@ impl ScrollListState {
             return;
         }
         if self.selected == 0 {
-            self.select_last();
+            if self.wrap {
+                self.select_last();
+            }
         } else {
             if self.selected - self.offset == 0 {
                 self.offset -= 1;
@@ -92,6 +118,59 @@ impl ScrollListState {
         }
     }
 
+    pub fn select_next_by(&mut self, n: usize) {
+        if self.total == 0 {
+            return;
+        }
+        self.select((self.selected + n).min(self.total - 1));
+    }
+
+    pub fn select_prev_by(&mut self, n: usize) {
+        if self.total == 0 {
+            return;
+        }
+        self.select(self.selected.saturating_sub(n));
+    }
+
+    pub fn select_next_half_page(&mut self) {
+        if self.total == 0 {
+            return;
+        }
+        let n = self.half_height();
+        self.selected = (self.selected + n).min(self.total - 1);
+        self.offset = (self.offset + n)
+            .min(self.total.saturating_sub(self.height))
+            .min(self.selected);
+    }
+
+    pub fn select_prev_half_page(&mut self) {
+        if self.total == 0 {
+            return;
+        }
+        let n = self.half_height();
+        self.selected = self.selected.saturating_sub(n);
+        self.offset = self.offset.saturating_sub(n).min(self.selected);
+    }
+
+    fn half_height(&self) -> usize {
+        (self.height / 2).max(1)
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index >= self.total {
+            return;
+        }
+        self.selected = index;
+        if self.height == 0 {
+            // not rendered yet
+            self.offset = index;
+        } else if index < self.offset {
+            self.offset = index;
+        } else if index >= self.offset + self.height {
+            self.offset = index + 1 - self.height;
+        }
+    }
+
     pub fn select_first(&mut self) {
         if self.total == 0 {
             return;
@@ -109,16 +188,40 @@ impl ScrollListState {
             self.offset = self.total - self.height;
         }
     }
+
+    // unlike select, an out of range index selects the last item
+    pub fn select_index(&mut self, index: usize) {
+        if self.total == 0 {
+            return;
+        }
+        self.select(index.min(self.total - 1));
+    }
 }
 
 #[derive(Debug)]
 pub struct ScrollList<'a> {
     items: Vec<ListItem<'a>>,
+    title: Option<String>,
+    empty_message: Option<String>,
 }
 
 impl ScrollList<'_> {
     pub fn new(items: Vec<ListItem>) -> ScrollList {
-        ScrollList { items }
+        ScrollList {
+            items,
+            title: None,
+            empty_message: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn empty_message(mut self, msg: impl Into<String>) -> Self {
+        self.empty_message = Some(msg.into());
+        self
     }
 }
 
@@ -126,18 +229,30 @@ impl StatefulWidget for ScrollList<'_> {
     type State = ScrollListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        state.height = area.height as usize - 2 /* border */;
+        // at least one line is kept for the selection, even if it is hidden behind the border
+        state.height = (area.height as usize).saturating_sub(2 /* border */).max(1);
 
         let title = format_list_count(state.total, state.selected);
-        let list = List::new(self.items).block(
-            Block::bordered()
-                .title(title)
-                .title_alignment(Alignment::Right)
-                .padding(Padding::horizontal(1)),
-        );
+        let mut block = Block::bordered()
+            .title(title)
+            .title_alignment(Alignment::Right)
+            .padding(Padding::horizontal(1));
+        if let Some(title) = self.title {
+            let title = format!(" {} ", title);
+            block = block.title(Title::from(title).alignment(Alignment::Left));
+        }
+        let empty_message = self.empty_message.filter(|_| self.items.is_empty());
+        let list = List::new(self.items).block(block);
         Widget::render(list, area, buf);
 
         let area = area.inner(&Margin::new(2, 1));
+
+        if let Some(msg) = empty_message {
+            let msg_area = Rect::new(area.x, area.y + area.height / 2, area.width, 1);
+            let paragraph =
+                Paragraph::new(msg.fg(EMPTY_MESSAGE_COLOR)).alignment(Alignment::Center);
+            Widget::render(paragraph, msg_area.intersection(area), buf);
+        }
         let scrollbar_area = Rect::new(area.right(), area.top(), 1, area.height);
 
         if state.total > (scrollbar_area.height as usize) {
@@ -263,6 +378,184 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn test_render_scroll_list_too_short() {
+        let mut state = ScrollListState::new(5);
+        let items: Vec<ListItem> = (1..=5)
+            .map(|i| ListItem::new(vec![Line::from(format!("Item {}", i))]))
+            .collect();
+        let scroll_list = ScrollList::new(items);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        scroll_list.render(buf.area, &mut buf, &mut state);
+
+        // the selection moves without the items shown
+        assert_eq!(state.height, 1);
+        state.select_next();
+        state.select_next();
+        assert_eq!((state.selected, state.offset), (2, 2));
+        state.select_prev();
+        assert_eq!((state.selected, state.offset), (1, 1));
+    }
+
+    #[test]
+    fn test_select_next_prev_wrap() {
+        let mut state = scroll_list_state(20, 10);
+
+        state.select_prev();
+        assert_eq!((state.selected, state.offset), (19, 10));
+        state.select_next();
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    #[test]
+    fn test_select_next_prev_without_wrap() {
+        let mut state = scroll_list_state(20, 10).wrap(false);
+
+        state.select_prev();
+        assert_eq!((state.selected, state.offset), (0, 0));
+
+        state.select_last();
+        state.select_next();
+        assert_eq!((state.selected, state.offset), (19, 10));
+
+        // empty list
+        let mut state = scroll_list_state(0, 10).wrap(false);
+        state.select_next();
+        assert_eq!((state.selected, state.offset), (0, 0));
+        state.select_prev();
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    #[test]
+    fn test_select_next_half_page() {
+        // large list
+        let mut state = scroll_list_state(20, 10);
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (5, 5));
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (10, 10));
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (15, 10));
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (19, 10));
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (19, 10));
+
+        // small list
+        let mut state = scroll_list_state(3, 10);
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (2, 0));
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (2, 0));
+
+        // empty list
+        let mut state = scroll_list_state(0, 10);
+        state.select_next_half_page();
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    #[test]
+    fn test_select_prev_half_page() {
+        // large list
+        let mut state = scroll_list_state(20, 10);
+        state.select_last();
+        assert_eq!((state.selected, state.offset), (19, 10));
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (14, 5));
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (9, 0));
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (4, 0));
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (0, 0));
+
+        // small list
+        let mut state = scroll_list_state(3, 10);
+        state.select_last();
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (0, 0));
+
+        // empty list
+        let mut state = scroll_list_state(0, 10);
+        state.select_prev_half_page();
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    #[test]
+    fn test_select_next_by() {
+        let mut state = ScrollListState::new(20);
+        state.height = 5;
+
+        state.select_next_by(3);
+        assert_eq!((state.selected, state.offset), (3, 0));
+
+        state.select_next_by(3);
+        assert_eq!((state.selected, state.offset), (6, 2));
+
+        state.select_next_by(100);
+        assert_eq!((state.selected, state.offset), (19, 15));
+    }
+
+    #[test]
+    fn test_select_prev_by() {
+        let mut state = ScrollListState::new(20);
+        state.height = 5;
+        state.select_last();
+
+        state.select_prev_by(3);
+        assert_eq!((state.selected, state.offset), (16, 15));
+
+        state.select_prev_by(3);
+        assert_eq!((state.selected, state.offset), (13, 13));
+
+        state.select_prev_by(100);
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    #[test]
+    fn test_select() {
+        let mut state = scroll_list_state(20, 10);
+        state.select(5);
+        assert_eq!((state.selected, state.offset), (5, 0));
+        state.select(15);
+        assert_eq!((state.selected, state.offset), (15, 6));
+        state.select(10);
+        assert_eq!((state.selected, state.offset), (10, 6));
+        state.select(3);
+        assert_eq!((state.selected, state.offset), (3, 3));
+        state.select(20);
+        assert_eq!((state.selected, state.offset), (3, 3));
+
+        // not rendered yet
+        let mut state = ScrollListState::new(20);
+        state.select(7);
+        assert_eq!((state.selected, state.offset), (7, 7));
+    }
+
+    #[test]
+    fn test_select_index() {
+        let mut state = scroll_list_state(20, 10);
+        state.select_index(12);
+        assert_eq!((state.selected, state.offset), (12, 3));
+        state.select_index(0);
+        assert_eq!((state.selected, state.offset), (0, 0));
+        state.select_index(100);
+        assert_eq!((state.selected, state.offset), (19, 10));
+
+        let mut state = scroll_list_state(0, 10);
+        state.select_index(5);
+        assert_eq!((state.selected, state.offset), (0, 0));
+    }
+
+    fn scroll_list_state(total: usize, height: usize) -> ScrollListState {
+        ScrollListState {
+            total,
+            height,
+            ..Default::default()
+        }
+    }
+
     fn render_scroll_list(state: &mut ScrollListState) -> Buffer {
         let show_item_count = 10_u16;
         let items: Vec<ListItem> = (1..=20)
@@ -0,0 +1,125 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{Paragraph, Widget},
+};
+
+use crate::util::truncate_with_ellipsis;
+
+const STATUS_COLOR: Color = Color::DarkGray;
+const OPERATION_COLOR: Color = Color::Blue;
+
+const DELIMITER: &str = " | ";
+
+#[derive(Debug, Default)]
+pub struct StatusBar {
+    bucket: Option<String>,
+    depth: usize,
+    item_count: Option<usize>,
+    operation: Option<String>,
+}
+
+impl StatusBar {
+    pub fn bucket(mut self, bucket: impl Into<String>, depth: usize) -> StatusBar {
+        self.bucket = Some(bucket.into());
+        self.depth = depth;
+        self
+    }
+
+    pub fn item_count(mut self, count: usize) -> StatusBar {
+        self.item_count = Some(count);
+        self
+    }
+
+    pub fn operation(mut self, operation: impl Into<String>) -> StatusBar {
+        self.operation = Some(operation.into());
+        self
+    }
+}
+
+impl Widget for StatusBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let operation = self.operation.as_deref().unwrap_or_default();
+        let max_width = (area.width as usize).saturating_sub(2 /* spaces */);
+
+        // the operation is kept as it is, and the rest is cut off if it does not fit
+        let location_w = max_width.saturating_sub(operation.chars().count() + 1);
+        let location = truncate_with_ellipsis(&self.build_location(), location_w);
+        let gap = max_width.saturating_sub(location.chars().count() + operation.chars().count());
+
+        let line = Line::from(vec![
+            format!(" {}", location).fg(STATUS_COLOR),
+            " ".repeat(gap).into(),
+            format!("{} ", operation).fg(OPERATION_COLOR),
+        ]);
+        Paragraph::new(line).render(area, buf);
+    }
+}
+
+impl StatusBar {
+    fn build_location(&self) -> String {
+        let mut segments = Vec::new();
+        if let Some(bucket) = &self.bucket {
+            segments.push(bucket.clone());
+            segments.push(format!("depth {}", self.depth));
+        }
+        if let Some(count) = self.item_count {
+            let unit = if count == 1 { "item" } else { "items" };
+            segments.push(format!("{} {}", count, unit));
+        }
+        segments.join(DELIMITER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_status_bar() {
+        let status_bar = StatusBar::default()
+            .bucket("bucket-1", 2)
+            .item_count(12)
+            .operation("Loading...");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 50, 1));
+        status_bar.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " bucket-1 | depth 2 | 12 items         Loading... ",
+        ]);
+        set_cells! { expected =>
+            (0..30, [0]) => fg: Color::DarkGray,
+            (39..50, [0]) => fg: Color::Blue,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_status_bar_truncated() {
+        let status_bar = StatusBar::default()
+            .bucket("very-long-bucket-name", 0)
+            .item_count(1)
+            .operation("Loading...");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+        status_bar.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            " very-long-buck... Loading... ",
+        ]);
+        set_cells! { expected =>
+            (0..18, [0]) => fg: Color::DarkGray,
+            (19..30, [0]) => fg: Color::Blue,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -14,53 +14,180 @@ use syntect::{
 };
 
 use crate::{
+    config::PreviewHandler,
     object::{FileDetail, RawObject},
-    util::{extension_from_file_name, to_preview_string},
+    ui::common::{format_size_byte, format_version},
+    util::{detect_binary_format, extension_from_file_name, is_binary_content, to_preview_string},
     widget::{ScrollLines, ScrollLinesOptions, ScrollLinesState},
 };
 
+// the whole object is already in memory, but building lines for all of a large object is slow
+const HEX_VIEW_MAX_BYTES: usize = 1024 * 1024;
+const HEX_VIEW_BYTES_PER_LINE: usize = 16;
+
 static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
 static THEME_SET: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PreviewMode {
+    Text,
+    // a summary of the binary object, instead of the garbled text
+    Binary,
+    Hex,
+}
+
 #[derive(Debug)]
 pub struct TextPreviewState {
     pub scroll_lines_state: ScrollLinesState,
+    mode: PreviewMode,
+    binary: bool,
+    // the position of the first byte in the object, for the addresses of the hex view
+    offset: usize,
 }
 
 impl TextPreviewState {
+    // the handler configured for the extension takes precedence over the content type
     pub fn new(
         file_detail: &FileDetail,
         object: &RawObject,
         highlight: bool,
+        handler: Option<PreviewHandler>,
     ) -> (Self, Option<String>) {
-        let mut warn_msg = None;
+        let binary = match handler {
+            Some(PreviewHandler::Text) => false,
+            _ => is_binary_content(&file_detail.content_type, &object.bytes),
+        };
+        let mode = match handler {
+            Some(PreviewHandler::Hex) => PreviewMode::Hex,
+            _ if binary => PreviewMode::Binary,
+            _ => PreviewMode::Text,
+        };
+        let (lines, warn_msg) = build_lines(mode, file_detail, object, highlight, 0);
+        let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::default());
 
-        let s = to_preview_string(&object.bytes);
-        let s = if s.ends_with('\n') {
-            s.trim_end()
-        } else {
-            s.as_str()
+        let state = Self {
+            scroll_lines_state,
+            mode,
+            binary,
+            offset: 0,
         };
+        (state, warn_msg)
+    }
 
-        let lines: Vec<Line<'static>> =
-            match build_highlighted_lines(s, &file_detail.name, highlight) {
-                Ok(lines) => lines,
-                Err(msg) => {
-                    // If there is an error, display the original text
-                    if let Some(msg) = msg {
-                        warn_msg = Some(msg);
-                    }
-                    s.split('\n').map(|s| Line::raw(s.to_string())).collect()
-                }
-            };
+    // keeps the scroll position and the options, used when the object content has changed
+    pub fn update(&mut self, file_detail: &FileDetail, object: &RawObject, highlight: bool) {
+        let (lines, _) = build_lines(self.mode, file_detail, object, highlight, self.offset);
+        self.scroll_lines_state.set_lines(lines);
+    }
 
-        let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::default());
+    pub fn set_offset(&mut self, offset: usize) {
+        self.offset = offset;
+    }
 
-        let state = Self { scroll_lines_state };
-        (state, warn_msg)
+    pub fn toggle_hex(&mut self, file_detail: &FileDetail, object: &RawObject, highlight: bool) {
+        self.mode = match self.mode {
+            PreviewMode::Hex if self.binary => PreviewMode::Binary,
+            PreviewMode::Hex => PreviewMode::Text,
+            PreviewMode::Text | PreviewMode::Binary => PreviewMode::Hex,
+        };
+        self.update(file_detail, object, highlight);
+        self.scroll_lines_state.scroll_to_top();
     }
 }
 
+fn build_lines(
+    mode: PreviewMode,
+    file_detail: &FileDetail,
+    object: &RawObject,
+    highlight: bool,
+    offset: usize,
+) -> (Vec<Line<'static>>, Option<String>) {
+    match mode {
+        PreviewMode::Text => build_preview_lines(file_detail, object, highlight),
+        PreviewMode::Binary => (build_binary_summary_lines(object), None),
+        PreviewMode::Hex => (build_hex_lines(object, offset), None),
+    }
+}
+
+fn build_binary_summary_lines(object: &RawObject) -> Vec<Line<'static>> {
+    let format = detect_binary_format(&object.bytes).unwrap_or("unknown format");
+    vec![
+        Line::raw(format!(
+            "Binary file detected: {} ({} bytes)",
+            format,
+            object.bytes.len()
+        )),
+        Line::raw(""),
+        Line::raw("Press x to view as hex, s to download"),
+    ]
+}
+
+fn build_hex_lines(object: &RawObject, offset: usize) -> Vec<Line<'static>> {
+    let bytes = &object.bytes[..object.bytes.len().min(HEX_VIEW_MAX_BYTES)];
+    let mut lines: Vec<Line<'static>> = bytes
+        .chunks(HEX_VIEW_BYTES_PER_LINE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let hex = chunk
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            Line::raw(format!(
+                "{:08x}  {:<width$}  |{}|",
+                offset + i * HEX_VIEW_BYTES_PER_LINE,
+                hex,
+                ascii,
+                width = HEX_VIEW_BYTES_PER_LINE * 3 - 1
+            ))
+        })
+        .collect();
+    if object.bytes.len() > HEX_VIEW_MAX_BYTES {
+        lines.push(Line::raw(format!(
+            "... (only the first {} bytes are shown)",
+            HEX_VIEW_MAX_BYTES
+        )));
+    }
+    lines
+}
+
+fn build_preview_lines(
+    file_detail: &FileDetail,
+    object: &RawObject,
+    highlight: bool,
+) -> (Vec<Line<'static>>, Option<String>) {
+    let mut warn_msg = None;
+
+    let s = to_preview_string(&object.bytes);
+    let s = if s.ends_with('\n') {
+        s.trim_end()
+    } else {
+        s.as_str()
+    };
+
+    let lines: Vec<Line<'static>> = match build_highlighted_lines(s, &file_detail.name, highlight) {
+        Ok(lines) => lines,
+        Err(msg) => {
+            // If there is an error, display the original text
+            if let Some(msg) = msg {
+                warn_msg = Some(msg);
+            }
+            s.split('\n').map(|s| Line::raw(s.to_string())).collect()
+        }
+    };
+    (lines, warn_msg)
+}
+
 fn build_highlighted_lines(
     s: &str,
     file_name: &str,
@@ -92,6 +219,10 @@ fn build_highlighted_lines(
 pub struct TextPreview<'a> {
     file_name: &'a str,
     file_version_id: Option<&'a str>,
+    tailing: bool,
+    truncated_size: Option<usize>,
+    // the first and last byte, if only a part of the object is previewed
+    range: Option<(usize, usize)>,
 }
 
 impl<'a> TextPreview<'a> {
@@ -99,21 +230,121 @@ impl<'a> TextPreview<'a> {
         Self {
             file_name,
             file_version_id,
+            tailing: false,
+            truncated_size: None,
+            range: None,
         }
     }
+
+    pub fn tailing(mut self, tailing: bool) -> Self {
+        self.tailing = tailing;
+        self
+    }
+
+    // the size loaded, if only the head of the object is previewed
+    pub fn truncated_size(mut self, truncated_size: Option<usize>) -> Self {
+        self.truncated_size = truncated_size;
+        self
+    }
+
+    pub fn range(mut self, range: Option<(usize, usize)>) -> Self {
+        self.range = range;
+        self
+    }
 }
 
 impl StatefulWidget for TextPreview<'_> {
     type State = TextPreviewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let title = if let Some(version_id) = self.file_version_id {
+        let mut title = if let Some(version_id) = self.file_version_id {
+            let version_id = format_version(version_id);
             format!("Preview [{} (Version ID: {})]", self.file_name, version_id)
         } else {
-            format!("Preview [{}]", self.file_name)
+            format!("Preview [{} (latest)]", self.file_name)
         };
+        if self.tailing {
+            title.push_str(" (tailing)");
+        }
+        if let Some(size) = self.truncated_size {
+            title.push_str(&format!(" (first {} only)", format_size_byte(size)));
+        }
+        if let Some((start, end)) = self.range {
+            title.push_str(&format!(" (bytes {}-{})", start, end));
+        }
         ScrollLines::default()
             .block(Block::bordered().title(title))
             .render(area, buf, &mut state.scroll_lines_state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_hex_lines() {
+        let object = RawObject {
+            bytes: b"%PDF-1.7\n\x00\x01\xffabcdefghij".to_vec(),
+        };
+        let actual: Vec<String> = build_hex_lines(&object, 0)
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        let expected = [
+            "00000000  25 50 44 46 2d 31 2e 37 0a 00 01 ff 61 62 63 64  |%PDF-1.7....abcd|",
+            "00000010  65 66 67 68 69 6a                                |efghij|",
+        ];
+        assert_eq!(actual, expected);
+
+        // the addresses of a byte range start at its offset
+        let actual: Vec<String> = build_hex_lines(&object, 0x400)
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert!(actual[0].starts_with("00000400  25 50"));
+        assert!(actual[1].starts_with("00000410  65 66"));
+    }
+
+    #[test]
+    fn test_new_with_handler() {
+        let file_detail = FileDetail {
+            name: "app.log".to_string(),
+            content_type: "application/octet-stream".to_string(),
+            ..Default::default()
+        };
+        let object = RawObject {
+            bytes: b"started\n\x00\nstopped\n".to_vec(),
+        };
+
+        // the null byte makes the content binary without a handler
+        let (state, _) = TextPreviewState::new(&file_detail, &object, false, None);
+        assert_eq!(state.mode, PreviewMode::Binary);
+
+        let handler = Some(PreviewHandler::Text);
+        let (mut state, _) = TextPreviewState::new(&file_detail, &object, false, handler);
+        assert_eq!(state.mode, PreviewMode::Text);
+        // the hex view is toggled back to the text forced by the handler
+        state.toggle_hex(&file_detail, &object, false);
+        state.toggle_hex(&file_detail, &object, false);
+        assert_eq!(state.mode, PreviewMode::Text);
+
+        let handler = Some(PreviewHandler::Hex);
+        let (mut state, _) = TextPreviewState::new(&file_detail, &object, false, handler);
+        assert_eq!(state.mode, PreviewMode::Hex);
+        state.toggle_hex(&file_detail, &object, false);
+        assert_eq!(state.mode, PreviewMode::Binary);
+    }
+
+    #[test]
+    fn test_build_binary_summary_lines() {
+        let object = RawObject {
+            bytes: b"%PDF-1.7\n\x00".to_vec(),
+        };
+        let actual = build_binary_summary_lines(&object);
+        assert_eq!(
+            actual[0].to_string(),
+            "Binary file detected: PDF (10 bytes)"
+        );
+    }
+}